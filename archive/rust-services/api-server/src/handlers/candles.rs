@@ -54,22 +54,24 @@ pub async fn get_candles(
     }
 }
 
+const BASE_GRANULARITY: u32 = 60; // canonical 1-minute candles
+
 async fn get_candles_impl(
     symbol: String,
     params: CandleQuery,
     state: AppState,
 ) -> Result<Json<CandleResponse>> {
     let exchange = params.exchange.as_deref().unwrap_or("coinbase");
-    let granularity = params.granularity.unwrap_or(60); // Default 1 minute
-    
+    let granularity = params.granularity.unwrap_or(BASE_GRANULARITY);
+
     // Default time range: last 7 days
     let end = params.end.unwrap_or_else(|| Utc::now().timestamp());
     let start = params.start.unwrap_or_else(|| end - 7 * 24 * 3600);
-    
+
     // Normalize symbol (BTC-USD -> BTC/USD for internal use)
     let normalized_symbol = symbol.replace("-", "/");
-    
-    // First try to get historical data from Parquet files
+
+    // First try to get historical 1m candles from Parquet files
     // Load config to get data directory
     let config = alphapulse_common::Config::load()
         .unwrap_or_else(|_| alphapulse_common::Config::default());
@@ -81,13 +83,13 @@ async fn get_candles_impl(
             info!("Failed to read parquet files: {}", e);
             Vec::new()
         });
-    
+
     info!("Read {} candles from parquet files", candles.len());
-    
+
     // If we don't have enough historical data, also get recent trades from Redis
     if candles.is_empty() || candles.last().map_or(true, |c| c.time < end - 3600) {
         info!("Fetching recent trades from Redis to supplement historical data");
-        
+
         let trades = state.redis
             .get_trades_in_range(
                 &normalized_symbol,
@@ -97,10 +99,10 @@ async fn get_candles_impl(
                 Some(10000), // Max trades to process
             )
             .await?;
-        
-        // Convert trades to candles and merge with historical data
-        let redis_candles = trades_to_candles(trades, granularity);
-        
+
+        // Convert trades to canonical 1m candles and merge with historical data
+        let redis_candles = trades_to_candles(trades, BASE_GRANULARITY);
+
         // Merge candles, using BTreeMap to deduplicate and sort
         let mut all_candles = BTreeMap::new();
         for candle in candles {
@@ -109,11 +111,17 @@ async fn get_candles_impl(
         for candle in redis_candles {
             all_candles.insert(candle.time, candle);
         }
-        
+
         candles = all_candles.into_values().collect();
         info!("Total candles after merging: {}", candles.len());
     }
-    
+
+    // Fold the canonical 1m candles up into the requested granularity so every
+    // timeframe is derived consistently from the same base series.
+    if granularity != BASE_GRANULARITY {
+        candles = combine_into_higher_order_candles(&candles, granularity, None);
+    }
+
     Ok(Json(CandleResponse {
         candles,
         symbol: symbol.clone(),
@@ -162,6 +170,77 @@ fn trades_to_candles(trades: Vec<Trade>, granularity: u32) -> Vec<Candle> {
     candles
 }
 
+/// Fold canonical 1m `base` candles up into coarser `target_granularity` buckets,
+/// so a 1h candle is exactly the aggregation of its sixty 1m candles instead of a
+/// separate re-bucketing of raw trades.
+///
+/// `seed` carries forward the previous candle's close so a gap at the very start
+/// of `base` (no 1m data yet for the first target bucket) still has a price to
+/// hold flat; internal gaps carry forward from the last real bucket seen.
+fn combine_into_higher_order_candles(
+    base: &[Candle],
+    target_granularity: u32,
+    seed: Option<Candle>,
+) -> Vec<Candle> {
+    if base.is_empty() {
+        return Vec::new();
+    }
+
+    let target = target_granularity as i64;
+
+    // Group base candles into target buckets, preserving time order within each.
+    let mut buckets: BTreeMap<i64, Vec<&Candle>> = BTreeMap::new();
+    for candle in base {
+        let bucket = (candle.time / target) * target;
+        buckets.entry(bucket).or_insert_with(Vec::new).push(candle);
+    }
+
+    let first_bucket = *buckets.keys().next().unwrap();
+    let last_bucket = *buckets.keys().last().unwrap();
+
+    let mut candles = Vec::new();
+    let mut last_close = seed.as_ref().map(|c| c.close);
+    // With a seed, start right after the seed's bucket so leading gap buckets
+    // before the first base candle are filled flat at the seed's close.
+    let mut time = seed
+        .as_ref()
+        .map(|c| (c.time / target) * target + target)
+        .filter(|&t| t < first_bucket)
+        .unwrap_or(first_bucket);
+
+    while time <= last_bucket {
+        match buckets.get(&time) {
+            Some(group) => {
+                let open = group.first().unwrap().open;
+                let close = group.last().unwrap().close;
+                let high = group.iter().map(|c| c.high).fold(f64::NEG_INFINITY, f64::max);
+                let low = group.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+                let volume: f64 = group.iter().map(|c| c.volume).sum();
+
+                candles.push(Candle { time, open, high, low, close, volume });
+                last_close = Some(close);
+            }
+            None => {
+                // No trades in this bucket: emit a flat synthetic candle at the
+                // last known close so charts don't show a phantom price jump.
+                if let Some(close) = last_close {
+                    candles.push(Candle {
+                        time,
+                        open: close,
+                        high: close,
+                        low: close,
+                        close,
+                        volume: 0.0,
+                    });
+                }
+            }
+        }
+        time += target;
+    }
+
+    candles
+}
+
 // Batch endpoint for multiple requests (mimics Python backend)
 #[derive(Debug, Deserialize)]
 pub struct BatchCandleRequest {
@@ -205,8 +284,13 @@ async fn get_candles_batch_impl(
             )
             .await?;
         
-        let candles = trades_to_candles(trades, req.granularity);
-        
+        let base_candles = trades_to_candles(trades, BASE_GRANULARITY);
+        let candles = if req.granularity != BASE_GRANULARITY {
+            combine_into_higher_order_candles(&base_candles, req.granularity, None)
+        } else {
+            base_candles
+        };
+
         responses.push(CandleResponse {
             candles,
             symbol: req.symbol,
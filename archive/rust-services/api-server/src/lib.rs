@@ -9,5 +9,6 @@ pub mod realtime_websocket_discovery;
 pub mod tokio_websocket;
 pub mod redis_websocket;
 pub mod parquet_reader;
+pub mod parquet_writer;
 //pub mod shm_reader_thread;
 //pub mod shm_reader;
\ No newline at end of file
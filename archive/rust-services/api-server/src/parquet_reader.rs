@@ -1,13 +1,37 @@
 // Parquet file reader for historical market data
 use arrow::array::{Array, Float64Array, Int64Array};
 use arrow::record_batch::RecordBatch;
+use futures::stream::{FuturesUnordered, StreamExt};
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
-use std::collections::BTreeMap;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::statistics::Statistics;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tracing::{info, warn, error};
 use crate::handlers::candles::Candle;
 
+/// How many parquet files to decode concurrently per symbol/exchange.
+pub const DEFAULT_FILE_PARALLELISM: usize = 8;
+
+/// A file that failed to load, kept alongside whatever other files succeeded rather
+/// than failing the whole historical load.
+#[derive(Debug)]
+pub struct FileLoadError {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// Result of loading one symbol/exchange's candles: partial results are always
+/// returned, even if some files errored or the deadline was hit first.
+#[derive(Debug, Default)]
+pub struct SymbolLoadResult {
+    pub candles: Vec<Candle>,
+    pub errors: Vec<FileLoadError>,
+    pub timed_out: bool,
+}
+
 pub struct ParquetReader {
     data_dir: PathBuf,
 }
@@ -20,6 +44,8 @@ impl ParquetReader {
     }
 
     /// Read historical candles from Parquet files
+    // Candles are stored as canonical 1-minute bars; callers fold them up to
+    // coarser granularities via `combine_into_higher_order_candles`.
     pub async fn read_historical_candles(
         &self,
         symbol: &str,
@@ -27,55 +53,159 @@ impl ParquetReader {
         start_time: Option<i64>,
         end_time: Option<i64>,
     ) -> anyhow::Result<Vec<Candle>> {
+        let result = self
+            .read_symbol_candles(symbol, exchange, start_time, end_time, DEFAULT_FILE_PARALLELISM, None)
+            .await;
+
+        for err in &result.errors {
+            error!("Error reading parquet file {:?}: {}", err.path, err.error);
+        }
+
+        info!("Read {} total candles from parquet files for {}/{}",
+              result.candles.len(), exchange, symbol);
+
+        Ok(result.candles)
+    }
+
+    /// Read historical candles for several symbol/exchange pairs at once, fanning
+    /// file reads out concurrently both within and across instruments. `deadline`
+    /// bounds the whole call: once it elapses, every still-pending instrument's
+    /// result is returned with whatever candles had already been decoded and
+    /// `timed_out: true`, rather than failing the batch.
+    pub async fn read_historical_candles_multi(
+        &self,
+        instruments: &[(String, String)], // (symbol, exchange)
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        deadline: Option<Duration>,
+    ) -> HashMap<(String, String), SymbolLoadResult> {
+        let deadline = deadline.map(|d| Instant::now() + d);
+
+        let mut in_flight: FuturesUnordered<_> = instruments
+            .iter()
+            .map(|(symbol, exchange)| async move {
+                let result = self
+                    .read_symbol_candles(symbol, exchange, start_time, end_time, DEFAULT_FILE_PARALLELISM, deadline)
+                    .await;
+                ((symbol.clone(), exchange.clone()), result)
+            })
+            .collect();
+
+        let mut results = HashMap::with_capacity(instruments.len());
+        while let Some((key, result)) = in_flight.next().await {
+            results.insert(key, result);
+        }
+        results
+    }
+
+    /// Load every parquet file for one symbol/exchange, decoding up to `parallelism`
+    /// files at a time and stopping early (with `timed_out: true`) if `deadline`
+    /// passes before all files complete.
+    async fn read_symbol_candles(
+        &self,
+        symbol: &str,
+        exchange: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        parallelism: usize,
+        deadline: Option<Instant>,
+    ) -> SymbolLoadResult {
         // Convert symbol format (BTC-USD -> BTC_USD for file paths)
         let file_symbol = symbol.replace("-", "_").replace("/", "_");
-        
+
         // Build path to parquet files
         let parquet_dir = self.data_dir
             .join("data")
             .join("parquet")
             .join(exchange)
             .join(&file_symbol);
-        
+
         if !parquet_dir.exists() {
             warn!("Parquet directory does not exist: {:?}", parquet_dir);
-            return Ok(Vec::new());
+            return SymbolLoadResult::default();
         }
-        
+
+        let entries = match std::fs::read_dir(&parquet_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                return SymbolLoadResult {
+                    candles: Vec::new(),
+                    errors: vec![FileLoadError { path: parquet_dir, error: e.to_string() }],
+                    timed_out: false,
+                };
+            }
+        };
+
+        let paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("parquet"))
+            .collect();
+
         let mut all_candles = BTreeMap::new();
-        
-        // Read all parquet files in the directory
-        let entries = std::fs::read_dir(&parquet_dir)?;
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
-            
-            if path.extension().and_then(|s| s.to_str()) == Some("parquet") {
-                info!("Reading parquet file: {:?}", path);
-                
-                match self.read_parquet_file(&path, start_time, end_time).await {
-                    Ok(candles) => {
-                        for candle in candles {
-                            // Use BTreeMap to automatically sort by time and deduplicate
-                            all_candles.insert(candle.time, candle);
-                        }
+        let mut errors = Vec::new();
+        let mut timed_out = false;
+
+        // Bounded concurrent fan-out: `buffer_unordered` drives up to `parallelism`
+        // of these futures via an internal `FuturesUnordered`, yielding results as
+        // soon as each file finishes rather than waiting on the slowest.
+        // The decode itself is blocking file I/O with no await points, so each
+        // file runs on the blocking pool; that is what lets files overlap.
+        let data_dir = self.data_dir.clone();
+        let mut pending = futures::stream::iter(paths.into_iter().map(|path| {
+            let reader = ParquetReader::new(&data_dir);
+            async move {
+                let task_path = path.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    futures::executor::block_on(reader.read_parquet_file(&task_path, start_time, end_time))
+                })
+                .await
+                .unwrap_or_else(|e| Err(anyhow::anyhow!("parquet decode task failed: {}", e)));
+                (path, result)
+            }
+        }))
+        .buffer_unordered(parallelism.max(1));
+
+        loop {
+            let next = if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    timed_out = true;
+                    break;
+                }
+                match tokio::time::timeout(remaining, pending.next()).await {
+                    Ok(next) => next,
+                    Err(_) => {
+                        timed_out = true;
+                        break;
                     }
-                    Err(e) => {
-                        error!("Error reading parquet file {:?}: {}", path, e);
+                }
+            } else {
+                pending.next().await
+            };
+
+            let Some((path, result)) = next else {
+                break; // all files finished
+            };
+
+            match result {
+                Ok(candles) => {
+                    for candle in candles {
+                        // Use BTreeMap to automatically sort by time and deduplicate
+                        all_candles.insert(candle.time, candle);
                     }
                 }
+                Err(e) => errors.push(FileLoadError { path, error: e.to_string() }),
             }
         }
-        
-        // Convert BTreeMap to Vec (already sorted by time)
-        let candles: Vec<Candle> = all_candles.into_values().collect();
-        
-        info!("Read {} total candles from parquet files for {}/{}", 
-              candles.len(), exchange, symbol);
-        
-        Ok(candles)
+
+        SymbolLoadResult {
+            candles: all_candles.into_values().collect(),
+            errors,
+            timed_out,
+        }
     }
-    
+
     /// Read a single parquet file
     async fn read_parquet_file(
         &self,
@@ -85,18 +215,82 @@ impl ParquetReader {
     ) -> anyhow::Result<Vec<Candle>> {
         let file = File::open(path)?;
         let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+
+        let total_row_groups = builder.metadata().num_row_groups();
+        let builder = match Self::prune_row_groups(builder.metadata(), start_time, end_time) {
+            Some(row_groups) => {
+                info!(
+                    "Row-group pruning on {:?}: keeping {}/{} row groups for window {:?}..{:?}",
+                    path, row_groups.len(), total_row_groups, start_time, end_time
+                );
+                builder.with_row_groups(row_groups)
+            }
+            None => builder, // no usable statistics or no time window: full scan
+        };
+
         let mut reader = builder.build()?;
-        
+
         let mut candles = Vec::new();
-        
+        let mut batches_decoded = 0usize;
+
         while let Some(batch_result) = reader.next() {
             let batch = batch_result?;
+            batches_decoded += 1;
             let batch_candles = self.extract_candles_from_batch(&batch, start_time, end_time)?;
             candles.extend(batch_candles);
         }
-        
+
+        info!("Decoded {} batch(es) from {:?}", batches_decoded, path);
+
         Ok(candles)
     }
+
+    /// Determine which row groups in `metadata` can possibly contain a row with
+    /// `timestamp` inside `[start_time, end_time]`, using the column's min/max
+    /// statistics. Returns `None` when there's no time window to prune against, or
+    /// when the `timestamp` column lacks statistics, so the caller should fall back
+    /// to reading every row group.
+    fn prune_row_groups(
+        metadata: &ParquetMetaData,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Option<Vec<usize>> {
+        if start_time.is_none() && end_time.is_none() {
+            return None;
+        }
+
+        let schema = metadata.file_metadata().schema_descr();
+        let timestamp_col_idx = (0..schema.num_columns())
+            .find(|&i| schema.column(i).name() == "timestamp")?;
+
+        let mut keep = Vec::with_capacity(metadata.num_row_groups());
+        for (i, row_group) in metadata.row_groups().iter().enumerate() {
+            let Some(stats) = row_group.column(timestamp_col_idx).statistics() else {
+                // No statistics for this row group: can't prove it's out of range.
+                keep.push(i);
+                continue;
+            };
+
+            let min_max = match stats {
+                Statistics::Int64(s) => s.min_opt().zip(s.max_opt()).map(|(min, max)| (*min, *max)),
+                _ => None,
+            };
+
+            let Some((min, max)) = min_max else {
+                keep.push(i);
+                continue;
+            };
+
+            let entirely_after_window = end_time.map_or(false, |end| min > end);
+            let entirely_before_window = start_time.map_or(false, |start| max < start);
+            if entirely_after_window || entirely_before_window {
+                continue;
+            }
+            keep.push(i);
+        }
+
+        Some(keep)
+    }
     
     /// Extract candles from an Arrow RecordBatch
     fn extract_candles_from_batch(
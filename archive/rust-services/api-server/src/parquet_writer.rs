@@ -0,0 +1,178 @@
+// Parquet file writer for persisting candles, matching the
+// `data/parquet/<exchange>/<symbol>/*.parquet` layout `ParquetReader` expects.
+use arrow::array::{Float64Array, Int64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{error, info};
+use crate::handlers::candles::Candle;
+
+/// Roll to a new file once the buffer holds this many candles.
+pub const DEFAULT_MAX_CANDLES_PER_FILE: usize = 100_000;
+/// Roll to a new file once the current one has been open this long, even if it
+/// hasn't filled up (keeps live feeds from sitting unflushed for hours).
+pub const DEFAULT_MAX_FILE_AGE: Duration = Duration::from_secs(3600);
+
+fn candle_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("timestamp", DataType::Int64, false),
+        Field::new("open", DataType::Float64, false),
+        Field::new("high", DataType::Float64, false),
+        Field::new("low", DataType::Float64, false),
+        Field::new("close", DataType::Float64, false),
+        Field::new("volume", DataType::Float64, false),
+    ]))
+}
+
+/// Buffers `Candle`s for one symbol/exchange and periodically rolls them into a new
+/// parquet file. Candles are deduplicated on `timestamp` as they're buffered, the same
+/// way `ParquetReader` merges results via a `BTreeMap`, so replaying a live feed twice
+/// doesn't produce duplicate rows.
+pub struct ParquetWriter {
+    data_dir: PathBuf,
+    exchange: String,
+    symbol: String,
+    max_candles_per_file: usize,
+    max_file_age: Duration,
+    pending: BTreeMap<i64, Candle>,
+    file_opened_at: Option<Instant>,
+}
+
+impl ParquetWriter {
+    pub fn new(data_dir: impl AsRef<Path>, exchange: &str, symbol: &str) -> Self {
+        Self {
+            data_dir: data_dir.as_ref().to_path_buf(),
+            exchange: exchange.to_string(),
+            symbol: symbol.to_string(),
+            max_candles_per_file: DEFAULT_MAX_CANDLES_PER_FILE,
+            max_file_age: DEFAULT_MAX_FILE_AGE,
+            pending: BTreeMap::new(),
+            file_opened_at: None,
+        }
+    }
+
+    pub fn with_roll_policy(mut self, max_candles_per_file: usize, max_file_age: Duration) -> Self {
+        self.max_candles_per_file = max_candles_per_file;
+        self.max_file_age = max_file_age;
+        self
+    }
+
+    fn target_dir(&self) -> PathBuf {
+        let file_symbol = self.symbol.replace("-", "_").replace("/", "_");
+        self.data_dir
+            .join("data")
+            .join("parquet")
+            .join(&self.exchange)
+            .join(file_symbol)
+    }
+
+    /// Buffer one candle, rolling to a new file first if the roll policy requires it.
+    pub fn write_candle(&mut self, candle: Candle) -> anyhow::Result<()> {
+        if self.should_roll() {
+            self.flush()?;
+        }
+        self.pending.insert(candle.time, candle);
+        if self.file_opened_at.is_none() {
+            self.file_opened_at = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    pub fn write_candles(&mut self, candles: impl IntoIterator<Item = Candle>) -> anyhow::Result<()> {
+        for candle in candles {
+            self.write_candle(candle)?;
+        }
+        Ok(())
+    }
+
+    fn should_roll(&self) -> bool {
+        if self.pending.len() >= self.max_candles_per_file {
+            return true;
+        }
+        match self.file_opened_at {
+            Some(opened_at) => opened_at.elapsed() >= self.max_file_age,
+            None => false,
+        }
+    }
+
+    /// Write any buffered candles out as a new parquet file and reset the buffer.
+    ///
+    /// Crash-safe: the batch is written to a hidden `.tmp` file in the target
+    /// directory and `rename`d into place only once the writer has closed
+    /// successfully, so a reader never observes a half-written `.parquet` file.
+    /// The final filename is derived from the candles' timestamp range, so
+    /// re-running over the same range is idempotent: it produces the same file
+    /// and atomically replaces it.
+    pub fn flush(&mut self) -> anyhow::Result<Option<PathBuf>> {
+        self.file_opened_at = None;
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+
+        let target_dir = self.target_dir();
+        std::fs::create_dir_all(&target_dir)?;
+
+        let candles: Vec<Candle> = std::mem::take(&mut self.pending).into_values().collect();
+        let first = candles.first().unwrap().time;
+        let last = candles.last().unwrap().time;
+        let final_path = target_dir.join(format!("{}_{}.parquet", first, last));
+        let tmp_path = target_dir.join(format!(".{}_{}.parquet.tmp", first, last));
+
+        let batch = Self::build_record_batch(&candles)?;
+
+        let props = WriterProperties::builder()
+            // Per-row-group min/max statistics, which `ParquetReader::prune_row_groups`
+            // uses to skip whole row groups outside a query's time window.
+            .set_statistics_enabled(EnabledStatistics::Chunk)
+            .build();
+
+        {
+            let file = File::create(&tmp_path)?;
+            let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+            writer.write(&batch)?;
+            writer.close()?;
+        }
+
+        std::fs::rename(&tmp_path, &final_path)?;
+        info!("Wrote {} candles to {:?}", candles.len(), final_path);
+
+        Ok(Some(final_path))
+    }
+
+    fn build_record_batch(candles: &[Candle]) -> anyhow::Result<RecordBatch> {
+        let timestamp: Int64Array = candles.iter().map(|c| c.time).collect();
+        let open: Float64Array = candles.iter().map(|c| c.open).collect();
+        let high: Float64Array = candles.iter().map(|c| c.high).collect();
+        let low: Float64Array = candles.iter().map(|c| c.low).collect();
+        let close: Float64Array = candles.iter().map(|c| c.close).collect();
+        let volume: Float64Array = candles.iter().map(|c| c.volume).collect();
+
+        Ok(RecordBatch::try_new(
+            candle_schema(),
+            vec![
+                Arc::new(timestamp),
+                Arc::new(open),
+                Arc::new(high),
+                Arc::new(low),
+                Arc::new(close),
+                Arc::new(volume),
+            ],
+        )?)
+    }
+}
+
+impl Drop for ParquetWriter {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            if let Err(e) = self.flush() {
+                error!("Failed to flush pending candles while dropping ParquetWriter: {}", e);
+            }
+        }
+    }
+}
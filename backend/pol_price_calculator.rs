@@ -1,4 +1,5 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use ethers::types::U256;
 use tracing::{debug, info, error};
 
 #[derive(Debug, Clone)]
@@ -19,13 +20,86 @@ pub struct SwapEvent {
     pub amount1_out_raw: u128,
     pub tx_hash: String,
     pub block_number: u64,
+    /// Current pool reserves, only populated for StableSwap (Curve-style) pools.
+    /// When present alongside `amplification` for a pair of stable-pegged tokens,
+    /// `POLPriceCalculator` prices the swap off the StableSwap invariant instead of
+    /// the simple input/output ratio.
+    pub pool_balance0_raw: Option<u128>,
+    pub pool_balance1_raw: Option<u128>,
+    pub amplification: Option<u64>,
+    /// Pool's swap fee, in basis points (e.g. Uniswap V3: 5/30/100 for the
+    /// 0.05%/0.30%/1.00% tiers; Uniswap V2: a flat 30). `None` when the fee tier is
+    /// unknown, in which case `POLPriceCalculator::DEFAULT_FEE_BPS` is assumed.
+    pub fee_tier_bps: Option<u32>,
+}
+
+/// Fixed-point amount, scaled to 1e18 regardless of the token's native `decimals`, so
+/// amounts of tokens with different decimals (POL's 18 vs USDC's 6) can be compared
+/// and divided without ever round-tripping through `f64` and losing precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UQ(U256);
+
+impl UQ {
+    /// Internal fixed-point scale.
+    pub const SCALE: u128 = 1_000_000_000_000_000_000;
+
+    /// Normalize a raw on-chain amount (in the token's native `decimals`) to the
+    /// common 1e18 scale.
+    pub fn from_raw(raw_amount: u128, decimals: u8) -> Result<Self> {
+        let diff = 18i32 - decimals as i32;
+        let value = U256::from(raw_amount);
+        let scaled = if diff > 0 {
+            value
+                .checked_mul(U256::from(10u128.pow(diff as u32)))
+                .context("Overflow normalizing amount to fixed point")?
+        } else if diff < 0 {
+            value / U256::from(10u128.pow((-diff) as u32))
+        } else {
+            value
+        };
+        Ok(Self(scaled))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// `self / other`, computed in fixed-point before the single, display-only
+    /// conversion to `f64`.
+    pub fn ratio(&self, other: &UQ) -> Result<f64> {
+        if other.is_zero() {
+            return Err(anyhow!("Division by zero computing UQ ratio"));
+        }
+        let scaled = self
+            .0
+            .checked_mul(U256::from(Self::SCALE))
+            .context("Overflow computing UQ ratio")?
+            / other.0;
+        Ok(Self(scaled).as_f64())
+    }
+
+    /// Convert to `f64` for display/logging only — never feed this back into further
+    /// fixed-point arithmetic.
+    pub fn as_f64(&self) -> f64 {
+        self.0.as_u128() as f64 / Self::SCALE as f64
+    }
 }
 
 #[derive(Debug)]
 pub struct PriceCalculation {
     pub raw_price: f64,
     pub inverted_price: f64,
+    /// Price as executed on-chain, with no further fee adjustment.
     pub final_price: f64,
+    /// `final_price` after deducting the pool's own swap fee, i.e. the price an
+    /// arbitrageur would actually realize trading through this pool again. Always
+    /// `<= final_price`.
+    pub net_final_price: f64,
+    /// Smallest `base_token` trade size considered economically worth taking,
+    /// given the pool's fee and `POLPriceCalculator::MIN_VIABLE_TRADE_USD`. `None`
+    /// when `quote_token` isn't a stablecoin, since the USD-denominated floor can't
+    /// be converted into `base_token` units.
+    pub min_tx_amount: Option<f64>,
     pub base_token: String,
     pub quote_token: String,
     pub calculation_steps: Vec<String>,
@@ -36,6 +110,15 @@ pub struct POLPriceCalculator {
 }
 
 impl POLPriceCalculator {
+    /// Assumed fee tier when `SwapEvent::fee_tier_bps` is unknown: Uniswap V2's flat
+    /// 0.30% fee, the most common case for the QuickSwap-style pools this was
+    /// written for.
+    const DEFAULT_FEE_BPS: u32 = 30;
+
+    /// Conservative floor, in USD, below which a trade isn't worth taking once the
+    /// pool's fee (and, implicitly, gas) are accounted for.
+    const MIN_VIABLE_TRADE_USD: f64 = 1.0;
+
     pub fn new(debug_mode: bool) -> Self {
         Self { debug_mode }
     }
@@ -50,35 +133,37 @@ impl POLPriceCalculator {
         info!("  Token1: {} ({} decimals)", swap.token1.symbol, swap.token1.decimals);
         info!("  TX: {}", swap.tx_hash);
 
-        // Step 1: Convert raw amounts to decimal-adjusted amounts
-        let amount0_in = self.convert_raw_amount(swap.amount0_in_raw, swap.token0.decimals);
-        let amount1_in = self.convert_raw_amount(swap.amount1_in_raw, swap.token1.decimals);
-        let amount0_out = self.convert_raw_amount(swap.amount0_out_raw, swap.token0.decimals);
-        let amount1_out = self.convert_raw_amount(swap.amount1_out_raw, swap.token1.decimals);
+        // Step 1: Normalize raw amounts to fixed-point, never through f64 - a token0
+        // decimals/token1 decimals mismatch (e.g. USDC's 6 vs POL's 18) round-trips
+        // through `f64` division badly otherwise.
+        let amount0_in = UQ::from_raw(swap.amount0_in_raw, swap.token0.decimals)?;
+        let amount1_in = UQ::from_raw(swap.amount1_in_raw, swap.token1.decimals)?;
+        let amount0_out = UQ::from_raw(swap.amount0_out_raw, swap.token0.decimals)?;
+        let amount1_out = UQ::from_raw(swap.amount1_out_raw, swap.token1.decimals)?;
 
-        steps.push(format!("Raw amounts: 0_in={}, 1_in={}, 0_out={}, 1_out={}", 
+        steps.push(format!("Raw amounts: 0_in={}, 1_in={}, 0_out={}, 1_out={}",
             swap.amount0_in_raw, swap.amount1_in_raw, swap.amount0_out_raw, swap.amount1_out_raw));
-        steps.push(format!("Decimal-adjusted: 0_in={:.6}, 1_in={:.6}, 0_out={:.6}, 1_out={:.6}", 
-            amount0_in, amount1_in, amount0_out, amount1_out));
+        steps.push(format!("Decimal-adjusted: 0_in={:.6}, 1_in={:.6}, 0_out={:.6}, 1_out={:.6}",
+            amount0_in.as_f64(), amount1_in.as_f64(), amount0_out.as_f64(), amount1_out.as_f64()));
 
         info!("  📊 AMOUNTS:");
-        info!("    Raw: amount0_in={}, amount1_in={}, amount0_out={}, amount1_out={}", 
+        info!("    Raw: amount0_in={}, amount1_in={}, amount0_out={}, amount1_out={}",
             swap.amount0_in_raw, swap.amount1_in_raw, swap.amount0_out_raw, swap.amount1_out_raw);
-        info!("    Adjusted: amount0_in={:.6}, amount1_in={:.6}, amount0_out={:.6}, amount1_out={:.6}", 
-            amount0_in, amount1_in, amount0_out, amount1_out);
+        info!("    Adjusted: amount0_in={:.6}, amount1_in={:.6}, amount0_out={:.6}, amount1_out={:.6}",
+            amount0_in.as_f64(), amount1_in.as_f64(), amount0_out.as_f64(), amount1_out.as_f64());
 
         // Step 2: Determine swap direction and calculate raw price
-        let (raw_price, swap_direction) = if amount0_in > 0.0 && amount1_out > 0.0 {
+        let (raw_price, swap_direction) = if !amount0_in.is_zero() && !amount1_out.is_zero() {
             // Selling token0 for token1: price = token1_out / token0_in
-            let price = amount1_out / amount0_in;
-            steps.push(format!("Swap direction: Selling {} for {} (price = {}/{} = {:.6})", 
-                swap.token0.symbol, swap.token1.symbol, amount1_out, amount0_in, price));
+            let price = amount1_out.ratio(&amount0_in)?;
+            steps.push(format!("Swap direction: Selling {} for {} (price = {:.6}/{:.6} = {:.6})",
+                swap.token0.symbol, swap.token1.symbol, amount1_out.as_f64(), amount0_in.as_f64(), price));
             (price, format!("{}->{}", swap.token0.symbol, swap.token1.symbol))
-        } else if amount1_in > 0.0 && amount0_out > 0.0 {
+        } else if !amount1_in.is_zero() && !amount0_out.is_zero() {
             // Selling token1 for token0: price = token1_in / token0_out (inverted)
-            let price = amount1_in / amount0_out;
-            steps.push(format!("Swap direction: Selling {} for {} (price = {}/{} = {:.6})", 
-                swap.token1.symbol, swap.token0.symbol, amount1_in, amount0_out, price));
+            let price = amount1_in.ratio(&amount0_out)?;
+            steps.push(format!("Swap direction: Selling {} for {} (price = {:.6}/{:.6} = {:.6})",
+                swap.token1.symbol, swap.token0.symbol, amount1_in.as_f64(), amount0_out.as_f64(), price));
             (price, format!("{}->{}", swap.token1.symbol, swap.token0.symbol))
         } else {
             return Err(anyhow::anyhow!("Invalid swap: no clear direction"));
@@ -88,6 +173,31 @@ impl POLPriceCalculator {
         info!("    Direction: {}", swap_direction);
         info!("    Raw price: {:.6}", raw_price);
 
+        // Step 2b: Stablecoin pairs trade on the StableSwap invariant, not constant
+        // product, so the input/output ratio above is only an approximation there.
+        // Replace it with the true marginal price when pool state is available.
+        let raw_price = if Self::is_stable_token(&swap.token0.symbol) && Self::is_stable_token(&swap.token1.symbol) {
+            match (swap.pool_balance0_raw, swap.pool_balance1_raw, swap.amplification) {
+                (Some(balance0), Some(balance1), Some(amplification)) => {
+                    match self.calculate_stableswap_price(swap, balance0, balance1, amplification, &swap_direction) {
+                        Ok(price) => {
+                            steps.push(format!("StableSwap invariant price (A={}): {:.6}", amplification, price));
+                            info!("    StableSwap invariant price (A={}): {:.6}", amplification, price);
+                            price
+                        }
+                        Err(e) => {
+                            steps.push(format!("StableSwap pricing failed ({}), falling back to ratio", e));
+                            debug!("StableSwap pricing failed for {}: {}, falling back to ratio", swap.pool_address, e);
+                            raw_price
+                        }
+                    }
+                }
+                _ => raw_price, // no pool state supplied; fall back to the simple ratio
+            }
+        } else {
+            raw_price
+        };
+
         // Step 3: Determine quote currency and price orientation
         let (base_token, quote_token, should_invert) = self.determine_price_orientation(
             &swap.token0.symbol, &swap.token1.symbol, &swap_direction)?;
@@ -119,27 +229,100 @@ impl POLPriceCalculator {
             self.validate_pol_price(final_price, &base_token, &quote_token, &mut steps)?;
         }
 
+        // Step 6: Fee-adjusted net price and dust threshold, so callers get a
+        // realistic arbitrage price instead of recomputing fees themselves.
+        let fee_bps = swap.fee_tier_bps.unwrap_or(Self::DEFAULT_FEE_BPS);
+        let net_final_price = final_price * (1.0 - fee_bps as f64 / 10_000.0);
+        let min_tx_amount = if Self::is_stable_token(&quote_token) && net_final_price > 0.0 {
+            Some(Self::MIN_VIABLE_TRADE_USD / net_final_price)
+        } else {
+            None
+        };
+
+        steps.push(format!(
+            "Fee-adjusted: {} bps fee -> net price {:.6} (gross {:.6}), min viable trade {}",
+            fee_bps,
+            net_final_price,
+            final_price,
+            min_tx_amount.map(|a| format!("{:.6} {}", a, base_token)).unwrap_or_else(|| "n/a".to_string())
+        ));
+        info!("  💸 FEE-ADJUSTED:");
+        info!("    Fee tier: {} bps", fee_bps);
+        info!("    Net price: {:.6} {} per {} (gross {:.6})", net_final_price, quote_token, base_token, final_price);
+
         Ok(PriceCalculation {
             raw_price,
             inverted_price: if should_invert { 1.0 / raw_price } else { raw_price },
             final_price,
+            net_final_price,
+            min_tx_amount,
             base_token,
             quote_token,
             calculation_steps: steps,
         })
     }
 
-    fn convert_raw_amount(&self, raw_amount: u128, decimals: u8) -> f64 {
-        (raw_amount as f64) / (10_f64.powi(decimals as i32))
+    /// Tokens priced 1:1 against the dollar, and so traded via a StableSwap pool
+    /// rather than a constant-product one.
+    fn is_stable_token(token: &str) -> bool {
+        matches!(token, "USDC" | "USDT" | "DAI" | "USD")
     }
 
-    fn determine_price_orientation(&self, token0: &str, token1: &str, swap_direction: &str) 
-        -> Result<(String, String, bool)> {
-        
-        let is_quote_currency = |token: &str| -> bool {
-            matches!(token, "USDC" | "USDT" | "DAI" | "USD")
+    /// Whether `swap_direction` (`"{token0}->{token1}"`) has `token` as its
+    /// input side. A bare `starts_with(token)` would also match e.g.
+    /// `"USDC->USD"` against `token == "USD"`, since `"USDC"` has `"USD"` as a
+    /// literal prefix - exactly the pairing `is_stable_token`'s whitelist
+    /// guarantees will occur. Matching against `"{token}->"` instead requires
+    /// the full token symbol up to the delimiter.
+    fn swap_direction_starts_with(swap_direction: &str, token: &str) -> bool {
+        swap_direction.starts_with(&format!("{}->", token))
+    }
+
+    /// Price a stablecoin-pair swap off the StableSwap invariant using the pool's
+    /// current reserves, rather than the swap's own input/output ratio.
+    fn calculate_stableswap_price(
+        &self,
+        swap: &SwapEvent,
+        pool_balance0_raw: u128,
+        pool_balance1_raw: u128,
+        amplification: u64,
+        swap_direction: &str,
+    ) -> Result<f64> {
+        let balance0 = Self::normalize_balance(pool_balance0_raw, swap.token0.decimals)?;
+        let balance1 = Self::normalize_balance(pool_balance1_raw, swap.token1.decimals)?;
+        let balances = vec![balance0, balance1];
+
+        let (in_index, out_index) = if Self::swap_direction_starts_with(swap_direction, &swap.token0.symbol) {
+            (0, 1)
+        } else {
+            (1, 0)
         };
 
+        StableSwapCalculator::marginal_price(&balances, in_index, out_index, amplification)
+    }
+
+    /// Scale a raw token amount to an 18-decimal fixed-point `U256`, the common basis
+    /// StableSwap math is computed in so balances of tokens with different decimals
+    /// (e.g. USDC's 6 vs DAI's 18) compare like-for-like.
+    fn normalize_balance(raw: u128, decimals: u8) -> Result<U256> {
+        const TARGET_DECIMALS: i32 = 18;
+        let diff = TARGET_DECIMALS - decimals as i32;
+        let value = U256::from(raw);
+        if diff > 0 {
+            value.checked_mul(U256::from(10u128.pow(diff as u32)))
+                .ok_or_else(|| anyhow!("Overflow normalizing balance to 18 decimals"))
+        } else if diff < 0 {
+            Ok(value / U256::from(10u128.pow((-diff) as u32)))
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn determine_price_orientation(&self, token0: &str, token1: &str, swap_direction: &str)
+        -> Result<(String, String, bool)> {
+
+        let is_quote_currency = Self::is_stable_token;
+
         // For POL pairs, we want POL as base and stablecoin as quote
         // So POL/USDC means: price in USDC per POL (like $0.23 per POL)
         
@@ -147,20 +330,20 @@ impl POLPriceCalculator {
             // POL is token0, stablecoin is token1 - perfect
             // If swap was POL->USDC, price is already correct (USDC per POL)
             // If swap was USDC->POL, price needs inversion
-            let should_invert = swap_direction.starts_with(token1);
+            let should_invert = Self::swap_direction_starts_with(swap_direction, token1);
             Ok((token0.to_string(), token1.to_string(), should_invert))
         } else if token1 == "POL" && is_quote_currency(token0) {
             // POL is token1, stablecoin is token0 - need to swap roles
             // We want POL as base, so invert if swap was stablecoin->POL
-            let should_invert = !swap_direction.starts_with(token0);
+            let should_invert = !Self::swap_direction_starts_with(swap_direction, token0);
             Ok((token1.to_string(), token0.to_string(), should_invert))
         } else if is_quote_currency(token1) {
             // token1 is quote, token0 is base
-            let should_invert = swap_direction.starts_with(token1);
+            let should_invert = Self::swap_direction_starts_with(swap_direction, token1);
             Ok((token0.to_string(), token1.to_string(), should_invert))
         } else if is_quote_currency(token0) {
             // token0 is quote, token1 is base
-            let should_invert = swap_direction.starts_with(token0);
+            let should_invert = Self::swap_direction_starts_with(swap_direction, token0);
             Ok((token1.to_string(), token0.to_string(), should_invert))
         } else {
             // Neither is quote - default to token0 as base, token1 as quote
@@ -215,6 +398,10 @@ impl POLPriceCalculator {
             amount1_in_raw: 0,
             amount0_out_raw: 0,
             amount1_out_raw: 230000000, // 230 USDC (6 decimals)
+            pool_balance0_raw: None,
+            pool_balance1_raw: None,
+            amplification: None,
+            fee_tier_bps: None,
             tx_hash: "0x1234567890abcdef".to_string(),
             block_number: 12345,
         };
@@ -236,6 +423,213 @@ impl POLPriceCalculator {
     }
 }
 
+/// StableSwap (Curve-style) invariant math for pools of like-pegged stablecoins.
+///
+/// Unlike constant-product AMMs, StableSwap keeps the price near 1:1 across a wide
+/// range of balances by blending a constant-sum and constant-product curve via the
+/// amplification coefficient `A`. There's no closed-form spot price, so both the
+/// invariant `D` and the counterfactual balance `y` are solved with Newton iteration,
+/// per the reference implementation in Curve's `StableSwap.vy`.
+pub struct StableSwapCalculator;
+
+impl StableSwapCalculator {
+    /// Newton iteration on D is guaranteed to converge in practice well under this
+    /// many steps; treat exceeding it as a sign the pool state is degenerate.
+    const MAX_ITERATIONS: u32 = 32;
+
+    /// Amplification coefficient scaled by `n^n`, as used throughout the invariant.
+    fn ann(n: usize, amplification: u64) -> Result<U256> {
+        let n_u256 = U256::from(n as u64);
+        let n_to_n = (0..n)
+            .try_fold(U256::one(), |acc, _| acc.checked_mul(n_u256))
+            .ok_or_else(|| anyhow!("Overflow computing n^n"))?;
+        U256::from(amplification)
+            .checked_mul(n_to_n)
+            .ok_or_else(|| anyhow!("Overflow computing Ann"))
+    }
+
+    /// Solve the StableSwap invariant `D` for the given balances via Newton iteration:
+    /// `D_P = D_P * D / (n * x_i)` per balance, then
+    /// `D = (Ann*S + n*D_P) * D / ((Ann-1)*D + (n+1)*D_P)`, until `|D - D_prev| <= 1`.
+    pub fn compute_d(balances: &[U256], amplification: u64) -> Result<U256> {
+        let n = balances.len();
+        if n == 0 {
+            return Err(anyhow!("StableSwap pool has no balances"));
+        }
+        if balances.iter().any(|b| b.is_zero()) {
+            return Err(anyhow!("StableSwap balances cannot be zero"));
+        }
+
+        let n_u256 = U256::from(n as u64);
+        let ann = Self::ann(n, amplification)?;
+
+        let s = balances
+            .iter()
+            .try_fold(U256::zero(), |acc, b| acc.checked_add(*b))
+            .ok_or_else(|| anyhow!("Overflow summing balances"))?;
+        if s.is_zero() {
+            return Ok(U256::zero());
+        }
+
+        let mut d = s;
+        for _ in 0..Self::MAX_ITERATIONS {
+            let mut d_p = d;
+            for balance in balances {
+                let denom = n_u256
+                    .checked_mul(*balance)
+                    .ok_or_else(|| anyhow!("Overflow in D_P denominator"))?;
+                d_p = d_p
+                    .checked_mul(d)
+                    .ok_or_else(|| anyhow!("Overflow in D_P"))?
+                    / denom;
+            }
+
+            let d_prev = d;
+            let numerator = ann
+                .checked_mul(s)
+                .ok_or_else(|| anyhow!("Overflow in Ann*S"))?
+                .checked_add(n_u256.checked_mul(d_p).ok_or_else(|| anyhow!("Overflow in n*D_P"))?)
+                .ok_or_else(|| anyhow!("Overflow in Ann*S + n*D_P"))?
+                .checked_mul(d)
+                .ok_or_else(|| anyhow!("Overflow in numerator*D"))?;
+            let denominator = ann
+                .checked_sub(U256::one())
+                .ok_or_else(|| anyhow!("Ann underflow"))?
+                .checked_mul(d)
+                .ok_or_else(|| anyhow!("Overflow in (Ann-1)*D"))?
+                .checked_add(
+                    n_u256
+                        .checked_add(U256::one())
+                        .ok_or_else(|| anyhow!("Overflow in n+1"))?
+                        .checked_mul(d_p)
+                        .ok_or_else(|| anyhow!("Overflow in (n+1)*D_P"))?,
+                )
+                .ok_or_else(|| anyhow!("Overflow in denominator sum"))?;
+
+            if denominator.is_zero() {
+                return Err(anyhow!("StableSwap invariant denominator is zero"));
+            }
+            d = numerator / denominator;
+
+            let diff = if d > d_prev { d - d_prev } else { d_prev - d };
+            if diff <= U256::one() {
+                return Ok(d);
+            }
+        }
+
+        Err(anyhow!(
+            "StableSwap invariant D failed to converge after {} iterations",
+            Self::MAX_ITERATIONS
+        ))
+    }
+
+    /// Solve for `out_index`'s balance that holds `D` constant after `in_index`'s
+    /// balance changes to `new_in_balance`, via Newton iteration on
+    /// `y = (y^2 + c) / (2y + b - D)`, where
+    /// `c = D^(n+1) / (n^n * Π(x_j≠out) * Ann)` and `b = Σ(x_j≠out) + D/Ann`.
+    pub fn compute_y(
+        balances: &[U256],
+        in_index: usize,
+        out_index: usize,
+        new_in_balance: U256,
+        amplification: u64,
+    ) -> Result<U256> {
+        let n = balances.len();
+        if in_index == out_index || in_index >= n || out_index >= n {
+            return Err(anyhow!("Invalid StableSwap token indices"));
+        }
+
+        let n_u256 = U256::from(n as u64);
+        let ann = Self::ann(n, amplification)?;
+        let d = Self::compute_d(balances, amplification)?;
+
+        let mut c = d;
+        let mut s = U256::zero();
+        for (i, balance) in balances.iter().enumerate() {
+            if i == out_index {
+                continue;
+            }
+            let x = if i == in_index { new_in_balance } else { *balance };
+            if x.is_zero() {
+                return Err(anyhow!("StableSwap balance cannot be zero"));
+            }
+            let denom = n_u256.checked_mul(x).ok_or_else(|| anyhow!("Overflow in c denominator"))?;
+            c = c.checked_mul(d).ok_or_else(|| anyhow!("Overflow in c"))? / denom;
+            s = s.checked_add(x).ok_or_else(|| anyhow!("Overflow summing S'"))?;
+        }
+        c = c.checked_mul(d).ok_or_else(|| anyhow!("Overflow scaling c by D"))?
+            / n_u256.checked_mul(ann).ok_or_else(|| anyhow!("Overflow n*Ann"))?;
+
+        let b = s.checked_add(d / ann).ok_or_else(|| anyhow!("Overflow computing b"))?;
+
+        let mut y = d;
+        for _ in 0..Self::MAX_ITERATIONS {
+            let y_prev = y;
+            let numerator = y
+                .checked_mul(y)
+                .ok_or_else(|| anyhow!("Overflow in y^2"))?
+                .checked_add(c)
+                .ok_or_else(|| anyhow!("Overflow in y^2 + c"))?;
+            let two_y_plus_b = y
+                .checked_mul(U256::from(2u64))
+                .ok_or_else(|| anyhow!("Overflow in 2y"))?
+                .checked_add(b)
+                .ok_or_else(|| anyhow!("Overflow in 2y + b"))?;
+            if two_y_plus_b < d {
+                return Err(anyhow!("StableSwap y iteration went negative"));
+            }
+            let denominator = two_y_plus_b - d;
+            if denominator.is_zero() {
+                return Err(anyhow!("StableSwap y denominator is zero"));
+            }
+            y = numerator / denominator;
+
+            let diff = if y > y_prev { y - y_prev } else { y_prev - y };
+            if diff <= U256::one() {
+                return Ok(y);
+            }
+        }
+
+        Err(anyhow!(
+            "StableSwap y failed to converge after {} iterations",
+            Self::MAX_ITERATIONS
+        ))
+    }
+
+    /// Marginal price of `in_token` in terms of `out_token` at the current reserves:
+    /// how much `out_token` balance must shrink for a small increase in `in_token`
+    /// balance, approximated as a finite difference since the Newton solution for
+    /// `y` has no closed-form derivative.
+    pub fn marginal_price(
+        balances: &[U256],
+        in_index: usize,
+        out_index: usize,
+        amplification: u64,
+    ) -> Result<f64> {
+        if in_index >= balances.len() || out_index >= balances.len() {
+            return Err(anyhow!("Invalid StableSwap token indices"));
+        }
+
+        let balance_in = balances[in_index];
+        let y0 = balances[out_index];
+
+        // A bump relative to the input reserve keeps the finite difference close to
+        // the true derivative without losing precision to integer rounding.
+        let bump = (balance_in / U256::from(1_000_000u64)).max(U256::one());
+        let bumped_in = balance_in
+            .checked_add(bump)
+            .ok_or_else(|| anyhow!("Overflow bumping input balance"))?;
+        let y1 = Self::compute_y(balances, in_index, out_index, bumped_in, amplification)?;
+
+        if y1 >= y0 {
+            return Err(anyhow!("StableSwap output balance did not decrease with input balance"));
+        }
+        let dy = y0 - y1;
+
+        Ok(dy.as_u128() as f64 / bump.as_u128() as f64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +639,66 @@ mod tests {
         let calculator = POLPriceCalculator::new(true);
         calculator.test_known_transaction().unwrap();
     }
+
+    #[test]
+    fn test_fee_adjusted_price_is_below_gross_and_dust_threshold_is_set() {
+        let calculator = POLPriceCalculator::new(false);
+        let swap = SwapEvent {
+            pool_address: "0x5b0d2536f0c970b8d9cbf3959460fb97ce808ade".to_string(),
+            token0: TokenInfo { symbol: "POL".to_string(), address: "0xpol".to_string(), decimals: 18 },
+            token1: TokenInfo { symbol: "USDC".to_string(), address: "0xusdc".to_string(), decimals: 6 },
+            amount0_in_raw: 1_000_000_000_000_000_000_000,
+            amount1_in_raw: 0,
+            amount0_out_raw: 0,
+            amount1_out_raw: 230_000_000,
+            pool_balance0_raw: None,
+            pool_balance1_raw: None,
+            amplification: None,
+            fee_tier_bps: Some(5), // Uniswap V3 0.05% tier
+            tx_hash: "0xfee".to_string(),
+            block_number: 1,
+        };
+
+        let result = calculator.calculate_price(&swap).unwrap();
+        assert!(result.net_final_price < result.final_price);
+        assert!((result.net_final_price - result.final_price * (1.0 - 0.0005)).abs() < 1e-9);
+        assert!(result.min_tx_amount.is_some());
+    }
+
+    #[test]
+    fn test_stableswap_d_balanced_pool_equals_sum() {
+        // For a perfectly balanced pool, D is exactly the sum of balances regardless
+        // of amplification (the invariant collapses to constant-sum at the center).
+        let balances = vec![U256::from(1_000_000u64), U256::from(1_000_000u64)];
+        let d = StableSwapCalculator::compute_d(&balances, 100).unwrap();
+        assert_eq!(d, U256::from(2_000_000u64));
+    }
+
+    #[test]
+    fn test_stableswap_y_roundtrip() {
+        let balances = vec![U256::from(1_000_000u64), U256::from(1_000_000u64)];
+        let d = StableSwapCalculator::compute_d(&balances, 100).unwrap();
+
+        // Moving 1_000 units into balance 0 and solving for balance 1 should hold D.
+        let new_balance0 = balances[0] + U256::from(1_000u64);
+        let new_balance1 = StableSwapCalculator::compute_y(&balances, 0, 1, new_balance0, 100).unwrap();
+
+        let new_balances = vec![new_balance0, new_balance1];
+        let d_after = StableSwapCalculator::compute_d(&new_balances, 100).unwrap();
+        let diff = if d_after > d { d_after - d } else { d - d_after };
+        assert!(diff <= U256::from(2u64), "D should be held (near-)constant by y");
+    }
+
+    #[test]
+    fn test_stableswap_marginal_price_near_balanced_pool_is_near_one() {
+        let balances = vec![U256::from(1_000_000_000u64), U256::from(1_000_000_000u64)];
+        let price = StableSwapCalculator::marginal_price(&balances, 0, 1, 100).unwrap();
+        assert!((price - 1.0).abs() < 0.01, "balanced stable pool should price near 1:1, got {}", price);
+    }
+
+    #[test]
+    fn test_stableswap_rejects_zero_balance() {
+        let balances = vec![U256::from(1_000_000u64), U256::zero()];
+        assert!(StableSwapCalculator::compute_d(&balances, 100).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+//! Batched Ed25519 verification for signed/authenticated message feeds.
+//!
+//! Mirrors the per-message signing scheme in `protocol_v2`'s
+//! `validation::signature` module, but verifies a whole
+//! [`SchemaTransformCache::process_signed_batch`](crate::schema_transform_cache::SchemaTransformCache::process_signed_batch)
+//! batch in one call instead of one message at a time - the same shape as
+//! Solana's batched `ed25519-dalek` verification path, which amortizes the
+//! cost of checking many signatures by verifying them together rather than
+//! sequentially. The `batch-verify` feature switches that batched call to the
+//! SIMD-accelerated path; without it, frames are verified one at a time on
+//! the CPU.
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+/// One frame's `(public key, signature, message)` triple, ready to be
+/// verified as part of a batch.
+#[derive(Debug, Clone, Copy)]
+pub struct SignedFrame<'a> {
+    pub public_key: VerifyingKey,
+    pub signature: Signature,
+    pub message: &'a [u8],
+}
+
+/// Verify every frame in `frames` together. Returns `false` if any single
+/// frame fails verification - callers that need to know *which* frame failed
+/// should verify frames individually instead.
+pub fn verify_batch(frames: &[SignedFrame<'_>]) -> bool {
+    if frames.is_empty() {
+        return true;
+    }
+
+    #[cfg(feature = "batch-verify")]
+    {
+        verify_batch_simd(frames)
+    }
+    #[cfg(not(feature = "batch-verify"))]
+    {
+        verify_batch_sequential(frames)
+    }
+}
+
+/// Batched verification through `ed25519-dalek`'s `verify_batch`, following
+/// the same API shape as Solana's batch verifier; it runs on the CPU, there is
+/// no GPU path. Requires the `batch-verify`
+/// feature (which in turn enables `ed25519-dalek`'s `batch` feature).
+#[cfg(feature = "batch-verify")]
+fn verify_batch_simd(frames: &[SignedFrame<'_>]) -> bool {
+    let messages: Vec<&[u8]> = frames.iter().map(|frame| frame.message).collect();
+    let signatures: Vec<Signature> = frames.iter().map(|frame| frame.signature).collect();
+    let keys: Vec<VerifyingKey> = frames.iter().map(|frame| frame.public_key).collect();
+    ed25519_dalek::verify_batch(&messages, &signatures, &keys).is_ok()
+}
+
+/// Sequential CPU fallback used when the `batch-verify` feature is off.
+fn verify_batch_sequential(frames: &[SignedFrame<'_>]) -> bool {
+    frames
+        .iter()
+        .all(|frame| frame.public_key.verify(frame.message, &frame.signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn verify_batch_accepts_all_valid_signatures() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let messages: [&[u8]; 2] = [b"frame one", b"frame two"];
+        let frames: Vec<SignedFrame> = messages
+            .iter()
+            .map(|message| SignedFrame {
+                public_key: signing_key.verifying_key(),
+                signature: signing_key.sign(message),
+                message,
+            })
+            .collect();
+
+        assert!(verify_batch(&frames));
+    }
+
+    #[test]
+    fn verify_batch_rejects_any_tampered_frame() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let good_message: &[u8] = b"frame one";
+        let mut frames = vec![SignedFrame {
+            public_key: signing_key.verifying_key(),
+            signature: signing_key.sign(good_message),
+            message: good_message,
+        }];
+        let tampered_message: &[u8] = b"frame two (tampered)";
+        frames.push(SignedFrame {
+            public_key: signing_key.verifying_key(),
+            signature: signing_key.sign(b"frame two"),
+            message: tampered_message,
+        });
+
+        assert!(!verify_batch(&frames));
+    }
+}
@@ -0,0 +1,87 @@
+// Exact-integer amount type for processed message data. Converting on-chain
+// fixed-point integers straight to `f64` (e.g. `price as f64 / 1e8`) loses
+// precision for large token amounts and wei-scale reserves, and bakes the
+// lossy conversion into the parse path where display code can't opt out of
+// it. `FixedAmount` keeps the raw integer and its scaling exponent instead,
+// so that conversion only happens where a caller explicitly asks for it.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An exact integer amount plus the power-of-ten it's scaled by, e.g.
+/// `raw: 15_000_000_000, decimals: 8` for a price of `150.0`. Serializes
+/// `raw` as `"0x..."` hex (the canonical on-chain representation) but
+/// deserializes from either `"0x..."` hex or a plain decimal string, mirroring
+/// the arbitrage service's `mev_protection::Wei` convention for amounts
+/// sourced from mixed hex/decimal feeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FixedAmount {
+    pub raw: u128,
+    pub decimals: u8,
+}
+
+impl FixedAmount {
+    pub fn new(raw: u128, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Explicit, lossy float conversion for display callers that still want
+    /// a float - never applied implicitly on the parse path.
+    pub fn to_f64_lossy(&self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+}
+
+impl Serialize for FixedAmount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FixedAmount", 2)?;
+        state.serialize_field("raw", &format!("{:#x}", self.raw))?;
+        state.serialize_field("decimals", &self.decimals)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FixedAmount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            raw: String,
+            decimals: u8,
+        }
+        let raw = Raw::deserialize(deserializer)?;
+        let value = if let Some(hex) = raw.raw.strip_prefix("0x").or_else(|| raw.raw.strip_prefix("0X")) {
+            u128::from_str_radix(hex, 16).map_err(serde::de::Error::custom)?
+        } else {
+            raw.raw.parse::<u128>().map_err(serde::de::Error::custom)?
+        };
+        Ok(FixedAmount {
+            raw: value,
+            decimals: raw.decimals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_hex() {
+        let amount = FixedAmount::new(15_000_000_000, 8);
+        let json = serde_json::to_string(&amount).unwrap();
+        let parsed: FixedAmount = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, amount);
+    }
+
+    #[test]
+    fn accepts_decimal_input() {
+        let parsed: FixedAmount =
+            serde_json::from_str(r#"{"raw":"15000000000","decimals":8}"#).unwrap();
+        assert_eq!(parsed, FixedAmount::new(15_000_000_000, 8));
+    }
+
+    #[test]
+    fn to_f64_lossy_applies_scale() {
+        let amount = FixedAmount::new(15_000_000_000, 8);
+        assert!((amount.to_f64_lossy() - 150.0).abs() < 1e-9);
+    }
+}
@@ -15,6 +15,9 @@ pub mod dex_config;
 pub mod message_protocol;
 pub mod messages;
 pub mod schema_transform_cache;
+pub mod fixed_amount;
+pub mod batch_verify;
+pub mod sinks;
 // TokenRegistry is in exchange_collector crate (alphapulse_exchange_collector::token_registry)
 
 pub const MAGIC_BYTE: u8 = 0xFE;
@@ -2528,4 +2531,7 @@ pub use schema_transform_cache::{
     SchemaTransformCache, InstrumentMetadata, TokenMetadata, PoolMetadata,
     CachedObject, ProcessedMessage, TradeData, QuoteData, CacheStats,
     SwapEventData, PoolUpdateData, ArbitrageData
-};
\ No newline at end of file
+};
+pub use fixed_amount::FixedAmount;
+pub use batch_verify::SignedFrame;
+pub use sinks::{MessageSink, SinkErrorPolicy, SinkFilter, BroadcastSink, FileSink};
\ No newline at end of file
@@ -24,6 +24,10 @@ pub enum ParseError {
     InvalidVenueId(u16),
     #[error("Invalid asset type: {0}")]
     InvalidAssetType(u8),
+    #[error("Signature verification failed")]
+    InvalidSignature,
+    #[error("Dynamic template parser failed: {0}")]
+    DynamicParseFailed(String),
 }
 
 /// Message type discriminants
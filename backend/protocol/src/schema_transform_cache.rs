@@ -1,24 +1,58 @@
 use crate::message_protocol::{InstrumentId, MessageType, ParseError};
 use crate::messages::{TradeMessage, QuoteMessage, InstrumentDiscoveredMessage, SwapEventMessage, PoolUpdateMessage, ArbitrageOpportunityMessage};
+use crate::fixed_amount::FixedAmount;
 use dashmap::DashMap;
+use rayon::prelude::*;
 use std::sync::Arc;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use std::any::Any;
-use zerocopy::AsBytes;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use zerocopy::{AsBytes, FromBytes};
 
 /// Schema and transform cache with full InstrumentId precision
 pub struct SchemaTransformCache {
     /// Static schemas loaded at startup
     static_schemas: std::collections::HashMap<(MessageType, u8), &'static MessageSchema>,
-    
+
     /// Dynamic schemas registered at runtime
     dynamic_schemas: DashMap<(MessageType, u8), MessageSchema>,
-    
+
     /// Object cache keyed by full InstrumentId (no truncation!)
     objects: DashMap<InstrumentId, CachedObject>,
-    
+
     /// Optional reverse lookup for legacy u64 keys
     u64_index: Option<DashMap<u64, InstrumentId>>,
+
+    /// Monotonic sequence number for the next write-ahead mutation. Every
+    /// `insert`/`remove` written to the WAL sink bumps this (nothing does
+    /// while no sink is set), so a replayed WAL tail can be resumed
+    /// exactly from `last_applied_seq()` without reapplying or skipping work.
+    wal_seq: AtomicU64,
+
+    /// Where incremental mutations get appended, if persistence is enabled.
+    /// `None` means the cache behaves exactly as before - purely in-memory.
+    wal_sink: Mutex<Option<Box<dyn Write + Send>>>,
+
+    /// Bounded per-key history of `(block_number, CachedObject)`, so a reorg
+    /// can roll a key back to exactly the canonical-chain state at a target
+    /// block instead of leaving whatever an orphaned fork last wrote.
+    versions: DashMap<InstrumentId, VecDeque<(u64, CachedObject)>>,
+    /// Maximum versions retained per key before the oldest is dropped.
+    version_depth: usize,
+
+    /// Ordered sinks registered via [`Self::register_sink`], fanned out to
+    /// after every successful parse.
+    sinks: Mutex<Vec<RegisteredSink>>,
+}
+
+/// A sink plus the filter and error policy it was registered with.
+struct RegisteredSink {
+    sink: Arc<dyn crate::sinks::MessageSink>,
+    filter: Option<crate::sinks::SinkFilter>,
+    error_policy: crate::sinks::SinkErrorPolicy,
 }
 
 /// Message schema definition
@@ -32,7 +66,13 @@ pub struct MessageSchema {
 /// Message parser trait for dynamic parsing
 pub trait MessageParser: Send + Sync {
     fn parse(&self, data: &[u8]) -> Result<Box<dyn Any>>;
-    fn to_cached_object(&self, parsed: Box<dyn Any>) -> Option<CachedObject>;
+    fn to_cached_object(&self, parsed: &dyn Any) -> Option<CachedObject>;
+
+    /// The key to cache `parsed`'s `CachedObject` under, if any. Returning
+    /// `None` means the parsed message is still reported back to the caller
+    /// but nothing is inserted into the cache (e.g. a heartbeat-like message
+    /// with no natural instrument/pool identity).
+    fn instrument_id(&self, parsed: &dyn Any) -> Option<InstrumentId>;
 }
 
 /// Cached object types
@@ -79,6 +119,263 @@ pub struct TokenMetadata {
     pub discovered_at: u64,
 }
 
+const SNAPSHOT_MAGIC: &[u8; 4] = b"STC1";
+const SNAPSHOT_VERSION: u64 = 1;
+const WAL_ENTRY_TAG: u8 = 0xAA;
+const DEFAULT_VERSION_DEPTH: usize = 64;
+
+/// A single cache mutation, recorded in WAL order.
+#[derive(Debug, Clone)]
+enum WalOp {
+    Insert(InstrumentId, CachedObject),
+    Remove(InstrumentId),
+}
+
+/// A `WalOp` tagged with the monotonic sequence it was applied at.
+#[derive(Debug, Clone)]
+struct WalEntry {
+    seq: u64,
+    op: WalOp,
+}
+
+impl WalEntry {
+    /// `[WAL_ENTRY_TAG][seq: u64][op_tag: u8]` then, for an insert, the
+    /// InstrumentId bytes followed by a length-prefixed encoded object; for
+    /// a remove, just the InstrumentId bytes.
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(WAL_ENTRY_TAG);
+        out.extend_from_slice(&self.seq.to_le_bytes());
+        match &self.op {
+            WalOp::Insert(id, object) => {
+                out.push(0);
+                out.extend_from_slice(id.as_bytes());
+                let mut obj_buf = Vec::new();
+                encode_cached_object(object, &mut obj_buf);
+                out.extend_from_slice(&(obj_buf.len() as u64).to_le_bytes());
+                out.extend_from_slice(&obj_buf);
+            }
+            WalOp::Remove(id) => {
+                out.push(1);
+                out.extend_from_slice(id.as_bytes());
+            }
+        }
+    }
+
+    /// Decode one entry from `reader`. Returns `Ok(None)` at a clean
+    /// end-of-stream, and also at a truncated/partial entry (the only way a
+    /// crash mid-write can leave the tail) - both are "nothing more to
+    /// replay", not an error.
+    fn try_decode<R: Read>(reader: &mut R) -> Result<Option<Self>> {
+        let mut tag = [0u8; 1];
+        match reader.read(&mut tag)? {
+            0 => return Ok(None),
+            _ => {}
+        }
+        if tag[0] != WAL_ENTRY_TAG {
+            return Ok(None);
+        }
+
+        let mut seq_bytes = [0u8; 8];
+        if reader.read_exact(&mut seq_bytes).is_err() {
+            return Ok(None);
+        }
+        let seq = u64::from_le_bytes(seq_bytes);
+
+        let mut op_tag = [0u8; 1];
+        if reader.read_exact(&mut op_tag).is_err() {
+            return Ok(None);
+        }
+
+        let mut id_bytes = [0u8; std::mem::size_of::<InstrumentId>()];
+        if reader.read_exact(&mut id_bytes).is_err() {
+            return Ok(None);
+        }
+        let Some(id) = InstrumentId::read_from_prefix(&id_bytes[..]) else {
+            return Ok(None);
+        };
+
+        let op = match op_tag[0] {
+            0 => {
+                let mut len_bytes = [0u8; 8];
+                if reader.read_exact(&mut len_bytes).is_err() {
+                    return Ok(None);
+                }
+                let len = u64::from_le_bytes(len_bytes) as usize;
+                let mut buf = vec![0u8; len];
+                if reader.read_exact(&mut buf).is_err() {
+                    return Ok(None);
+                }
+                let Ok((object, _)) = decode_cached_object(&buf) else {
+                    return Ok(None);
+                };
+                WalOp::Insert(id, object)
+            }
+            1 => WalOp::Remove(id),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(WalEntry { seq, op }))
+    }
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: u64) -> Result<()> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn write_u8<W: Write>(writer: &mut W, value: u8) -> Result<()> {
+    writer.write_all(&[value])?;
+    Ok(())
+}
+
+fn read_string(buf: &[u8], offset: &mut usize) -> String {
+    let len = u64::from_le_bytes(buf[*offset..*offset + 8].try_into().unwrap()) as usize;
+    *offset += 8;
+    let s = String::from_utf8_lossy(&buf[*offset..*offset + len]).to_string();
+    *offset += len;
+    s
+}
+
+fn read_u64_field(buf: &[u8], offset: &mut usize) -> u64 {
+    let value = u64::from_le_bytes(buf[*offset..*offset + 8].try_into().unwrap());
+    *offset += 8;
+    value
+}
+
+fn read_u8_field(buf: &[u8], offset: &mut usize) -> u8 {
+    let value = buf[*offset];
+    *offset += 1;
+    value
+}
+
+fn read_instrument_id(buf: &[u8], offset: &mut usize) -> InstrumentId {
+    let size = std::mem::size_of::<InstrumentId>();
+    let id = InstrumentId::read_from_prefix(&buf[*offset..*offset + size])
+        .expect("InstrumentId is fixed-size and always decodable from its own byte width");
+    *offset += size;
+    id
+}
+
+/// Encode a `CachedObject` to its on-disk form: a variant tag followed by
+/// its fields, length-prefixed strings throughout. `Custom` has no generic
+/// wire format and is never passed in (callers filter it out beforehand).
+fn encode_cached_object(object: &CachedObject, out: &mut Vec<u8>) {
+    match object {
+        CachedObject::Instrument(meta) => {
+            out.push(0);
+            out.extend_from_slice(meta.id.as_bytes());
+            write_string_into(out, &meta.symbol);
+            out.push(meta.decimals);
+            out.extend_from_slice(&meta.discovered_at.to_le_bytes());
+            write_string_into(out, &meta.venue_name);
+            write_string_into(out, &meta.asset_type_name);
+        }
+        CachedObject::Pool(meta) => {
+            out.push(1);
+            out.extend_from_slice(meta.id.as_bytes());
+            out.extend_from_slice(meta.token0_id.as_bytes());
+            out.extend_from_slice(meta.token1_id.as_bytes());
+            write_string_into(out, &meta.symbol);
+            out.extend_from_slice(&meta.fee_tier.map(|f| f as u64).unwrap_or(u64::MAX).to_le_bytes());
+            write_string_into(out, &meta.protocol_type);
+            out.extend_from_slice(&meta.discovered_at.to_le_bytes());
+        }
+        CachedObject::Token(meta) => {
+            out.push(2);
+            out.extend_from_slice(meta.id.as_bytes());
+            write_string_into(out, &meta.address);
+            write_string_into(out, &meta.symbol);
+            write_string_into(out, &meta.name);
+            out.push(meta.decimals);
+            out.extend_from_slice(&(meta.chain_id as u64).to_le_bytes());
+            out.extend_from_slice(&meta.discovered_at.to_le_bytes());
+        }
+        CachedObject::Custom(_) => {
+            // Filtered out before this is ever called; nothing honest to write.
+        }
+    }
+}
+
+fn write_string_into(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Decode a `CachedObject` previously written by `encode_cached_object`,
+/// returning it along with the number of bytes consumed.
+fn decode_cached_object(buf: &[u8]) -> Result<(CachedObject, usize)> {
+    if buf.is_empty() {
+        return Err(anyhow!("Empty buffer for cached object"));
+    }
+    let mut offset = 1usize;
+    let object = match buf[0] {
+        0 => {
+            let id = read_instrument_id(buf, &mut offset);
+            let symbol = read_string(buf, &mut offset);
+            let decimals = read_u8_field(buf, &mut offset);
+            let discovered_at = read_u64_field(buf, &mut offset);
+            let venue_name = read_string(buf, &mut offset);
+            let asset_type_name = read_string(buf, &mut offset);
+            CachedObject::Instrument(InstrumentMetadata {
+                id,
+                symbol,
+                decimals,
+                discovered_at,
+                venue_name,
+                asset_type_name,
+            })
+        }
+        1 => {
+            let id = read_instrument_id(buf, &mut offset);
+            let token0_id = read_instrument_id(buf, &mut offset);
+            let token1_id = read_instrument_id(buf, &mut offset);
+            let symbol = read_string(buf, &mut offset);
+            let fee_tier_raw = read_u64_field(buf, &mut offset);
+            let fee_tier = if fee_tier_raw == u64::MAX { None } else { Some(fee_tier_raw as u32) };
+            let protocol_type = read_string(buf, &mut offset);
+            let discovered_at = read_u64_field(buf, &mut offset);
+            CachedObject::Pool(PoolMetadata {
+                id,
+                token0_id,
+                token1_id,
+                symbol,
+                fee_tier,
+                protocol_type,
+                discovered_at,
+            })
+        }
+        2 => {
+            let id = read_instrument_id(buf, &mut offset);
+            let address = read_string(buf, &mut offset);
+            let symbol = read_string(buf, &mut offset);
+            let name = read_string(buf, &mut offset);
+            let decimals = read_u8_field(buf, &mut offset);
+            let chain_id = {
+                let v = read_u64_field(buf, &mut offset);
+                v as u32
+            };
+            let discovered_at = read_u64_field(buf, &mut offset);
+            CachedObject::Token(TokenMetadata {
+                id,
+                address,
+                symbol,
+                name,
+                decimals,
+                chain_id,
+                discovered_at,
+            })
+        }
+        other => return Err(anyhow!("Unknown cached object tag: {}", other)),
+    };
+    Ok((object, offset))
+}
+
 impl SchemaTransformCache {
     /// Create a new cache
     pub fn new() -> Self {
@@ -87,9 +384,14 @@ impl SchemaTransformCache {
             dynamic_schemas: DashMap::new(),
             objects: DashMap::new(),
             u64_index: Some(DashMap::new()), // Enable for compatibility
+            wal_seq: AtomicU64::new(0),
+            wal_sink: Mutex::new(None),
+            versions: DashMap::new(),
+            version_depth: DEFAULT_VERSION_DEPTH,
+            sinks: Mutex::new(Vec::new()),
         }
     }
-    
+
     /// Create cache without u64 compatibility index
     pub fn new_without_u64_index() -> Self {
         Self {
@@ -97,13 +399,96 @@ impl SchemaTransformCache {
             dynamic_schemas: DashMap::new(),
             objects: DashMap::new(),
             u64_index: None,
+            wal_seq: AtomicU64::new(0),
+            wal_sink: Mutex::new(None),
+            versions: DashMap::new(),
+            version_depth: DEFAULT_VERSION_DEPTH,
+            sinks: Mutex::new(Vec::new()),
         }
     }
-    
+
+    /// Register a sink to receive every subsequently processed message whose
+    /// `filter` matches (or every message, if `filter` is `None`). Sinks fire
+    /// in registration order.
+    pub fn register_sink(
+        &self,
+        sink: Arc<dyn crate::sinks::MessageSink>,
+        filter: Option<crate::sinks::SinkFilter>,
+        error_policy: crate::sinks::SinkErrorPolicy,
+    ) {
+        self.sinks.lock().unwrap().push(RegisteredSink {
+            sink,
+            filter,
+            error_policy,
+        });
+    }
+
+    /// Push `message` through every registered sink whose filter matches.
+    /// A `DropAndLog` sink's failure is logged and dispatch continues to the
+    /// next sink; a `Propagate` sink's failure is logged and also stops this
+    /// message from reaching any sink registered after it, so a broken sink
+    /// can't be silently routed around while still never panicking or
+    /// failing the parse that produced `message`.
+    fn dispatch_to_sinks(&self, message: &ProcessedMessage) {
+        let sinks = self.sinks.lock().unwrap();
+        for registered in sinks.iter() {
+            if let Some(filter) = &registered.filter {
+                if !filter.matches(message) {
+                    continue;
+                }
+            }
+            if let Err(e) = registered.sink.accept(message) {
+                eprintln!("Sink '{}' failed to accept message: {}", registered.sink.name(), e);
+                if registered.error_policy == crate::sinks::SinkErrorPolicy::Propagate {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Start appending every subsequent `insert`/`remove` to `writer` as a
+    /// write-ahead entry, flushing after each one so a crash mid-write loses
+    /// at most the in-flight entry rather than corrupting prior ones.
+    pub fn set_wal_sink(&self, writer: Box<dyn Write + Send>) {
+        *self.wal_sink.lock().unwrap() = Some(writer);
+    }
+
+    /// Stop appending to the write-ahead sink (e.g. after rolling the file).
+    pub fn clear_wal_sink(&self) {
+        *self.wal_sink.lock().unwrap() = None;
+    }
+
+    /// Sequence number of the most recently applied mutation. A `restore`d
+    /// cache can be handed a WAL tail starting strictly after this value to
+    /// resume replay without reapplying already-applied entries.
+    pub fn last_applied_seq(&self) -> u64 {
+        self.wal_seq.load(Ordering::SeqCst)
+    }
+
+    fn append_wal(&self, op: &WalOp) {
+        let mut sink = self.wal_sink.lock().unwrap();
+        let Some(writer) = sink.as_mut() else {
+            return;
+        };
+        // `Custom` objects have no wire format (see `encode_cached_object`),
+        // so they are not recorded, the same way `snapshot` skips them.
+        if matches!(op, WalOp::Insert(_, CachedObject::Custom(_))) {
+            return;
+        }
+        let seq = self.wal_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let entry = WalEntry { seq, op: op.clone() };
+        // Best-effort: a failed WAL write shouldn't take down the in-memory
+        // cache, which is still the source of truth until the next restore.
+        let mut buf = Vec::new();
+        entry.encode(&mut buf);
+        let _ = writer.write_all(&buf).and_then(|_| writer.flush());
+    }
+
     /// Insert object with full InstrumentId key (no data loss!)
     pub fn insert(&self, id: InstrumentId, object: CachedObject) {
+        self.append_wal(&WalOp::Insert(id, object.clone()));
         self.objects.insert(id, object);
-        
+
         // Optionally maintain u64 index for legacy compatibility
         if let Some(ref index) = self.u64_index {
             let u64_key = id.to_u64();
@@ -170,16 +555,144 @@ impl SchemaTransformCache {
     
     /// Remove object
     pub fn remove(&self, id: &InstrumentId) -> Option<CachedObject> {
+        self.append_wal(&WalOp::Remove(*id));
         let result = self.objects.remove(id).map(|(_, v)| v);
-        
+
         // Clean up u64 index if enabled
         if let Some(ref index) = self.u64_index {
             let u64_key = id.to_u64();
             index.remove(&u64_key);
         }
-        
+
         result
     }
+
+    /// Serialize every cached `(InstrumentId, CachedObject)` plus the
+    /// registered dynamic schema keys to `writer` as a full snapshot. Pair
+    /// with [`Self::last_applied_seq`] so a WAL tail recorded after this
+    /// point can be replayed on top without reapplying earlier mutations.
+    ///
+    /// Entries holding a [`CachedObject::Custom`] are skipped: the boxed
+    /// `dyn Any` has no generic wire format, so there's nothing honest to
+    /// write for it. Likewise the dynamic schemas' `Box<dyn MessageParser>`
+    /// can't be serialized - only the `(MessageType, version, size)` key is
+    /// persisted, as a record of what was registered; callers must
+    /// re-register the actual parser after a `restore`.
+    pub fn snapshot<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(SNAPSHOT_MAGIC)?;
+        write_u64(&mut writer, SNAPSHOT_VERSION)?;
+        write_u64(&mut writer, self.last_applied_seq())?;
+
+        let entries: Vec<(InstrumentId, CachedObject)> = self
+            .objects
+            .iter()
+            .filter(|entry| !matches!(entry.value(), CachedObject::Custom(_)))
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect();
+        write_u64(&mut writer, entries.len() as u64)?;
+        for (id, object) in entries {
+            writer.write_all(id.as_bytes())?;
+            let mut buf = Vec::new();
+            encode_cached_object(&object, &mut buf);
+            write_u64(&mut writer, buf.len() as u64)?;
+            writer.write_all(&buf)?;
+        }
+
+        let schemas: Vec<(MessageType, u8, Option<usize>)> = self
+            .dynamic_schemas
+            .iter()
+            .map(|entry| (entry.value().message_type, entry.value().version, entry.value().size))
+            .collect();
+        write_u64(&mut writer, schemas.len() as u64)?;
+        for (message_type, version, size) in schemas {
+            write_u8(&mut writer, message_type as u8)?;
+            write_u8(&mut writer, version)?;
+            write_u64(&mut writer, size.map(|s| s as u64).unwrap_or(u64::MAX))?;
+        }
+
+        Ok(())
+    }
+
+    /// Restore a cache from a snapshot written by [`Self::snapshot`], then
+    /// replay any WAL entries appended to the same stream afterward. A
+    /// trailing entry truncated by a crash mid-write (not enough bytes left
+    /// to decode a full entry) stops replay without erroring - the cache
+    /// ends up exactly at the last fully-flushed sequence number.
+    pub fn restore<R: Read>(mut reader: R) -> Result<Self> {
+        let mut magic = [0u8; SNAPSHOT_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != *SNAPSHOT_MAGIC {
+            return Err(anyhow!("Invalid schema transform cache snapshot header"));
+        }
+        let version = read_u64(&mut reader)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(anyhow!("Unsupported snapshot version: {}", version));
+        }
+        let snapshot_seq = read_u64(&mut reader)?;
+
+        let cache = Self::new();
+
+        let object_count = read_u64(&mut reader)?;
+        for _ in 0..object_count {
+            let mut id_bytes = [0u8; std::mem::size_of::<InstrumentId>()];
+            reader.read_exact(&mut id_bytes)?;
+            let id = InstrumentId::read_from_prefix(&id_bytes[..])
+                .ok_or_else(|| anyhow!("Corrupt InstrumentId in snapshot"))?;
+            let len = read_u64(&mut reader)? as usize;
+            let mut buf = vec![0u8; len];
+            reader.read_exact(&mut buf)?;
+            let (object, _) = decode_cached_object(&buf)?;
+            cache.objects.insert(id, object);
+            if let Some(ref index) = cache.u64_index {
+                index.insert(id.to_u64(), id);
+            }
+        }
+
+        // Schema keys are informational only - see snapshot()'s doc comment.
+        let schema_count = read_u64(&mut reader)?;
+        for _ in 0..schema_count {
+            let mut tag = [0u8; 1];
+            reader.read_exact(&mut tag)?;
+            let mut version_byte = [0u8; 1];
+            reader.read_exact(&mut version_byte)?;
+            let _ = read_u64(&mut reader)?; // size, unused without a parser
+        }
+
+        cache.wal_seq.store(snapshot_seq, Ordering::SeqCst);
+
+        // Replay whatever WAL tail follows the snapshot in the same stream.
+        loop {
+            match WalEntry::try_decode(&mut reader)? {
+                Some(entry) if entry.seq > cache.last_applied_seq() => {
+                    cache.apply_wal_op(entry.op);
+                    cache.wal_seq.store(entry.seq, Ordering::SeqCst);
+                }
+                Some(_) => {} // already covered by the snapshot, skip
+                None => break,
+            }
+        }
+
+        Ok(cache)
+    }
+
+    /// Apply a decoded WAL mutation directly, without re-recording it (used
+    /// only during `restore`'s replay).
+    fn apply_wal_op(&self, op: WalOp) {
+        match op {
+            WalOp::Insert(id, object) => {
+                self.objects.insert(id, object);
+                if let Some(ref index) = self.u64_index {
+                    index.insert(id.to_u64(), id);
+                }
+            }
+            WalOp::Remove(id) => {
+                self.objects.remove(&id);
+                if let Some(ref index) = self.u64_index {
+                    index.remove(&id.to_u64());
+                }
+            }
+        }
+    }
     
     /// Clear all objects
     pub fn clear(&self) {
@@ -200,18 +713,32 @@ impl SchemaTransformCache {
     
     /// Process binary message and cache any discovered objects
     pub fn process_message(&self, data: &[u8]) -> Result<ProcessedMessage, ParseError> {
+        let processed = self.parse_message(data)?;
+        if let ProcessedMessage::InstrumentDiscovered(metadata) = &processed {
+            self.insert(metadata.id, CachedObject::Instrument(metadata.clone()));
+        }
+        self.dispatch_to_sinks(&processed);
+        Ok(processed)
+    }
+
+    /// Parse a binary message into a [`ProcessedMessage`]. Split out of
+    /// `process_message` so `process_batch` can run this half across frames
+    /// in parallel while `InstrumentDiscovered` inserts stay sequential and
+    /// ordered by the caller. The one exception is a runtime-registered
+    /// template: its cached object is inserted here, as it is parsed.
+    fn parse_message(&self, data: &[u8]) -> Result<ProcessedMessage, ParseError> {
         // Parse header to determine message type
         let header = crate::message_protocol::MessageHeader::from_bytes(data)?;
         let message_type = header.message_type()?;
         let version = header.version;
-        
+
         match message_type {
             MessageType::Trade => {
                 let trade = TradeMessage::from_bytes(data)?;
                 Ok(ProcessedMessage::Trade(TradeData {
                     instrument_id: trade.instrument_id,
-                    price: trade.price_decimal(),
-                    volume: trade.volume_decimal(),
+                    price: FixedAmount::new(trade.price as u128, 8),
+                    volume: FixedAmount::new(trade.volume as u128, 8),
                     side: trade.trade_side()?,
                     timestamp: header.timestamp,
                 }))
@@ -244,9 +771,7 @@ impl SchemaTransformCache {
                         .map(|t| format!("{:?}", t))
                         .unwrap_or_else(|_| "Unknown".to_string()),
                 };
-                
-                self.insert(discovery.header.instrument_id, CachedObject::Instrument(metadata.clone()));
-                
+
                 Ok(ProcessedMessage::InstrumentDiscovered(metadata))
             }
             MessageType::SwapEvent => {
@@ -255,10 +780,10 @@ impl SchemaTransformCache {
                     pool_id: swap.pool_id,
                     token0_id: swap.token0_id,
                     token1_id: swap.token1_id,
-                    amount0_in: swap.amount0_in_decimal(),
-                    amount1_in: swap.amount1_in_decimal(),
-                    amount0_out: swap.amount0_out_decimal(),
-                    amount1_out: swap.amount1_out_decimal(),
+                    amount0_in: FixedAmount::new(swap.amount0_in as u128, 8),
+                    amount1_in: FixedAmount::new(swap.amount1_in as u128, 8),
+                    amount0_out: FixedAmount::new(swap.amount0_out as u128, 8),
+                    amount1_out: FixedAmount::new(swap.amount1_out as u128, 8),
                     timestamp: header.timestamp,
                 }))
             }
@@ -266,8 +791,8 @@ impl SchemaTransformCache {
                 let pool_update = PoolUpdateMessage::from_bytes(data)?;
                 Ok(ProcessedMessage::PoolUpdate(PoolUpdateData {
                     pool_id: pool_update.pool_id,
-                    reserve0: pool_update.reserve0_decimal(),
-                    reserve1: pool_update.reserve1_decimal(),
+                    reserve0: FixedAmount::new(pool_update.reserve0 as u128, 8),
+                    reserve1: FixedAmount::new(pool_update.reserve1 as u128, 8),
                     sqrt_price_x96: pool_update.sqrt_price_x96,
                     tick: pool_update.tick,
                     timestamp: header.timestamp,
@@ -280,14 +805,29 @@ impl SchemaTransformCache {
                     token1_id: arb.token1_id,
                     buy_pool_id: arb.buy_pool_id,
                     sell_pool_id: arb.sell_pool_id,
-                    buy_price: arb.buy_price as f64 / 100_000_000.0,
-                    sell_price: arb.sell_price as f64 / 100_000_000.0,
+                    buy_price: FixedAmount::new(arb.buy_price as u128, 8),
+                    sell_price: FixedAmount::new(arb.sell_price as u128, 8),
                     profit_percentage: arb.profit_percent_decimal(),
                     timestamp: header.timestamp,
                 }))
             }
             _ => {
-                // For unknown message types, return raw data for forwarding
+                if let Some(schema) = self.dynamic_schemas.get(&(message_type, version)) {
+                    let parsed = schema
+                        .parser
+                        .parse(data)
+                        .map_err(|e| ParseError::DynamicParseFailed(e.to_string()))?;
+                    let instrument_id = schema.parser.instrument_id(parsed.as_ref());
+                    if let Some(object) = schema.parser.to_cached_object(parsed.as_ref()) {
+                        if let Some(id) = instrument_id {
+                            self.insert(id, object.clone());
+                        }
+                        return Ok(ProcessedMessage::Dynamic { message_type, object });
+                    }
+                }
+
+                // For unknown message types with no registered template,
+                // return raw data for forwarding
                 Ok(ProcessedMessage::Unknown {
                     message_type,
                     version,
@@ -296,12 +836,153 @@ impl SchemaTransformCache {
             }
         }
     }
-    
+
+    /// Parse a batch of independent frames in parallel with rayon, preserving
+    /// input order in the output. Frames are parsed concurrently; any
+    /// `InstrumentDiscovered` cache insert is then applied sequentially in
+    /// input order afterwards, so the final cached value for a discovered
+    /// instrument is deterministic regardless of how the batch happened to be
+    /// scheduled across threads. Objects from runtime-registered templates
+    /// are inserted during the parallel parse, so their order is not fixed.
+    pub fn process_batch(&self, frames: &[&[u8]]) -> Vec<Result<ProcessedMessage, ParseError>> {
+        let parsed: Vec<Result<ProcessedMessage, ParseError>> = frames
+            .par_iter()
+            .map(|frame| self.parse_message(frame))
+            .collect();
+
+        for result in &parsed {
+            if let Ok(message) = result {
+                if let ProcessedMessage::InstrumentDiscovered(metadata) = message {
+                    self.insert(metadata.id, CachedObject::Instrument(metadata.clone()));
+                }
+                self.dispatch_to_sinks(message);
+            }
+        }
+
+        parsed
+    }
+
+    /// Same as [`Self::process_batch`], but first verifies every frame's
+    /// `(pubkey, signature, message)` triple together in one batched call
+    /// (see [`crate::batch_verify`]) before parsing any of them. If
+    /// verification fails, every frame in the batch is rejected with
+    /// `ParseError::InvalidSignature` rather than parsing the ones that
+    /// happen to still check out - a forged frame shouldn't get to pick which
+    /// of its batch-mates are trusted.
+    pub fn process_signed_batch(
+        &self,
+        frames: &[crate::batch_verify::SignedFrame<'_>],
+    ) -> Vec<Result<ProcessedMessage, ParseError>> {
+        if !crate::batch_verify::verify_batch(frames) {
+            return frames.iter().map(|_| Err(ParseError::InvalidSignature)).collect();
+        }
+
+        let raw: Vec<&[u8]> = frames.iter().map(|frame| frame.message).collect();
+        self.process_batch(&raw)
+    }
+
     /// Register a dynamic schema at runtime
     pub fn register_dynamic_schema(&self, schema: MessageSchema) {
         let key = (schema.message_type, schema.version);
         self.dynamic_schemas.insert(key, schema);
     }
+
+    /// Teach the running cache a new venue/protocol message template without
+    /// a recompile, graph-node-style: `(message_type, version)` frames that
+    /// don't match a built-in `MessageType` arm are parsed with `parser`
+    /// instead of falling through to `ProcessedMessage::Unknown`. Sugar over
+    /// [`Self::register_dynamic_schema`] for callers that don't need to pin
+    /// down a fixed wire size.
+    pub fn register_template(
+        &self,
+        message_type: MessageType,
+        version: u8,
+        parser: Box<dyn MessageParser>,
+    ) {
+        self.register_dynamic_schema(MessageSchema {
+            message_type,
+            version,
+            size: None,
+            parser,
+        });
+    }
+
+    /// Same as [`Self::process_message`], but tags any state it mutates with
+    /// `block_number` and keeps it in the per-key version history so a later
+    /// reorg can be rolled back with [`Self::revert_to_block`]. Instrument
+    /// discoveries, pool updates, and swap events are all versioned, keyed
+    /// by the instrument/pool id the message concerns.
+    pub fn process_message_at_block(
+        &self,
+        data: &[u8],
+        block_number: u64,
+    ) -> Result<ProcessedMessage, ParseError> {
+        let processed = self.process_message(data)?;
+
+        match &processed {
+            ProcessedMessage::InstrumentDiscovered(meta) => {
+                self.push_version(meta.id, block_number, CachedObject::Instrument(meta.clone()));
+            }
+            ProcessedMessage::PoolUpdate(update) => {
+                let object = CachedObject::Custom(Arc::new(update.clone()));
+                self.insert(update.pool_id, object.clone());
+                self.push_version(update.pool_id, block_number, object);
+            }
+            ProcessedMessage::SwapEvent(swap) => {
+                let object = CachedObject::Custom(Arc::new(swap.clone()));
+                self.insert(swap.pool_id, object.clone());
+                self.push_version(swap.pool_id, block_number, object);
+            }
+            _ => {}
+        }
+
+        Ok(processed)
+    }
+
+    /// Append a block-tagged version for `id`, evicting the oldest entry
+    /// once the per-key history exceeds `version_depth`.
+    fn push_version(&self, id: InstrumentId, block_number: u64, object: CachedObject) {
+        let mut history = self.versions.entry(id).or_insert_with(VecDeque::new);
+        history.push_back((block_number, object));
+        while history.len() > self.version_depth {
+            history.pop_front();
+        }
+    }
+
+    /// Roll every versioned key back to exactly the canonical-chain state at
+    /// `target`: drop every version from an orphaned block (`block_number >
+    /// target`), then restore the newest surviving version, or evict the key
+    /// entirely if nothing survives. This bypasses the WAL - a revert is a
+    /// correction of already-applied state, not a new forward mutation to
+    /// replay - so callers that persist the cache should re-snapshot after
+    /// calling this rather than relying on WAL replay to reproduce it.
+    pub fn revert_to_block(&self, target: u64) {
+        let keys: Vec<InstrumentId> = self.versions.iter().map(|entry| *entry.key()).collect();
+
+        for id in keys {
+            let Some(mut history) = self.versions.get_mut(&id) else {
+                continue;
+            };
+            while matches!(history.back(), Some((block_number, _)) if *block_number > target) {
+                history.pop_back();
+            }
+
+            match history.back() {
+                Some((_, object)) => {
+                    let object = object.clone();
+                    drop(history);
+                    self.objects.insert(id, object);
+                }
+                None => {
+                    drop(history);
+                    self.objects.remove(&id);
+                    if let Some(ref index) = self.u64_index {
+                        index.remove(&id.to_u64());
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Processed message types
@@ -318,14 +999,61 @@ pub enum ProcessedMessage {
         version: u8,
         data: Vec<u8>,
     },
+    /// Decoded by a runtime-registered template (see
+    /// [`SchemaTransformCache::register_template`]) rather than a built-in
+    /// `MessageType` arm.
+    Dynamic {
+        message_type: MessageType,
+        object: CachedObject,
+    },
+}
+
+impl ProcessedMessage {
+    /// The wire `MessageType` this message was decoded from - used to
+    /// evaluate a sink's `MessageType` filter.
+    pub fn message_type(&self) -> MessageType {
+        match self {
+            ProcessedMessage::Trade(_) => MessageType::Trade,
+            ProcessedMessage::Quote(_) => MessageType::Quote,
+            ProcessedMessage::InstrumentDiscovered(_) => MessageType::InstrumentDiscovered,
+            ProcessedMessage::SwapEvent(_) => MessageType::SwapEvent,
+            ProcessedMessage::PoolUpdate(_) => MessageType::PoolUpdate,
+            ProcessedMessage::ArbitrageOpportunity(_) => MessageType::ArbitrageOpportunity,
+            ProcessedMessage::Unknown { message_type, .. } => *message_type,
+            ProcessedMessage::Dynamic { message_type, .. } => *message_type,
+        }
+    }
+
+    /// The instrument/pool id most representative of this message, used to
+    /// evaluate a sink's `VenueId`/`AssetType` filter via
+    /// [`InstrumentId::venue`]/[`InstrumentId::asset_type`]. `None` for
+    /// arbitrage opportunities (which span a buy pool and a sell pool, with
+    /// no single "the" id) and unknown messages.
+    pub fn primary_instrument_id(&self) -> Option<InstrumentId> {
+        match self {
+            ProcessedMessage::Trade(data) => Some(data.instrument_id),
+            ProcessedMessage::Quote(data) => Some(data.instrument_id),
+            ProcessedMessage::InstrumentDiscovered(meta) => Some(meta.id),
+            ProcessedMessage::SwapEvent(data) => Some(data.pool_id),
+            ProcessedMessage::PoolUpdate(data) => Some(data.pool_id),
+            ProcessedMessage::ArbitrageOpportunity(_) => None,
+            ProcessedMessage::Unknown { .. } => None,
+            ProcessedMessage::Dynamic { object, .. } => match object {
+                CachedObject::Instrument(meta) => Some(meta.id),
+                CachedObject::Pool(meta) => Some(meta.id),
+                CachedObject::Token(meta) => Some(meta.id),
+                CachedObject::Custom(_) => None,
+            },
+        }
+    }
 }
 
 /// Processed trade data
 #[derive(Debug, Clone)]
 pub struct TradeData {
     pub instrument_id: InstrumentId,
-    pub price: f64,
-    pub volume: f64,
+    pub price: FixedAmount,
+    pub volume: FixedAmount,
     pub side: crate::messages::TradeSide,
     pub timestamp: u64,
 }
@@ -348,10 +1076,10 @@ pub struct SwapEventData {
     pub pool_id: InstrumentId,
     pub token0_id: InstrumentId,
     pub token1_id: InstrumentId,
-    pub amount0_in: f64,
-    pub amount1_in: f64,
-    pub amount0_out: f64,
-    pub amount1_out: f64,
+    pub amount0_in: FixedAmount,
+    pub amount1_in: FixedAmount,
+    pub amount0_out: FixedAmount,
+    pub amount1_out: FixedAmount,
     pub timestamp: u64,
 }
 
@@ -359,8 +1087,8 @@ pub struct SwapEventData {
 #[derive(Debug, Clone)]
 pub struct PoolUpdateData {
     pub pool_id: InstrumentId,
-    pub reserve0: f64,
-    pub reserve1: f64,
+    pub reserve0: FixedAmount,
+    pub reserve1: FixedAmount,
     pub sqrt_price_x96: u128,
     pub tick: i32,
     pub timestamp: u64,
@@ -373,8 +1101,8 @@ pub struct ArbitrageData {
     pub token1_id: InstrumentId,
     pub buy_pool_id: InstrumentId,
     pub sell_pool_id: InstrumentId,
-    pub buy_price: f64,
-    pub sell_price: f64,
+    pub buy_price: FixedAmount,
+    pub sell_price: FixedAmount,
     pub profit_percentage: f64,
     pub timestamp: u64,
 }
@@ -510,14 +1238,105 @@ mod tests {
         match processed {
             ProcessedMessage::Trade(data) => {
                 assert_eq!(data.instrument_id, instrument_id);
-                assert_eq!(data.price, 150.0);
-                assert_eq!(data.volume, 0.1);
+                assert!((data.price.to_f64_lossy() - 150.0).abs() < 1e-9);
+                assert!((data.volume.to_f64_lossy() - 0.1).abs() < 1e-9);
                 assert_eq!(data.side, TradeSide::Buy);
             }
             _ => panic!("Expected trade message"),
         }
     }
 
+    #[test]
+    fn test_process_batch_preserves_order_and_caches_instruments() {
+        let cache = SchemaTransformCache::new();
+
+        let id1 = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+        let id2 = InstrumentId::polygon_token("0x2791bca1f2de4661ed88a30c99a7a9449aa84174").unwrap();
+
+        let discovery1 = InstrumentDiscoveredMessage::new(id1, "USDC".to_string(), 6, vec![], 1, SourceType::External);
+        let trade = TradeMessage::new(id1, 15000000000, 10000000, TradeSide::Buy, 2, SourceType::External);
+        let discovery2 = InstrumentDiscoveredMessage::new(id2, "WMATIC".to_string(), 18, vec![], 3, SourceType::External);
+
+        let bytes1 = discovery1.serialize();
+        let bytes2 = trade.as_bytes().to_vec();
+        let bytes3 = discovery2.serialize();
+        let frames: Vec<&[u8]> = vec![&bytes1, &bytes2, &bytes3];
+
+        let results = cache.process_batch(&frames);
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Ok(ProcessedMessage::InstrumentDiscovered(_))));
+        assert!(matches!(results[1], Ok(ProcessedMessage::Trade(_))));
+        assert!(matches!(results[2], Ok(ProcessedMessage::InstrumentDiscovered(_))));
+
+        assert!(cache.get(&id1).is_some());
+        assert!(cache.get(&id2).is_some());
+    }
+
+    /// A template parser for a made-up venue format: the payload is just the
+    /// instrument's symbol as UTF-8.
+    struct TestTemplateParser {
+        id: InstrumentId,
+    }
+
+    impl MessageParser for TestTemplateParser {
+        fn parse(&self, data: &[u8]) -> Result<Box<dyn Any>> {
+            Ok(Box::new(String::from_utf8_lossy(data).to_string()))
+        }
+
+        fn to_cached_object(&self, parsed: &dyn Any) -> Option<CachedObject> {
+            let symbol = parsed.downcast_ref::<String>()?.clone();
+            Some(CachedObject::Instrument(InstrumentMetadata {
+                id: self.id,
+                symbol,
+                decimals: 0,
+                discovered_at: 0,
+                venue_name: "Unknown".to_string(),
+                asset_type_name: "Unknown".to_string(),
+            }))
+        }
+
+        fn instrument_id(&self, _parsed: &dyn Any) -> Option<InstrumentId> {
+            Some(self.id)
+        }
+    }
+
+    #[test]
+    fn test_dynamic_template_dispatch() {
+        let cache = SchemaTransformCache::new();
+        let id = InstrumentId::stock(VenueId::NASDAQ, "TEST");
+
+        cache.register_template(
+            MessageType::Custom,
+            1,
+            Box::new(TestTemplateParser { id }),
+        );
+
+        let payload = b"TEST".to_vec();
+        let mut header = crate::message_protocol::MessageHeader::new(
+            MessageType::Custom,
+            1,
+            SourceType::External,
+            payload.len() as u32,
+            1,
+        );
+        let mut bytes = header.as_bytes().to_vec();
+        bytes.extend_from_slice(&payload);
+        header.calculate_checksum(&bytes);
+        bytes[..header.as_bytes().len()].copy_from_slice(header.as_bytes());
+
+        let processed = cache.process_message(&bytes).unwrap();
+        match processed {
+            ProcessedMessage::Dynamic { message_type, object } => {
+                assert_eq!(message_type, MessageType::Custom);
+                assert!(matches!(object, CachedObject::Instrument(_)));
+            }
+            other => panic!("Expected dynamic message, got {:?}", other),
+        }
+
+        assert!(cache.get(&id).is_some());
+    }
+
     #[test]
     fn test_cache_stats() {
         let cache = SchemaTransformCache::new();
@@ -548,4 +1367,141 @@ mod tests {
         assert_eq!(stats.object_count, 2);
         assert_eq!(stats.u64_index_count, 2); // u64 index enabled by default
     }
+
+    #[test]
+    fn test_snapshot_restore_round_trip() {
+        let cache = SchemaTransformCache::new();
+
+        let token = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+        cache.insert(token, CachedObject::Token(TokenMetadata {
+            id: token,
+            address: "0xa0b8...".to_string(),
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            chain_id: 1,
+            discovered_at: 1234567890,
+        }));
+
+        let mut buf = Vec::new();
+        cache.snapshot(&mut buf).unwrap();
+
+        let restored = SchemaTransformCache::restore(&buf[..]).unwrap();
+        assert_eq!(restored.last_applied_seq(), cache.last_applied_seq());
+
+        match restored.get(&token) {
+            Some(CachedObject::Token(meta)) => assert_eq!(meta.symbol, "USDC"),
+            other => panic!("Expected restored token, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_wal_tail_replays_after_snapshot() {
+        let cache = SchemaTransformCache::new();
+
+        let token = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+        cache.insert(token, CachedObject::Token(TokenMetadata {
+            id: token,
+            address: "0xa0b8...".to_string(),
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            chain_id: 1,
+            discovered_at: 1234567890,
+        }));
+
+        let mut buf = Vec::new();
+        cache.snapshot(&mut buf).unwrap();
+
+        // A mutation applied after the snapshot was taken, appended as a WAL
+        // entry directly onto the same stream.
+        let poly_token = InstrumentId::polygon_token("0x2791bca1f2de4661ed88a30c99a7a9449aa84174").unwrap();
+        let op = WalOp::Insert(poly_token, CachedObject::Token(TokenMetadata {
+            id: poly_token,
+            address: "0x2791...".to_string(),
+            symbol: "USDC".to_string(),
+            name: "USD Coin (PoS)".to_string(),
+            decimals: 6,
+            chain_id: 137,
+            discovered_at: 1234567891,
+        }));
+        let entry = WalEntry { seq: cache.last_applied_seq() + 1, op };
+        entry.encode(&mut buf);
+
+        let restored = SchemaTransformCache::restore(&buf[..]).unwrap();
+        assert_eq!(restored.last_applied_seq(), entry.seq);
+        assert!(restored.get(&poly_token).is_some());
+    }
+
+    #[test]
+    fn test_truncated_wal_tail_stops_cleanly() {
+        let cache = SchemaTransformCache::new();
+        let mut buf = Vec::new();
+        cache.snapshot(&mut buf).unwrap();
+
+        // Simulate a crash mid-write: a WAL tag with no complete entry behind it.
+        buf.push(WAL_ENTRY_TAG);
+        buf.extend_from_slice(&1u64.to_le_bytes());
+        // op_tag and InstrumentId bytes are missing entirely.
+
+        let restored = SchemaTransformCache::restore(&buf[..]).unwrap();
+        assert_eq!(restored.last_applied_seq(), cache.last_applied_seq());
+    }
+
+    #[test]
+    fn test_revert_to_block_restores_canonical_state() {
+        use crate::message_protocol::SourceType;
+
+        let cache = SchemaTransformCache::new();
+        let pool_id = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+
+        let canonical = PoolUpdateMessage::new(pool_id, 1_000, 2_000, 0, 0, 1, SourceType::External);
+        cache.process_message_at_block(canonical.as_bytes(), 100).unwrap();
+
+        let orphaned = PoolUpdateMessage::new(pool_id, 9_999, 9_999, 0, 0, 2, SourceType::External);
+        cache.process_message_at_block(orphaned.as_bytes(), 101).unwrap();
+
+        // Block 101 is the orphaned fork; reverting to 100 should bring back
+        // exactly the reserves the canonical chain had there.
+        cache.revert_to_block(100);
+
+        match cache.get(&pool_id) {
+            Some(CachedObject::Custom(object)) => {
+                let update = object.downcast_ref::<PoolUpdateData>().unwrap();
+                assert!((update.reserve0.to_f64_lossy() - 1_000.0 / 1e8).abs() < 1e-9);
+            }
+            other => panic!("Expected a custom pool update object, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_revert_evicts_key_with_no_surviving_version() {
+        use crate::message_protocol::SourceType;
+
+        let cache = SchemaTransformCache::new();
+        let pool_id = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+
+        let only_block = PoolUpdateMessage::new(pool_id, 1_000, 2_000, 0, 0, 1, SourceType::External);
+        cache.process_message_at_block(only_block.as_bytes(), 50).unwrap();
+
+        // Nothing survives reverting to a block before the key's only version.
+        cache.revert_to_block(10);
+
+        assert!(cache.get(&pool_id).is_none());
+    }
+
+    #[test]
+    fn test_version_history_stays_bounded() {
+        use crate::message_protocol::SourceType;
+
+        let cache = SchemaTransformCache::new();
+        let pool_id = InstrumentId::ethereum_token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+
+        for block in 0..(DEFAULT_VERSION_DEPTH as u64 + 10) {
+            let update = PoolUpdateMessage::new(pool_id, block, block, 0, 0, block, SourceType::External);
+            cache.process_message_at_block(update.as_bytes(), block).unwrap();
+        }
+
+        assert_eq!(cache.versions.get(&pool_id).unwrap().len(), DEFAULT_VERSION_DEPTH);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+//! Pluggable sink pipeline for routing processed messages to external
+//! consumers, patterned after Oura's source -> filter -> sink pipeline:
+//! after [`SchemaTransformCache::process_message`](crate::schema_transform_cache::SchemaTransformCache::process_message)
+//! parses a frame, it is pushed through every sink registered via
+//! [`SchemaTransformCache::register_sink`](crate::schema_transform_cache::SchemaTransformCache::register_sink)
+//! whose filter matches.
+use crate::message_protocol::{AssetType, MessageType, VenueId};
+use crate::schema_transform_cache::ProcessedMessage;
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+
+/// A consumer of processed messages. Implementations should be cheap and
+/// non-blocking where possible - a slow `accept` call is run inline with
+/// parsing, one registered sink at a time.
+pub trait MessageSink: Send + Sync {
+    fn accept(&self, msg: &ProcessedMessage) -> Result<()>;
+
+    /// Identifies this sink in error logs.
+    fn name(&self) -> &str;
+}
+
+/// What a dispatch does when a sink's `accept` call fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkErrorPolicy {
+    /// Log the failure and keep dispatching to the remaining sinks.
+    DropAndLog,
+    /// Log the failure and stop dispatching *this message* to any sink
+    /// registered after this one.
+    Propagate,
+}
+
+/// Restricts which messages reach a sink. A `None` field imposes no
+/// restriction on that dimension; an empty `Vec` matches nothing. Venue and
+/// asset-type filters are evaluated via
+/// [`ProcessedMessage::primary_instrument_id`] the same way
+/// [`SchemaTransformCache::get_by_venue`](crate::schema_transform_cache::SchemaTransformCache::get_by_venue)/
+/// [`get_by_asset_type`](crate::schema_transform_cache::SchemaTransformCache::get_by_asset_type)
+/// filter the cache.
+#[derive(Debug, Clone, Default)]
+pub struct SinkFilter {
+    pub message_types: Option<Vec<MessageType>>,
+    pub venues: Option<Vec<VenueId>>,
+    pub asset_types: Option<Vec<AssetType>>,
+}
+
+impl SinkFilter {
+    pub fn matches(&self, message: &ProcessedMessage) -> bool {
+        if let Some(types) = &self.message_types {
+            if !types.contains(&message.message_type()) {
+                return false;
+            }
+        }
+
+        if self.venues.is_some() || self.asset_types.is_some() {
+            let Some(id) = message.primary_instrument_id() else {
+                return false;
+            };
+
+            if let Some(venues) = &self.venues {
+                if !venues.iter().any(|venue| id.venue().ok() == Some(*venue)) {
+                    return false;
+                }
+            }
+
+            if let Some(asset_types) = &self.asset_types {
+                if !asset_types
+                    .iter()
+                    .any(|asset_type| id.asset_type().ok() == Some(*asset_type))
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Fans every accepted message out to however many `subscribe()` receivers
+/// are currently live, dropping any whose receiver has gone away.
+pub struct BroadcastSink {
+    name: String,
+    subscribers: Mutex<Vec<Sender<ProcessedMessage>>>,
+}
+
+impl BroadcastSink {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Register a new in-process subscriber. The returned receiver gets a
+    /// clone of every message accepted from this point on.
+    pub fn subscribe(&self) -> Receiver<ProcessedMessage> {
+        let (tx, rx) = channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+}
+
+impl MessageSink for BroadcastSink {
+    fn accept(&self, msg: &ProcessedMessage) -> Result<()> {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|tx| tx.send(msg.clone()).is_ok());
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Writes one `{:?}`-formatted line per accepted message, flushing after
+/// each write so a crashed process loses at most the in-flight line.
+pub struct FileSink {
+    name: String,
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl FileSink {
+    pub fn new(name: impl Into<String>, writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            name: name.into(),
+            writer: Mutex::new(writer),
+        }
+    }
+}
+
+impl MessageSink for FileSink {
+    fn accept(&self, msg: &ProcessedMessage) -> Result<()> {
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| anyhow::anyhow!("file sink '{}' writer lock poisoned", self.name))?;
+        writeln!(writer, "{:?}", msg).context("failed to write message to sink file")?;
+        writer.flush().context("failed to flush sink file")?;
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message_protocol::SourceType;
+    use crate::messages::InstrumentDiscoveredMessage;
+    use crate::schema_transform_cache::{InstrumentMetadata, SchemaTransformCache};
+
+    fn sample_trade_instrument() -> crate::message_protocol::InstrumentId {
+        crate::message_protocol::InstrumentId::stock(VenueId::NASDAQ, "AAPL")
+    }
+
+    #[test]
+    fn filter_matches_by_message_type() {
+        let filter = SinkFilter {
+            message_types: Some(vec![MessageType::Trade]),
+            ..Default::default()
+        };
+        let metadata = InstrumentMetadata {
+            id: sample_trade_instrument(),
+            symbol: "AAPL".to_string(),
+            decimals: 2,
+            discovered_at: 0,
+            venue_name: "NASDAQ".to_string(),
+            asset_type_name: "Stock".to_string(),
+        };
+        assert!(!filter.matches(&ProcessedMessage::InstrumentDiscovered(metadata)));
+    }
+
+    #[test]
+    fn broadcast_sink_delivers_to_subscriber() {
+        let sink = BroadcastSink::new("test-broadcast");
+        let rx = sink.subscribe();
+
+        let cache = SchemaTransformCache::new();
+        let id = sample_trade_instrument();
+        let discovery = InstrumentDiscoveredMessage::new(id, "AAPL".to_string(), 2, vec![], 1, SourceType::External);
+
+        cache.register_sink(std::sync::Arc::new(sink), None, SinkErrorPolicy::DropAndLog);
+        cache.process_message(&discovery.serialize()).unwrap();
+
+        let received = rx.try_recv().expect("expected a broadcast message");
+        assert!(matches!(received, ProcessedMessage::InstrumentDiscovered(_)));
+    }
+}
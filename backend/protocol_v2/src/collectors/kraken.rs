@@ -0,0 +1,195 @@
+//! Kraken WebSocket rate collector
+//!
+//! Connects to Kraken's public ticker feed and republishes best bid/ask as
+//! `Quote` TLV messages, forwarded to a relay-bound `mpsc::Sender<Vec<u8>>` the
+//! same way any other collector feeds its messages into the pipeline.
+
+use super::rate::{LatestRate, Rate};
+use crate::{ProtocolError, RelayDomain, SourceType, TLVMessageBuilder, TLVType};
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{error, info, warn};
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+const KRAKEN_WS_URL: &str = "wss://ws.kraken.com";
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const RATE_UPDATE_CHANNEL_CAPACITY: usize = 256;
+
+/// Fixed-point scale `Quote` TLV prices are carried at, matching the rest of the
+/// market data domain's price fields.
+const PRICE_SCALE: f64 = 100_000_000.0; // 1e8
+
+#[derive(Debug, Serialize)]
+struct KrakenSubscribe<'a> {
+    event: &'static str,
+    pair: &'a [String],
+    subscription: KrakenSubscription,
+}
+
+#[derive(Debug, Serialize)]
+struct KrakenSubscription {
+    name: &'static str,
+}
+
+/// `TLVType::Quote` payload: best bid/ask price and size, fixed-point at 1e8.
+/// Only the top-of-book price is parsed from Kraken's ticker frame, so size
+/// fields are left zero rather than fabricated.
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy, AsBytes, FromBytes, FromZeroes)]
+struct QuoteTlv {
+    bid_price: u64,
+    ask_price: u64,
+    bid_size: u64,
+    ask_size: u64,
+}
+
+/// Streams Kraken's public ticker channel and exposes the latest bid/ask per pair
+/// via `LatestRate`, while forwarding each update as a `Quote` TLV message.
+pub struct KrakenCollector {
+    pairs: Vec<String>,
+    rates: Arc<RwLock<HashMap<String, Rate>>>,
+    updates: broadcast::Sender<(String, Rate)>,
+}
+
+impl KrakenCollector {
+    pub fn new(pairs: Vec<String>) -> Self {
+        let (updates, _) = broadcast::channel(RATE_UPDATE_CHANNEL_CAPACITY);
+        Self {
+            pairs,
+            rates: Arc::new(RwLock::new(HashMap::new())),
+            updates,
+        }
+    }
+
+    /// Connect once, subscribe to the ticker channel for every configured pair, and
+    /// forward updates to `sender` until the connection drops. Callers that want
+    /// automatic reconnection should use `run_forever` instead.
+    pub async fn connect_and_stream(&self, sender: &mpsc::Sender<Vec<u8>>) -> Result<(), ProtocolError> {
+        info!("Connecting to Kraken WebSocket at {}", KRAKEN_WS_URL);
+        let (ws_stream, _) = connect_async(KRAKEN_WS_URL).await.map_err(|e| {
+            ProtocolError::Transport(std::io::Error::new(std::io::ErrorKind::ConnectionRefused, e.to_string()))
+        })?;
+
+        let (mut write, mut read) = ws_stream.split();
+
+        let subscribe = KrakenSubscribe {
+            event: "subscribe",
+            pair: &self.pairs,
+            subscription: KrakenSubscription { name: "ticker" },
+        };
+        let msg = serde_json::to_string(&subscribe)
+            .map_err(|e| ProtocolError::Transport(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+        write
+            .send(Message::Text(msg))
+            .await
+            .map_err(|e| ProtocolError::Transport(std::io::Error::new(std::io::ErrorKind::BrokenPipe, e.to_string())))?;
+        info!("Subscribed to Kraken ticker feed for {:?}", self.pairs);
+
+        while let Some(message) = read.next().await {
+            let message = message
+                .map_err(|e| ProtocolError::Transport(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())))?;
+            let Message::Text(text) = message else { continue };
+
+            let Some((pair, rate)) = Self::parse_ticker_update(&text) else { continue };
+
+            self.rates.write().await.insert(pair.clone(), rate);
+            let _ = self.updates.send((pair.clone(), rate));
+
+            let quote = QuoteTlv {
+                bid_price: (rate.bid * PRICE_SCALE) as u64,
+                ask_price: (rate.ask * PRICE_SCALE) as u64,
+                bid_size: 0,
+                ask_size: 0,
+            };
+            let message = TLVMessageBuilder::new(RelayDomain::MarketData, SourceType::KrakenCollector)
+                .add_tlv(TLVType::Quote, &quote)
+                .build();
+
+            if sender.send(message).await.is_err() {
+                warn!("Kraken rate pipeline receiver dropped; stopping collector");
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reconnect with a fixed backoff for as long as the process runs.
+    pub async fn run_forever(&self, sender: mpsc::Sender<Vec<u8>>) {
+        loop {
+            if let Err(e) = self.connect_and_stream(&sender).await {
+                error!("Kraken collector disconnected: {}", e);
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    /// Parse one ticker message: `[channelID, {"a": [price, ...], "b": [price, ...], ...}, "ticker", "<pair>"]`.
+    /// Only the top-of-book price (index 0 of each array) is used.
+    fn parse_ticker_update(text: &str) -> Option<(String, Rate)> {
+        let value: Value = serde_json::from_str(text).ok()?;
+        let arr = value.as_array()?;
+        if arr.len() < 4 || arr.get(2)?.as_str() != Some("ticker") {
+            return None;
+        }
+
+        let pair = arr.get(3)?.as_str()?.to_string();
+        let fields = arr.get(1)?.as_object()?;
+
+        let ask = fields.get("a")?.as_array()?.first()?.as_str()?.parse::<f64>().ok()?;
+        let bid = fields.get("b")?.as_array()?.first()?.as_str()?.parse::<f64>().ok()?;
+
+        let timestamp_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        Some((pair, Rate::new(bid, ask, timestamp_ns)))
+    }
+}
+
+impl LatestRate for KrakenCollector {
+    fn latest_rate(&self, pair: &str) -> Result<Rate, ProtocolError> {
+        self.rates
+            .try_read()
+            .ok()
+            .and_then(|rates| rates.get(pair).copied())
+            .ok_or_else(|| ProtocolError::UnknownPair(pair.to_string()))
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<(String, Rate)> {
+        self.updates.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ticker_update_extracts_top_of_book() {
+        let text = r#"[340,{"a":["5525.40000",1,"1.000"],"b":["5525.10000",1,"1.000"]},"ticker","XBT/USD"]"#;
+        let (pair, rate) = KrakenCollector::parse_ticker_update(text).unwrap();
+        assert_eq!(pair, "XBT/USD");
+        assert_eq!(rate.ask, 5525.40000);
+        assert_eq!(rate.bid, 5525.10000);
+    }
+
+    #[test]
+    fn test_parse_ticker_update_ignores_non_ticker_events() {
+        let text = r#"{"event":"heartbeat"}"#;
+        assert!(KrakenCollector::parse_ticker_update(text).is_none());
+    }
+
+    #[test]
+    fn test_latest_rate_unknown_pair_errors() {
+        let collector = KrakenCollector::new(vec!["XBT/USD".to_string()]);
+        assert!(matches!(collector.latest_rate("XBT/USD"), Err(ProtocolError::UnknownPair(_))));
+    }
+}
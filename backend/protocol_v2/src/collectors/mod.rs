@@ -0,0 +1,15 @@
+//! External Rate Collectors
+//!
+//! The Polygon collector feeds DEX pool swaps into the TLV pipeline, but
+//! computing DEX-vs-CEX arbitrage also needs a reference price from a
+//! centralized exchange. A rate collector implements `LatestRate` and
+//! publishes its updates as `Quote` TLV messages through the same
+//! `mpsc::Sender<Vec<u8>>` byte pipeline any other collector feeds into a
+//! relay, so downstream strategies don't need to know a quote came from a
+//! CEX WebSocket rather than an on-chain swap.
+
+pub mod kraken;
+pub mod rate;
+
+pub use kraken::KrakenCollector;
+pub use rate::{LatestRate, Rate};
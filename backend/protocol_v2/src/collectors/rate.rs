@@ -0,0 +1,35 @@
+//! `Rate`: a point-in-time best bid/ask observed at an external venue.
+
+use crate::ProtocolError;
+
+/// Best bid/ask for one trading pair at `timestamp_ns`, as reported by a venue
+/// outside this protocol (e.g. a centralized exchange's order book).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate {
+    pub bid: f64,
+    pub ask: f64,
+    pub timestamp_ns: u64,
+}
+
+impl Rate {
+    pub fn new(bid: f64, ask: f64, timestamp_ns: u64) -> Self {
+        Self { bid, ask, timestamp_ns }
+    }
+
+    /// Midpoint of bid/ask - the reference price a DEX pool's price is spread against.
+    pub fn mid(&self) -> f64 {
+        (self.bid + self.ask) / 2.0
+    }
+}
+
+/// A source of reference rates for one or more trading pairs, kept current by a
+/// background collector task.
+pub trait LatestRate: Send + Sync {
+    /// Most recently observed rate for `pair` (e.g. `"XBT/USD"`). Errors with
+    /// `ProtocolError::UnknownPair` if no update has arrived for it yet.
+    fn latest_rate(&self, pair: &str) -> Result<Rate, ProtocolError>;
+
+    /// Subscribe to every rate update as it arrives, for callers that want to react
+    /// to changes rather than poll `latest_rate`.
+    fn subscribe(&self) -> tokio::sync::broadcast::Receiver<(String, Rate)>;
+}
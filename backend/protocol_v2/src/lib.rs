@@ -14,6 +14,7 @@ pub mod recovery;
 pub mod transport;
 pub mod validation;
 pub mod relay;
+pub mod collectors;
 
 pub use header::*;
 pub use tlv::*;
@@ -21,6 +22,7 @@ pub use instrument_id::*;
 pub use recovery::*;
 pub use transport::*;
 pub use validation::*;
+pub use collectors::*;
 
 /// Protocol magic number for message identification
 pub const MESSAGE_MAGIC: u32 = 0xDEADBEEF;
@@ -56,9 +58,15 @@ pub enum ProtocolError {
     
     #[error("Recovery error: {0}")]
     Recovery(String),
-    
+
     #[error("Transport error: {0}")]
     Transport(#[from] std::io::Error),
+
+    #[error("Bridge policy violation: {0}")]
+    BridgePolicyViolation(String),
+
+    #[error("No rate observed yet for pair: {0}")]
+    UnknownPair(String),
 }
 
 /// Result type for protocol operations
@@ -0,0 +1,368 @@
+//! Snapshot-Based Recovery for Protocol V2
+//!
+//! Retransmission (`RecoveryRequestType::Retransmit`) only works while the
+//! relay's message buffer still holds the missing sequences. Once a consumer
+//! falls too far behind, or the buffer has wrapped, the relay instead offers
+//! a full state checkpoint: reconstructable state (candles, orderbook
+//! snapshots, etc., keyed by `InstrumentId`) as of a known `base_sequence`,
+//! split into fixed-size chunks and content-hashed so a consumer can verify
+//! each one independently before applying it.
+//!
+//! The flow:
+//! 1. The relay checkpoints its state while holding the sequence lock, so
+//!    `base_sequence` is a clean boundary - no live message straddles it.
+//! 2. `SnapshotBuilder::build` splits the serialized state into chunks and
+//!    produces a `SnapshotManifest` describing them.
+//! 3. The consumer drives a `SnapshotImport` with the manifest, feeding it
+//!    chunks as they arrive. A chunk is only considered applied once its hash
+//!    matches the manifest; a mismatch leaves it pending for re-request.
+//! 4. Once `SnapshotImport::is_complete` the consumer reassembles the state
+//!    via `into_state` and replays any buffered live messages with
+//!    `sequence > base_sequence` to converge.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use thiserror::Error;
+
+/// Chunk size used when splitting snapshot state. Kept well under typical
+/// relay buffer sizes so a chunk always fits in one TLV message.
+pub const SNAPSHOT_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Content hash of a single snapshot chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkHash(pub [u8; 32]);
+
+impl ChunkHash {
+    /// Hash a chunk's bytes.
+    pub fn of(data: &[u8]) -> Self {
+        Self(*blake3::hash(data).as_bytes())
+    }
+}
+
+/// Describes a complete snapshot: the sequence it was taken at, the overall
+/// size, and the ordered hash of every chunk. A consumer must not treat a
+/// manifest as authoritative until all of its declared chunks have been
+/// received and validated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    /// Sequence number this snapshot reflects. Live messages with
+    /// `sequence <= base_sequence` are already represented in the snapshot;
+    /// the consumer must replay only `sequence > base_sequence`.
+    pub base_sequence: u64,
+    pub total_size: u64,
+    pub chunk_count: u32,
+    pub chunk_hashes: Vec<ChunkHash>,
+}
+
+impl SnapshotManifest {
+    /// Serialize to the wire format carried by the `SnapshotManifest` TLV.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(20 + self.chunk_hashes.len() * 32);
+        buf.extend_from_slice(&self.base_sequence.to_le_bytes());
+        buf.extend_from_slice(&self.total_size.to_le_bytes());
+        buf.extend_from_slice(&self.chunk_count.to_le_bytes());
+        for hash in &self.chunk_hashes {
+            buf.extend_from_slice(&hash.0);
+        }
+        buf
+    }
+
+    /// Parse from the `SnapshotManifest` TLV payload.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < 20 {
+            return Err(SnapshotError::Truncated);
+        }
+        let base_sequence = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let total_size = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let chunk_count = u32::from_le_bytes(bytes[16..20].try_into().unwrap());
+
+        let expected_len = 20 + chunk_count as usize * 32;
+        if bytes.len() != expected_len {
+            return Err(SnapshotError::Truncated);
+        }
+
+        let mut chunk_hashes = Vec::with_capacity(chunk_count as usize);
+        for i in 0..chunk_count as usize {
+            let start = 20 + i * 32;
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&bytes[start..start + 32]);
+            chunk_hashes.push(ChunkHash(hash));
+        }
+
+        Ok(Self { base_sequence, total_size, chunk_count, chunk_hashes })
+    }
+}
+
+/// One fixed-size slice of a snapshot, carried by the `SnapshotChunk` TLV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotChunk {
+    pub base_sequence: u64,
+    pub chunk_index: u32,
+    pub data: Vec<u8>,
+}
+
+impl SnapshotChunk {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(12 + self.data.len());
+        buf.extend_from_slice(&self.base_sequence.to_le_bytes());
+        buf.extend_from_slice(&self.chunk_index.to_le_bytes());
+        buf.extend_from_slice(&self.data);
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < 12 {
+            return Err(SnapshotError::Truncated);
+        }
+        let base_sequence = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let chunk_index = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let data = bytes[12..].to_vec();
+        Ok(Self { base_sequence, chunk_index, data })
+    }
+}
+
+/// Errors from building or importing a snapshot.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SnapshotError {
+    #[error("snapshot payload is truncated or malformed")]
+    Truncated,
+
+    #[error("chunk belongs to base sequence {got}, expected {expected}")]
+    WrongBaseSequence { expected: u64, got: u64 },
+
+    #[error("chunk index {0} is out of range for this manifest")]
+    ChunkIndexOutOfRange(u32),
+
+    #[error("chunk {index} failed hash validation against the manifest")]
+    ChunkHashMismatch { index: u32 },
+
+    #[error("snapshot is incomplete: {missing} chunk(s) still pending")]
+    Incomplete { missing: usize },
+
+    #[error("reassembled snapshot size {got} does not match manifest total_size {expected}")]
+    SizeMismatch { expected: u64, got: u64 },
+}
+
+/// Splits serialized state into chunks and builds the manifest describing
+/// them. Used by the relay when a consumer's gap exceeds the retransmit
+/// threshold.
+pub struct SnapshotBuilder;
+
+impl SnapshotBuilder {
+    pub fn build(base_sequence: u64, state: &[u8]) -> (SnapshotManifest, Vec<SnapshotChunk>) {
+        if state.is_empty() {
+            let manifest = SnapshotManifest {
+                base_sequence,
+                total_size: 0,
+                chunk_count: 0,
+                chunk_hashes: Vec::new(),
+            };
+            return (manifest, Vec::new());
+        }
+
+        let raw_chunks: Vec<&[u8]> = state.chunks(SNAPSHOT_CHUNK_SIZE).collect();
+        let chunk_hashes: Vec<ChunkHash> = raw_chunks.iter().map(|c| ChunkHash::of(c)).collect();
+
+        let manifest = SnapshotManifest {
+            base_sequence,
+            total_size: state.len() as u64,
+            chunk_count: raw_chunks.len() as u32,
+            chunk_hashes,
+        };
+
+        let chunks = raw_chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, data)| SnapshotChunk {
+                base_sequence,
+                chunk_index: index as u32,
+                data: data.to_vec(),
+            })
+            .collect();
+
+        (manifest, chunks)
+    }
+}
+
+/// Consumer-side state machine for importing a snapshot. Tracks which chunks
+/// are still pending and refuses to hand back a reassembled state until
+/// every chunk has been received and validated.
+#[derive(Debug)]
+pub struct SnapshotImport {
+    manifest: SnapshotManifest,
+    received: HashMap<u32, Vec<u8>>,
+    pending: BTreeSet<u32>,
+    blacklisted_sources: HashSet<String>,
+}
+
+impl SnapshotImport {
+    pub fn new(manifest: SnapshotManifest) -> Self {
+        let pending = (0..manifest.chunk_count).collect();
+        Self {
+            manifest,
+            received: HashMap::new(),
+            pending,
+            blacklisted_sources: HashSet::new(),
+        }
+    }
+
+    pub fn base_sequence(&self) -> u64 {
+        self.manifest.base_sequence
+    }
+
+    /// Validate and apply an incoming chunk. On success, it is removed from
+    /// the pending set; on a hash mismatch the chunk is discarded and stays
+    /// pending so it can be re-requested, optionally from another source.
+    pub fn apply_chunk(
+        &mut self,
+        chunk: SnapshotChunk,
+        source: Option<&str>,
+    ) -> Result<(), SnapshotError> {
+        if chunk.base_sequence != self.manifest.base_sequence {
+            return Err(SnapshotError::WrongBaseSequence {
+                expected: self.manifest.base_sequence,
+                got: chunk.base_sequence,
+            });
+        }
+
+        let expected_hash = self
+            .manifest
+            .chunk_hashes
+            .get(chunk.chunk_index as usize)
+            .ok_or(SnapshotError::ChunkIndexOutOfRange(chunk.chunk_index))?;
+
+        if ChunkHash::of(&chunk.data) != *expected_hash {
+            if let Some(source) = source {
+                self.blacklisted_sources.insert(source.to_string());
+            }
+            return Err(SnapshotError::ChunkHashMismatch { index: chunk.chunk_index });
+        }
+
+        self.received.insert(chunk.chunk_index, chunk.data);
+        self.pending.remove(&chunk.chunk_index);
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Chunk indices still needed to complete the snapshot.
+    pub fn missing_chunks(&self) -> Vec<u32> {
+        self.pending.iter().copied().collect()
+    }
+
+    /// Sources that sent a chunk failing hash validation; a retry should
+    /// prefer a different source when one is available.
+    pub fn is_source_blacklisted(&self, source: &str) -> bool {
+        self.blacklisted_sources.contains(source)
+    }
+
+    /// Reassemble the full state blob. Fails if any chunk is still pending -
+    /// a partially received manifest must never be treated as authoritative.
+    pub fn into_state(self) -> Result<Vec<u8>, SnapshotError> {
+        if !self.pending.is_empty() {
+            return Err(SnapshotError::Incomplete { missing: self.pending.len() });
+        }
+
+        let mut state = Vec::with_capacity(self.manifest.total_size as usize);
+        for index in 0..self.manifest.chunk_count {
+            // Guaranteed present: `pending` is empty, so every index was inserted.
+            state.extend_from_slice(&self.received[&index]);
+        }
+
+        if state.len() as u64 != self.manifest.total_size {
+            return Err(SnapshotError::SizeMismatch {
+                expected: self.manifest.total_size,
+                got: state.len() as u64,
+            });
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_and_reassemble_roundtrip() {
+        let state: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let (manifest, chunks) = SnapshotBuilder::build(1000, &state);
+
+        assert!(manifest.chunk_count > 1);
+        assert_eq!(manifest.total_size, state.len() as u64);
+
+        let mut import = SnapshotImport::new(manifest);
+        for chunk in chunks {
+            import.apply_chunk(chunk, None).unwrap();
+        }
+
+        assert!(import.is_complete());
+        let reassembled = import.into_state().unwrap();
+        assert_eq!(reassembled, state);
+    }
+
+    #[test]
+    fn test_manifest_wire_roundtrip() {
+        let (manifest, _) = SnapshotBuilder::build(42, &[1u8; SNAPSHOT_CHUNK_SIZE + 5]);
+        let bytes = manifest.to_bytes();
+        let parsed = SnapshotManifest::from_bytes(&bytes).unwrap();
+        assert_eq!(manifest, parsed);
+    }
+
+    #[test]
+    fn test_chunk_wire_roundtrip() {
+        let chunk = SnapshotChunk { base_sequence: 7, chunk_index: 3, data: vec![9, 9, 9] };
+        let bytes = chunk.to_bytes();
+        let parsed = SnapshotChunk::from_bytes(&bytes).unwrap();
+        assert_eq!(chunk, parsed);
+    }
+
+    #[test]
+    fn test_hash_mismatch_leaves_chunk_pending_and_blacklists_source() {
+        let (manifest, mut chunks) = SnapshotBuilder::build(1, b"hello world");
+        let mut import = SnapshotImport::new(manifest);
+
+        let corrupted = SnapshotChunk { data: b"corrupted!!".to_vec(), ..chunks.remove(0) };
+        let err = import.apply_chunk(corrupted, Some("relay-a")).unwrap_err();
+
+        assert_eq!(err, SnapshotError::ChunkHashMismatch { index: 0 });
+        assert!(!import.is_complete());
+        assert_eq!(import.missing_chunks(), vec![0]);
+        assert!(import.is_source_blacklisted("relay-a"));
+    }
+
+    #[test]
+    fn test_partial_import_is_never_authoritative() {
+        let (manifest, chunks) = SnapshotBuilder::build(1, &[1u8; SNAPSHOT_CHUNK_SIZE * 2]);
+        let mut import = SnapshotImport::new(manifest);
+        import.apply_chunk(chunks.into_iter().next().unwrap(), None).unwrap();
+
+        assert!(!import.is_complete());
+        assert_eq!(import.into_state().unwrap_err(), SnapshotError::Incomplete { missing: 1 });
+    }
+
+    #[test]
+    fn test_wrong_base_sequence_rejected() {
+        let (manifest, chunks) = SnapshotBuilder::build(10, b"state");
+        let mut import = SnapshotImport::new(manifest);
+
+        let mut mismatched = chunks.into_iter().next().unwrap();
+        mismatched.base_sequence = 11;
+
+        let err = import.apply_chunk(mismatched, None).unwrap_err();
+        assert_eq!(err, SnapshotError::WrongBaseSequence { expected: 10, got: 11 });
+    }
+
+    #[test]
+    fn test_empty_state_produces_trivially_complete_manifest() {
+        let (manifest, chunks) = SnapshotBuilder::build(5, &[]);
+        assert_eq!(manifest.chunk_count, 0);
+        assert!(chunks.is_empty());
+
+        let import = SnapshotImport::new(manifest);
+        assert!(import.is_complete());
+        assert_eq!(import.into_state().unwrap(), Vec::<u8>::new());
+    }
+}
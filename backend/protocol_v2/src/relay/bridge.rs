@@ -0,0 +1,358 @@
+//! Cross-Host Relay Bridge
+//!
+//! `MarketDataRelay` / `SignalRelay` / `ExecutionRelay` forward TLV messages
+//! over a local Unix socket within one host. `BridgeRelay` extends that to a
+//! second relay running on a different machine, connected over TCP (QUIC can
+//! be swapped in later behind the same interface - the bridge only needs a
+//! byte stream).
+//!
+//! Each side of a bridge maintains its own monotonic sequence space, so a
+//! message can't simply be forwarded byte-for-byte: the bridge re-stamps the
+//! `MessageHeader` source and sequence on egress to this side's own space,
+//! while preserving the original source/sequence/domain in a `Provenance`
+//! TLV appended to the message. On the inbound side the bridge tracks the
+//! remote sequence stream through a `ConsumerRegistry`, so a dropped
+//! connection or missed message surfaces as a normal `RecoveryRequest` -
+//! reconnect and gap recovery reuse the same protocol as any other consumer.
+//!
+//! The bridge is domain-aware: each domain crossing the bridge has its own
+//! `BridgeDomainPolicy`, mirroring the per-domain validation policy already
+//! used for checksums (see `test_selective_checksums` in `bin/test_protocol.rs`)
+//! - market data can flow freely, while execution can require authentication
+//! or be refused outright.
+
+use super::{ConsumerId, ConsumerRegistry, RecoveryRequest};
+use crate::{
+    parse_header, parse_tlv_extensions, MessageHeader, ProtocolError, RelayDomain, SourceType,
+    TLVExtensionEnum, TLVMessageBuilder, TLVType,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{debug, info, warn};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Ref};
+
+/// How a domain's messages are treated when crossing the bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeDomainPolicy {
+    /// Forward without authentication (suitable for market data).
+    AllowFreely,
+    /// Forward only if the sender presents the configured auth token.
+    RequireAuth,
+    /// Never forward this domain across the bridge.
+    Refuse,
+}
+
+/// Configuration for one direction of a cross-host bridge.
+#[derive(Debug, Clone)]
+pub struct BridgeConfig {
+    pub domain: RelayDomain,
+    pub remote_addr: String,
+    pub policy: BridgeDomainPolicy,
+    pub auth_token: Option<String>,
+}
+
+/// Original provenance of a message re-stamped by a bridge, carried as a
+/// fixed-size TLV alongside the forwarded payload.
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy, AsBytes, FromBytes, FromZeroes)]
+pub struct ProvenanceTlv {
+    pub original_source: u8,
+    pub original_relay_domain: u8,
+    pub original_sequence: u64,
+}
+
+/// Result of processing one inbound message from the remote side of a bridge.
+#[derive(Debug)]
+pub struct BridgeForward {
+    /// Re-stamped message, ready to forward on the local side.
+    pub message: Vec<u8>,
+    /// Set when the remote sequence stream has a gap; the bridge should
+    /// request recovery from the remote relay before trusting later data.
+    pub recovery_request: Option<RecoveryRequest>,
+}
+
+/// Forwards TLV messages for a single domain between two relay instances on
+/// different hosts, re-sequencing and re-stamping source/provenance as
+/// messages cross.
+pub struct BridgeRelay {
+    domain: RelayDomain,
+    egress_source: SourceType,
+    policy: BridgeDomainPolicy,
+    auth_token: Option<String>,
+    local_sequence: u64,
+    remote_consumers: ConsumerRegistry,
+}
+
+impl BridgeRelay {
+    pub fn new(config: BridgeConfig) -> Self {
+        let egress_source = match config.domain {
+            RelayDomain::MarketData => SourceType::MarketDataRelay,
+            RelayDomain::Signal => SourceType::SignalRelay,
+            RelayDomain::Execution => SourceType::ExecutionRelay,
+        };
+
+        Self {
+            domain: config.domain,
+            egress_source,
+            policy: config.policy,
+            auth_token: config.auth_token,
+            local_sequence: 1,
+            remote_consumers: ConsumerRegistry::new(config.domain),
+        }
+    }
+
+    /// Register the remote relay as a tracked sequence source before
+    /// forwarding its messages. The registry then expects the remote stream
+    /// to start at sequence 1, so the first message is already checked for a
+    /// gap; an unregistered remote is auto-registered on its first message,
+    /// whose sequence is then not recorded.
+    pub fn register_remote(&mut self, remote: ConsumerId) {
+        let _ = self.remote_consumers.register_consumer(remote);
+    }
+
+    /// Check whether a message from `remote` is allowed to cross the bridge
+    /// under this domain's policy.
+    fn check_policy(&self, presented_auth: Option<&str>) -> Result<(), ProtocolError> {
+        match self.policy {
+            BridgeDomainPolicy::Refuse => Err(ProtocolError::BridgePolicyViolation(format!(
+                "bridge refuses to forward {:?} domain traffic",
+                self.domain
+            ))),
+            BridgeDomainPolicy::AllowFreely => Ok(()),
+            BridgeDomainPolicy::RequireAuth => match (&self.auth_token, presented_auth) {
+                (Some(expected), Some(got)) if expected == got => Ok(()),
+                _ => Err(ProtocolError::BridgePolicyViolation(format!(
+                    "bridge requires authentication for {:?} domain",
+                    self.domain
+                ))),
+            },
+        }
+    }
+
+    /// Process one message received from the remote relay: enforce the
+    /// domain policy, track the remote sequence for gap detection, and
+    /// re-stamp the message with a fresh local sequence while preserving the
+    /// original source/sequence/domain in a `Provenance` TLV.
+    pub fn forward_inbound(
+        &mut self,
+        remote: &ConsumerId,
+        message: &[u8],
+        presented_auth: Option<&str>,
+    ) -> Result<BridgeForward, ProtocolError> {
+        self.check_policy(presented_auth)?;
+
+        let header = parse_header(message)?;
+        if header.relay_domain != self.domain as u8 {
+            return Err(ProtocolError::InvalidRelayDomain(header.relay_domain));
+        }
+
+        let original_source = header.source;
+        let original_sequence = header.sequence;
+        let original_relay_domain = header.relay_domain;
+
+        // Each side has its own sequence space, so a gap here means the
+        // *remote* relay skipped us - plug straight into the same recovery
+        // protocol a local consumer would use.
+        let recovery_request = self
+            .remote_consumers
+            .update_consumer_sequence(remote, original_sequence);
+
+        let tlv_payload = &message[MessageHeader::SIZE..];
+        let mut builder = TLVMessageBuilder::new(self.domain, self.egress_source);
+        for tlv in parse_tlv_extensions(tlv_payload)? {
+            let (tlv_type, payload) = match tlv {
+                TLVExtensionEnum::Standard(ext) => (ext.header.tlv_type, ext.payload),
+                TLVExtensionEnum::Extended(ext) => (ext.header.tlv_type, ext.payload),
+            };
+            let tlv_type = TLVType::try_from(tlv_type).map_err(|_| ProtocolError::UnknownTLV(tlv_type))?;
+            builder = builder.add_tlv_bytes(tlv_type, payload);
+        }
+
+        let provenance = ProvenanceTlv {
+            original_source,
+            original_relay_domain,
+            original_sequence,
+        };
+        builder = builder.add_tlv(TLVType::Provenance, &provenance);
+
+        let message = builder.with_sequence(self.local_sequence).build();
+        self.local_sequence += 1;
+
+        Ok(BridgeForward { message, recovery_request })
+    }
+
+    /// Connect to the remote relay and continuously forward its messages
+    /// through `forward_inbound`, handing re-stamped bytes to `on_forward`.
+    /// On disconnect the caller is expected to reconnect; any gap detected
+    /// mid-stream is surfaced via the `recovery_request` of the
+    /// `BridgeForward` handed to `on_forward`.
+    pub async fn run(
+        &mut self,
+        remote_addr: &str,
+        remote: ConsumerId,
+        presented_auth: Option<&str>,
+        mut on_forward: impl FnMut(BridgeForward),
+    ) -> Result<(), ProtocolError> {
+        info!("Bridging {:?} domain to {}", self.domain, remote_addr);
+        self.register_remote(remote.clone());
+        let mut stream = TcpStream::connect(remote_addr)
+            .await
+            .map_err(ProtocolError::Transport)?;
+
+        // Unlike the same-host Unix-socket relays, where one read() lining up
+        // with one message is the common case, a cross-host TCP stream
+        // routinely splits a message across reads or coalesces several into
+        // one. Accumulate bytes in `pending` until a full message (header +
+        // `payload_size`) is available before handing it to `forward_inbound`.
+        let mut read_buf = vec![0u8; 65536];
+        let mut pending: Vec<u8> = Vec::new();
+        loop {
+            let bytes_read = stream.read(&mut read_buf).await.map_err(ProtocolError::Transport)?;
+            if bytes_read == 0 {
+                info!("Bridge to {} closed by remote", remote_addr);
+                return Ok(());
+            }
+            pending.extend_from_slice(&read_buf[..bytes_read]);
+
+            while let Some(message_len) = Self::pending_message_len(&pending)? {
+                let message: Vec<u8> = pending.drain(..message_len).collect();
+
+                match self.forward_inbound(&remote, &message, presented_auth) {
+                    Ok(forward) => {
+                        if forward.recovery_request.is_some() {
+                            warn!("Bridge detected gap from {}: {:?}", remote_addr, forward.recovery_request);
+                        }
+                        on_forward(forward);
+                    }
+                    Err(e) => {
+                        debug!("Dropping message from bridge {}: {}", remote_addr, e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the total length (header + TLV payload) of the next complete
+    /// message sitting at the front of `pending`, or `None` if not enough
+    /// bytes have arrived yet to know it (or to complete it). Only the magic
+    /// number is checked here - `forward_inbound`'s `parse_header` still does
+    /// full checksum validation once the whole message is in hand.
+    fn pending_message_len(pending: &[u8]) -> Result<Option<usize>, ProtocolError> {
+        if pending.len() < MessageHeader::SIZE {
+            return Ok(None);
+        }
+
+        let header_bytes = &pending[..MessageHeader::SIZE];
+        let header = Ref::<_, MessageHeader>::new(header_bytes)
+            .ok_or(ProtocolError::Parse(crate::ParseError::MessageTooSmall {
+                need: MessageHeader::SIZE,
+                got: pending.len(),
+            }))?
+            .into_ref();
+
+        if header.magic != crate::MESSAGE_MAGIC {
+            return Err(ProtocolError::Parse(crate::ParseError::InvalidMagic {
+                expected: crate::MESSAGE_MAGIC,
+                actual: header.magic,
+            }));
+        }
+
+        let message_len = MessageHeader::SIZE + header.payload_size as usize;
+        Ok((pending.len() >= message_len).then_some(message_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceType;
+
+    fn config(policy: BridgeDomainPolicy, auth_token: Option<&str>) -> BridgeConfig {
+        BridgeConfig {
+            domain: RelayDomain::MarketData,
+            remote_addr: "127.0.0.1:9999".to_string(),
+            policy,
+            auth_token: auth_token.map(str::to_string),
+        }
+    }
+
+    fn sample_message(domain: RelayDomain, sequence: u64) -> Vec<u8> {
+        #[repr(C, packed)]
+        #[derive(AsBytes, FromBytes, FromZeroes)]
+        struct Dummy {
+            value: u64,
+        }
+
+        TLVMessageBuilder::new(domain, SourceType::PolygonCollector)
+            .add_tlv(TLVType::Trade, &Dummy { value: 42 })
+            .with_sequence(sequence)
+            .build()
+    }
+
+    #[test]
+    fn test_allow_freely_forwards_and_restamps() {
+        let mut bridge = BridgeRelay::new(config(BridgeDomainPolicy::AllowFreely, None));
+        let remote = ConsumerId::new("remote-relay", 1);
+        bridge.register_remote(remote.clone());
+        let message = sample_message(RelayDomain::MarketData, 1);
+
+        let forward = bridge.forward_inbound(&remote, &message, None).unwrap();
+        let header = parse_header(&forward.message).unwrap();
+
+        assert_eq!(header.sequence, 1); // Re-stamped to this side's own space
+        assert_eq!(header.source, SourceType::MarketDataRelay as u8);
+
+        let tlv_payload = &forward.message[MessageHeader::SIZE..];
+        let tlvs = parse_tlv_extensions(tlv_payload).unwrap();
+        assert!(tlvs.iter().any(|t| matches!(t,
+            TLVExtensionEnum::Standard(ext) if ext.header.tlv_type == TLVType::Provenance as u8)));
+    }
+
+    #[test]
+    fn test_refuse_policy_blocks_all_traffic() {
+        let mut bridge = BridgeRelay::new(config(BridgeDomainPolicy::Refuse, None));
+        let remote = ConsumerId::new("remote-relay", 1);
+        let message = sample_message(RelayDomain::MarketData, 1);
+
+        let err = bridge.forward_inbound(&remote, &message, None).unwrap_err();
+        assert!(matches!(err, ProtocolError::BridgePolicyViolation(_)));
+    }
+
+    #[test]
+    fn test_require_auth_rejects_missing_or_wrong_token() {
+        let mut bridge = BridgeRelay::new(config(BridgeDomainPolicy::RequireAuth, Some("secret")));
+        let remote = ConsumerId::new("remote-relay", 1);
+        let message = sample_message(RelayDomain::MarketData, 1);
+
+        assert!(bridge.forward_inbound(&remote, &message, None).is_err());
+        assert!(bridge.forward_inbound(&remote, &message, Some("wrong")).is_err());
+        assert!(bridge.forward_inbound(&remote, &message, Some("secret")).is_ok());
+    }
+
+    #[test]
+    fn test_gap_in_remote_sequence_triggers_recovery_request() {
+        let mut bridge = BridgeRelay::new(config(BridgeDomainPolicy::AllowFreely, None));
+        let remote = ConsumerId::new("remote-relay", 1);
+        bridge.register_remote(remote.clone());
+
+        let first = sample_message(RelayDomain::MarketData, 1);
+        let forward = bridge.forward_inbound(&remote, &first, None).unwrap();
+        assert!(forward.recovery_request.is_none());
+
+        let skipped = sample_message(RelayDomain::MarketData, 10);
+        let forward = bridge.forward_inbound(&remote, &skipped, None).unwrap();
+        let recovery = forward.recovery_request.unwrap();
+        assert_eq!(recovery.start_sequence, 2);
+        assert_eq!(recovery.end_sequence, 9);
+    }
+
+    #[test]
+    fn test_wrong_domain_message_is_rejected() {
+        let mut bridge = BridgeRelay::new(config(BridgeDomainPolicy::AllowFreely, None));
+        let remote = ConsumerId::new("remote-relay", 1);
+        let message = sample_message(RelayDomain::Signal, 1);
+
+        let err = bridge.forward_inbound(&remote, &message, None).unwrap_err();
+        assert!(matches!(err, ProtocolError::InvalidRelayDomain(_)));
+    }
+}
@@ -3,6 +3,8 @@
 //! Base relay implementation with common functionality for all domain-specific relays.
 
 use crate::{MessageHeader, parse_header, RelayDomain, SourceType, ProtocolError};
+use crate::recovery::SnapshotBuilder;
+use crate::tlv::{TLVMessageBuilder, TLVType};
 use super::{ConsumerId, RelayConfig, RelayStats, RecoveryRequest, RecoveryRequestType};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -91,19 +93,30 @@ pub struct BaseRelay {
     pub state: Arc<RwLock<RelayState>>,
     pub message_buffer: Vec<(u64, Vec<u8>)>, // (sequence, message) for recovery
     pub connected_clients: Vec<UnixStream>,
+    /// Produces a serialized checkpoint of reconstructable state (candles,
+    /// orderbook snapshots, etc.) for snapshot-based recovery. `None` means
+    /// this relay has nothing to checkpoint, so snapshot requests fall back
+    /// to an empty response.
+    pub snapshot_provider: Option<Arc<dyn Fn() -> Vec<u8> + Send + Sync>>,
 }
 
 impl BaseRelay {
     pub fn new(config: RelayConfig) -> Self {
         let state = RelayState::new(&config);
-        
+
         Self {
             config: config.clone(),
             state: Arc::new(RwLock::new(state)),
             message_buffer: Vec::new(),
             connected_clients: Vec::new(),
+            snapshot_provider: None,
         }
     }
+
+    /// Install the callback used to checkpoint state for snapshot recovery.
+    pub fn set_snapshot_provider(&mut self, provider: Arc<dyn Fn() -> Vec<u8> + Send + Sync>) {
+        self.snapshot_provider = Some(provider);
+    }
     
     /// Start the relay server
     pub async fn start(&mut self) -> Result<(), ProtocolError> {
@@ -279,11 +292,47 @@ impl BaseRelay {
         Ok(messages)
     }
     
-    async fn handle_snapshot_request(&self, _request: RecoveryRequest) -> Result<Vec<Vec<u8>>, ProtocolError> {
-        // Snapshot generation would be implemented here
-        // For now, return empty (not implemented)
-        warn!("Snapshot recovery not yet implemented");
-        Ok(vec![])
+    async fn handle_snapshot_request(&self, request: RecoveryRequest) -> Result<Vec<Vec<u8>>, ProtocolError> {
+        let Some(provider) = &self.snapshot_provider else {
+            warn!("Snapshot recovery requested by {:?} but no snapshot provider is configured",
+                  request.consumer_id);
+            return Ok(vec![]);
+        };
+
+        // Checkpoint while holding the sequence lock so base_sequence is a
+        // clean boundary: no live message being assigned a sequence can
+        // straddle the snapshot, so the consumer's later replay of
+        // `sequence > base_sequence` neither double-applies nor drops one.
+        let (base_sequence, source, state_bytes) = {
+            let state = self.state.read().await;
+            let source = match state.domain {
+                RelayDomain::MarketData => SourceType::MarketDataRelay,
+                RelayDomain::Signal => SourceType::SignalRelay,
+                RelayDomain::Execution => SourceType::ExecutionRelay,
+            };
+            (state.global_sequence.saturating_sub(1), source, provider())
+        };
+
+        let (manifest, chunks) = SnapshotBuilder::build(base_sequence, &state_bytes);
+
+        info!("Snapshot for consumer {:?}: base_sequence={}, {} chunks, {} bytes",
+              request.consumer_id, base_sequence, manifest.chunk_count, manifest.total_size);
+
+        let mut messages = Vec::with_capacity(1 + chunks.len());
+        messages.push(
+            TLVMessageBuilder::new(self.config.domain, source)
+                .add_tlv_bytes(TLVType::SnapshotManifest, manifest.to_bytes())
+                .build(),
+        );
+        for chunk in chunks {
+            messages.push(
+                TLVMessageBuilder::new(self.config.domain, source)
+                    .add_tlv_bytes(TLVType::SnapshotChunk, chunk.to_bytes())
+                    .build(),
+            );
+        }
+
+        Ok(messages)
     }
 }
 
@@ -332,4 +381,57 @@ mod tests {
         assert_eq!(recovery_req.start_sequence, 2);
         assert_eq!(recovery_req.end_sequence, 4);
     }
+
+    #[tokio::test]
+    async fn test_snapshot_request_without_provider_returns_empty() {
+        let config = RelayConfig::market_data("/tmp/test_snapshot_noprovider.sock");
+        let mut relay = BaseRelay::new(config);
+
+        let request = RecoveryRequest {
+            consumer_id: ConsumerId::new("dashboard", 1),
+            start_sequence: 1,
+            end_sequence: 500,
+            request_type: RecoveryRequestType::Snapshot,
+        };
+
+        let messages = relay.handle_recovery_request(request).await.unwrap();
+        assert!(messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_request_with_provider_builds_manifest_and_chunks() {
+        let config = RelayConfig::market_data("/tmp/test_snapshot_provider.sock");
+        let mut relay = BaseRelay::new(config);
+        relay.set_snapshot_provider(Arc::new(|| vec![7u8; crate::recovery::SNAPSHOT_CHUNK_SIZE + 1]));
+
+        {
+            let mut state = relay.state.write().await;
+            state.global_sequence = 101; // Snapshot base_sequence is global_sequence - 1 = 100
+        }
+
+        let request = RecoveryRequest {
+            consumer_id: ConsumerId::new("dashboard", 1),
+            start_sequence: 1,
+            end_sequence: 500,
+            request_type: RecoveryRequestType::Snapshot,
+        };
+
+        let messages = relay.handle_recovery_request(request).await.unwrap();
+        // One manifest message followed by one chunk per SNAPSHOT_CHUNK_SIZE-sized piece.
+        assert_eq!(messages.len(), 3);
+
+        let tlv_payload = &messages[0][MessageHeader::SIZE..];
+        let tlvs = crate::tlv::parse_tlv_extensions(tlv_payload).unwrap();
+        match &tlvs[0] {
+            crate::tlv::TLVExtensionEnum::Extended(ext) => {
+                assert_eq!(ext.header.tlv_type, TLVType::SnapshotManifest as u8);
+                let manifest = crate::recovery::SnapshotManifest::from_bytes(&ext.payload).unwrap();
+                assert_eq!(manifest.base_sequence, 100);
+                assert_eq!(manifest.chunk_count, 2);
+            }
+            crate::tlv::TLVExtensionEnum::Standard(std_tlv) => {
+                assert_eq!(std_tlv.header.tlv_type, TLVType::SnapshotManifest as u8);
+            }
+        }
+    }
 }
\ No newline at end of file
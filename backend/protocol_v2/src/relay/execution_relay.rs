@@ -7,6 +7,7 @@
 
 use super::{BaseRelay, RelayConfig, ConsumerId, RelayStats, RecoveryRequest};
 use crate::{RelayDomain, SourceType, ProtocolError, MessageHeader, parse_header, parse_tlv_extensions, TLVExtensionEnum, InstrumentId};
+use crate::validation::{KeyRegistry, verify_execution_signature};
 use std::sync::Arc;
 use std::collections::HashMap;
 use tokio::sync::{RwLock, broadcast};
@@ -26,6 +27,10 @@ pub struct ExecutionRelay {
     audit_log: Option<tokio::fs::File>,
     security_log: Option<tokio::fs::File>,
     execution_events: Vec<ExecutionEvent>,
+    /// Authorized Ed25519 keys for signed execution messages, registered via
+    /// `register_signing_key`. Unsigned or badly-signed messages are always rejected;
+    /// while the registry is empty every message is (see `validation::signature`).
+    key_registry: Arc<RwLock<KeyRegistry>>,
 }
 
 /// Execution message with full security validation
@@ -177,9 +182,16 @@ impl ExecutionRelay {
             audit_log,
             security_log,
             execution_events: Vec::new(),
+            key_registry: Arc::new(RwLock::new(KeyRegistry::new())),
         })
     }
-    
+
+    /// Authorize a signing key to produce execution messages. Until at least one key is
+    /// registered, every execution message is rejected.
+    pub async fn register_signing_key(&self, key_id: u32, verifying_key: ed25519_dalek::VerifyingKey) {
+        self.key_registry.write().await.register(key_id, verifying_key);
+    }
+
     /// Start the execution relay server
     pub async fn start(&mut self) -> Result<(), ProtocolError> {
         info!("🛡️  Starting Execution Relay (MAXIMUM SECURITY MODE)");
@@ -213,10 +225,11 @@ impl ExecutionRelay {
                     
                     let state = Arc::clone(&self.base.state);
                     let config = self.base.config.clone();
+                    let key_registry = Arc::clone(&self.key_registry);
                     let message_receiver = self.message_sender.subscribe();
-                    
+
                     tokio::spawn(async move {
-                        if let Err(e) = Self::handle_execution_client(socket, state, config, message_receiver).await {
+                        if let Err(e) = Self::handle_execution_client(socket, state, config, key_registry, message_receiver).await {
                             error!("🚨 SECURITY: Execution client error: {}", e);
                         }
                     });
@@ -233,6 +246,7 @@ impl ExecutionRelay {
         mut socket: UnixStream,
         state: Arc<RwLock<super::RelayState>>,
         config: RelayConfig,
+        key_registry: Arc<RwLock<KeyRegistry>>,
         mut message_receiver: broadcast::Receiver<ExecutionMessage>,
     ) -> Result<(), ProtocolError> {
         let mut read_buffer = vec![0u8; config.buffer_size_bytes];
@@ -250,7 +264,7 @@ impl ExecutionRelay {
                             let message_data = &read_buffer[..bytes_read];
                             
                             // SECURITY CRITICAL PATH - MAXIMUM VALIDATION
-                            match Self::process_execution_message(message_data, &state, &config).await {
+                            match Self::process_execution_message(message_data, &state, &config, &key_registry).await {
                                 Ok(exec_msg) => {
                                     info!("✅ SECURE: Execution message type {} processed with full validation", exec_msg.tlv_type);
                                 }
@@ -303,25 +317,33 @@ impl ExecutionRelay {
         message_data: &[u8],
         state: &Arc<RwLock<super::RelayState>>,
         config: &RelayConfig,
+        key_registry: &Arc<RwLock<KeyRegistry>>,
     ) -> Result<ExecutionMessage, ProtocolError> {
         // CRITICAL: ALWAYS validate checksum for execution messages
         let header = parse_header(message_data)?;
-        
+
         // Domain validation
         if header.relay_domain != RelayDomain::Execution as u8 {
             error!("🚨 SECURITY VIOLATION: Wrong domain {} for execution relay", header.relay_domain);
             return Err(ProtocolError::InvalidRelayDomain(header.relay_domain));
         }
-        
+
         // Source validation - ensure source is authorized for execution
         let source = crate::SourceType::try_from(header.source)
             .map_err(|_| ProtocolError::Parse(crate::ParseError::UnknownSource(header.source)))?;
-            
+
         if !Self::is_authorized_execution_source(source) {
             error!("🚨 SECURITY VIOLATION: Unauthorized source {:?} attempting execution", source);
             return Err(ProtocolError::Parse(crate::ParseError::UnknownSource(header.source)));
         }
-        
+
+        // CRITICAL: the checksum only catches corruption, not forgery. Verify the
+        // Signature TLV against the authorized key set before trusting the message.
+        if let Err(e) = verify_execution_signature(message_data, &*key_registry.read().await) {
+            error!("🚨 SECURITY VIOLATION: Signature verification failed for source {:?}: {}", source, e);
+            return Err(e);
+        }
+
         // Validate TLV type range for execution (40-59)
         let tlv_payload = &message_data[MessageHeader::SIZE..];
         let tlvs = parse_tlv_extensions(tlv_payload)?;
@@ -335,6 +357,12 @@ impl ExecutionRelay {
                 TLVExtensionEnum::Extended(ref ext_tlv) => ext_tlv.header.tlv_type,
             };
             
+            // The Signature TLV rides alongside the execution payload but isn't an
+            // execution TLV itself, so it's exempt from the 40-59 range check.
+            if tlv_type == crate::TLVType::Signature as u8 {
+                continue;
+            }
+
             if !(40..=59).contains(&tlv_type) {
                 error!("🚨 SECURITY VIOLATION: Invalid TLV type {} for execution domain (must be 40-59)", tlv_type);
                 return Err(ProtocolError::UnknownTLV(tlv_type));
@@ -10,12 +10,14 @@ pub mod market_data_relay;
 pub mod signal_relay;
 pub mod execution_relay;
 pub mod consumer_registry;
+pub mod bridge;
 
 pub use core::*;
 pub use market_data_relay::*;
 pub use signal_relay::*;
 pub use execution_relay::*;
 pub use consumer_registry::*;
+pub use bridge::*;
 
 use crate::{RelayDomain, SourceType};
 use std::collections::HashMap;
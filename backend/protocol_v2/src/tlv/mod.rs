@@ -46,6 +46,15 @@ pub enum ParseError {
     
     #[error("TLV payload too large: {size} bytes")]
     PayloadTooLarge { size: usize },
+
+    #[error("Message is missing a required Signature TLV")]
+    MissingSignature,
+
+    #[error("Signature verification failed")]
+    InvalidSignature,
+
+    #[error("Unknown signing key id: {0}")]
+    UnknownSigningKey(u32),
 }
 
 /// TLV Header for standard TLVs (types 1-254)
@@ -55,13 +55,17 @@ pub enum TLVType {
     ConfigUpdate = 103,
     ServiceDiscovery = 104,
     MetricsReport = 105,
-    // Reserved 106-109 for future system types
+    Signature = 106,
+    // Reserved 107-109 for future system types
     
     // Recovery Domain (110-119)
     RecoveryRequest = 110,
     RecoveryResponse = 111,
     SequenceSync = 112,
-    // Reserved 113-119 for future recovery types
+    SnapshotManifest = 113,
+    SnapshotChunk = 114,
+    Provenance = 115,
+    // Reserved 116-119 for future recovery types
     
     // Extended TLV marker (255)
     ExtendedTLV = 255,
@@ -86,7 +90,7 @@ impl TLVType {
     /// Check if this TLV type is reserved/undefined
     pub fn is_reserved(&self) -> bool {
         match *self as u8 {
-            10..=19 | 32..=39 | 50..=59 | 60..=99 | 106..=109 | 113..=119 | 120..=199 | 200..=254 => true,
+            10..=19 | 32..=39 | 50..=59 | 60..=99 | 107..=109 | 116..=119 | 120..=199 | 200..=254 => true,
             _ => false,
         }
     }
@@ -114,6 +118,8 @@ impl TLVType {
             TLVType::ExecutionReport => Some(48),
             TLVType::Heartbeat => Some(16),
             TLVType::RecoveryRequest => Some(18),
+            TLVType::Provenance => Some(10),
+            TLVType::Signature => Some(68),
             // Variable-size TLVs
             _ => None,
         }
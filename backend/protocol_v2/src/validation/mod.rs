@@ -4,6 +4,8 @@
 
 pub mod checksum;
 pub mod bounds;
+pub mod signature;
 
 pub use checksum::*;
-pub use bounds::*;
\ No newline at end of file
+pub use bounds::*;
+pub use signature::*;
\ No newline at end of file
@@ -1,14 +1,90 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
 
-fn generate_hash(canonical: &str) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    canonical.hash(&mut hasher);
-    hasher.finish()
+/// FNV-1a 64-bit offset basis and prime. Unlike `DefaultHasher` (SipHash),
+/// whose internal constants and byte-feeding order aren't specified and can
+/// change across Rust releases, FNV-1a is a few lines of documented,
+/// language-portable arithmetic - the same hash can be reproduced exactly in
+/// TypeScript, so the frontend mapping can't silently desync from this one.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a_hash(canonical: &str) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in canonical.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Registers canonical `exchange:symbol` strings and assigns each a stable
+/// FNV-1a hash, hard-erroring the moment two distinct canonical strings
+/// collide so the Rust table and the frontend mapping emitted from it can
+/// never silently desync.
+#[derive(Default)]
+struct SymbolRegistry {
+    hash_to_canonical: HashMap<u64, String>,
+    display_names: Vec<(String, String)>, // (canonical, display_name), insertion order preserved
+}
+
+impl SymbolRegistry {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn register(&mut self, canonical: &str, display_name: &str) {
+        let hash = fnv1a_hash(canonical);
+
+        if let Some(existing) = self.hash_to_canonical.get(&hash) {
+            if existing != canonical {
+                panic!(
+                    "FNV-1a hash collision: '{}' and '{}' both hash to {}",
+                    existing, canonical, hash
+                );
+            }
+        }
+
+        self.hash_to_canonical.insert(hash, canonical.to_string());
+        self.display_names.push((canonical.to_string(), display_name.to_string()));
+    }
+
+    fn hash_of(&self, canonical: &str) -> u64 {
+        fnv1a_hash(canonical)
+    }
+
+    /// Emit the Rust constant table - the source of truth the frontend
+    /// mapping below is generated from.
+    fn emit_rust_table(&self) {
+        println!("// Generated symbol hash table (FNV-1a 64-bit)");
+        println!("pub const SYMBOL_TABLE: &[(u64, &str, &str)] = &[");
+        for (canonical, display_name) in &self.display_names {
+            println!("    ({}, \"{}\", \"{}\"),", self.hash_of(canonical), canonical, display_name);
+        }
+        println!("];");
+    }
+
+    fn emit_frontend_table(&self) {
+        println!("// Generated symbol hash mappings");
+        println!("// Copy these to frontend/src/dashboard/utils/symbolHash.ts");
+        println!();
+        println!("const HASH_TO_SYMBOL: Record<string, string> = {{");
+        for (canonical, display_name) in &self.display_names {
+            println!("  '{}': '{}', // {}", self.hash_of(canonical), display_name, canonical);
+        }
+        println!("}};");
+        println!();
+        println!("// For reference - canonical to hash mapping:");
+        println!("const CANONICAL_TO_HASH: Record<string, string> = {{");
+        for (canonical, _) in &self.display_names {
+            println!("  '{}': '{}',", canonical, self.hash_of(canonical));
+        }
+        println!("}};");
+    }
 }
 
 fn main() {
-    // Generate hashes for all known symbols
+    let mut registry = SymbolRegistry::new();
+
     let symbols = vec![
         // Coinbase crypto pairs
         ("coinbase:BTC-USD", "BTC-USD"),
@@ -19,11 +95,11 @@ fn main() {
         ("coinbase:MATIC-USD", "MATIC-USD"),
         ("coinbase:ADA-USD", "ADA-USD"),
         ("coinbase:DOT-USD", "DOT-USD"),
-        
+
         // Additional pairs that might be used
         ("coinbase:BTC-USDT", "BTC-USDT"),
         ("coinbase:ETH-USDT", "ETH-USDT"),
-        
+
         // Potential stock symbols from Alpaca
         ("alpaca:AAPL", "AAPL"),
         ("alpaca:GOOGL", "GOOGL"),
@@ -36,26 +112,12 @@ fn main() {
         ("alpaca:QQQ", "QQQ"),
         ("alpaca:AMZN", "AMZN"),
     ];
-    
-    println!("// Generated symbol hash mappings");
-    println!("// Copy these to frontend/src/dashboard/utils/symbolHash.ts");
-    println!();
-    println!("const HASH_TO_SYMBOL: Record<string, string> = {{");
-    
+
     for (canonical, display_name) in &symbols {
-        let hash = generate_hash(canonical);
-        println!("  '{}': '{}', // {}", hash, display_name, canonical);
+        registry.register(canonical, display_name);
     }
-    
-    println!("}};");
+
+    registry.emit_rust_table();
     println!();
-    println!("// For reference - canonical to hash mapping:");
-    println!("const CANONICAL_TO_HASH: Record<string, string> = {{");
-    
-    for (canonical, _) in &symbols {
-        let hash = generate_hash(canonical);
-        println!("  '{}': '{}',", canonical, hash);
-    }
-    
-    println!("}};");
-}
\ No newline at end of file
+    registry.emit_frontend_table();
+}
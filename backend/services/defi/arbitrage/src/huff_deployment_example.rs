@@ -4,7 +4,7 @@
 use anyhow::Result;
 use tracing::{info, warn};
 
-use crate::mev_protection::{HuffMevIntegration, HuffDeploymentStatus, HuffMetrics};
+use crate::mev_protection::{HuffMevIntegration, HuffDeploymentStatus, HuffMetrics, Wei};
 
 /// Example function showing how Huff deployment updates affect MEV protection
 pub async fn demonstrate_huff_mev_integration() -> Result<()> {
@@ -21,8 +21,8 @@ pub async fn demonstrate_huff_mev_integration() -> Result<()> {
     // Simulate starting canary deployment at 1%
     info!("📈 Starting Huff canary deployment at 1%...");
     let initial_metrics = HuffMetrics {
-        measured_huff_gas: 46_500,      // Slightly above target
-        measured_solidity_gas: 185_000,
+        measured_huff_gas: Wei::from(46_500u64),      // Slightly above target
+        measured_solidity_gas: Wei::from(185_000u64),
         gas_improvement_ratio: 185_000.0 / 46_500.0, // ~4.0x
         success_rate: 0.99,
         total_executions: 25,
@@ -49,8 +49,8 @@ pub async fn demonstrate_huff_mev_integration() -> Result<()> {
     // Simulate canary expansion to 25%
     info!("📈 Expanding canary deployment to 25%...");
     let expanded_metrics = HuffMetrics {
-        measured_huff_gas: 45_800,      // Improving with more usage
-        measured_solidity_gas: 185_000,
+        measured_huff_gas: Wei::from(45_800u64),      // Improving with more usage
+        measured_solidity_gas: Wei::from(185_000u64),
         gas_improvement_ratio: 185_000.0 / 45_800.0, // ~4.0x
         success_rate: 0.995,
         total_executions: 150,
@@ -74,8 +74,8 @@ pub async fn demonstrate_huff_mev_integration() -> Result<()> {
     // Simulate full deployment
     info!("🎯 Completing full Huff deployment...");
     let full_metrics = HuffMetrics {
-        measured_huff_gas: 44_200,      // Achieved target efficiency
-        measured_solidity_gas: 185_000,
+        measured_huff_gas: Wei::from(44_200u64),      // Achieved target efficiency
+        measured_solidity_gas: Wei::from(185_000u64),
         gas_improvement_ratio: 185_000.0 / 44_200.0, // ~4.2x
         success_rate: 0.998,
         total_executions: 500,
@@ -195,8 +195,8 @@ mod tests {
         
         // Phase 2: Canary deployment
         let metrics = HuffMetrics {
-            measured_huff_gas: 45_000,
-            measured_solidity_gas: 180_000,
+            measured_huff_gas: Wei::from(45_000u64),
+            measured_solidity_gas: Wei::from(180_000u64),
             gas_improvement_ratio: 4.0,
             success_rate: 0.99,
             total_executions: 100,
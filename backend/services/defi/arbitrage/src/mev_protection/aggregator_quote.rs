@@ -0,0 +1,130 @@
+// DEX-aggregator swap-quote client: grounds the MEV decision's
+// expected-loss estimate in a real best-execution quote instead of relying
+// purely on internal heuristics (profit, path complexity, execution speed).
+use anyhow::{anyhow, Context, Result};
+use ethers::types::U256;
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::amount::Wei;
+
+/// A 0x/1inch-style swap-quote response, parsed through [`Wei`] so amounts
+/// round-trip whether the aggregator emits `"0x..."` hex or plain decimal.
+#[derive(Debug, Clone, Deserialize)]
+struct AggregatorQuoteResponse {
+    #[serde(rename = "sellAmount")]
+    sell_amount: Wei,
+    #[serde(rename = "buyAmount")]
+    buy_amount: Wei,
+}
+
+/// A fetched quote's amounts, in the units the aggregator reports them.
+#[derive(Debug, Clone, Copy)]
+pub struct AggregatorQuote {
+    pub sell_amount: U256,
+    pub buy_amount: U256,
+}
+
+impl AggregatorQuote {
+    /// Fraction of the aggregator's best-execution output that the bot's own
+    /// computed output falls short of - the slippage surface a sandwich or
+    /// backrun could realistically extract. Zero if the bot's own route is
+    /// at least as good as the aggregator's.
+    pub fn estimated_extractable_fraction(&self, bot_computed_buy_amount: U256) -> f64 {
+        if self.buy_amount.is_zero() || bot_computed_buy_amount >= self.buy_amount {
+            return 0.0;
+        }
+        let gap = self.buy_amount - bot_computed_buy_amount;
+        (gap.as_u128() as f64 / self.buy_amount.as_u128() as f64).clamp(0.0, 1.0)
+    }
+}
+
+/// Minimal client for a standard swap-quote endpoint (sell token, buy token,
+/// sell amount in, best-execution buy amount out).
+pub struct AggregatorQuoteClient {
+    http_client: reqwest::Client,
+    base_url: String,
+    request_timeout: Duration,
+}
+
+impl AggregatorQuoteClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            base_url: base_url.into(),
+            request_timeout: Duration::from_secs(3),
+        }
+    }
+
+    pub async fn get_quote(
+        &self,
+        sell_token: &str,
+        buy_token: &str,
+        sell_amount: U256,
+    ) -> Result<AggregatorQuote> {
+        let url = format!("{}/swap/v1/quote", self.base_url);
+
+        let response = self
+            .http_client
+            .get(&url)
+            .query(&[
+                ("sellToken", sell_token.to_string()),
+                ("buyToken", buy_token.to_string()),
+                ("sellAmount", format!("{:#x}", sell_amount)),
+            ])
+            .timeout(self.request_timeout)
+            .send()
+            .await
+            .context("Failed to reach DEX aggregator")?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Aggregator quote request returned status: {}",
+                response.status()
+            ));
+        }
+
+        let parsed: AggregatorQuoteResponse = response
+            .json()
+            .await
+            .context("Failed to parse aggregator quote response")?;
+
+        Ok(AggregatorQuote {
+            sell_amount: parsed.sell_amount.as_u256(),
+            buy_amount: parsed.buy_amount.as_u256(),
+        })
+    }
+}
+
+/// Parameters for grounding a single decision's expected loss in a live quote.
+#[derive(Debug, Clone)]
+pub struct QuoteGroundingRequest {
+    pub sell_token: String,
+    pub buy_token: String,
+    pub sell_amount: U256,
+    pub bot_computed_buy_amount: U256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extractable_fraction_is_zero_when_bot_output_is_better() {
+        let quote = AggregatorQuote {
+            sell_amount: U256::from(1_000u64),
+            buy_amount: U256::from(2_000u64),
+        };
+        assert_eq!(quote.estimated_extractable_fraction(U256::from(2_500u64)), 0.0);
+    }
+
+    #[test]
+    fn extractable_fraction_reflects_slippage_gap() {
+        let quote = AggregatorQuote {
+            sell_amount: U256::from(1_000u64),
+            buy_amount: U256::from(2_000u64),
+        };
+        let fraction = quote.estimated_extractable_fraction(U256::from(1_800u64));
+        assert!((fraction - 0.1).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,84 @@
+// Exact-integer amount type for wei/gas quantities in the MEV-protection
+// path. Plain `f64` loses precision once wei-denominated values get large
+// enough (gas * gas_price alone can exceed 2^53) and doesn't round-trip with
+// on-chain data, which is exactly what's needed when comparing measured Huff
+// gas usage against live RPC/aggregator figures.
+use ethers::types::U256;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A wei/gas-denominated integer amount. Serializes as `"0x..."` hex (the
+/// canonical on-chain representation) but deserializes from either `"0x..."`
+/// hex or a plain decimal string, since RPC providers and quote aggregators
+/// don't agree on which one they emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Wei(pub U256);
+
+impl Wei {
+    pub fn as_u256(&self) -> U256 {
+        self.0
+    }
+
+    /// Lossy, saturating conversion for call sites that still track gas as
+    /// `u64` (e.g. `MarketContext`'s gas-usage fields).
+    pub fn as_u64_saturating(&self) -> u64 {
+        if self.0 > U256::from(u64::MAX) {
+            u64::MAX
+        } else {
+            self.0.as_u64()
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl From<U256> for Wei {
+    fn from(value: U256) -> Self {
+        Wei(value)
+    }
+}
+
+impl From<u64> for Wei {
+    fn from(value: u64) -> Self {
+        Wei(U256::from(value))
+    }
+}
+
+impl Serialize for Wei {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:#x}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Wei {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        let value = if let Some(hex) = raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+            U256::from_str_radix(hex, 16).map_err(serde::de::Error::custom)?
+        } else {
+            U256::from_dec_str(&raw).map_err(serde::de::Error::custom)?
+        };
+        Ok(Wei(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_hex() {
+        let amount = Wei(U256::from(123_456_789u64));
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, "\"0x75bcd15\"");
+        let parsed: Wei = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, amount);
+    }
+
+    #[test]
+    fn accepts_decimal_input() {
+        let parsed: Wei = serde_json::from_str("\"123456789\"").unwrap();
+        assert_eq!(parsed, Wei(U256::from(123_456_789u64)));
+    }
+}
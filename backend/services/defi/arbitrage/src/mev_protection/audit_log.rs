@@ -0,0 +1,229 @@
+// Append-only Merkle audit trail for Huff deployment transitions and MEV
+// protection decisions, so operators can cryptographically verify a given
+// record was logged without replaying the full history.
+use ethers::types::H256;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::huff_integration::{HuffDeploymentStatus, HuffMetrics};
+
+/// One logged event: either a deployment-status transition or a protection
+/// decision, each carrying enough context to be independently verified.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AuditRecord {
+    DeploymentTransition {
+        timestamp: u64,
+        prior_status: HuffDeploymentStatus,
+        new_status: HuffDeploymentStatus,
+        metrics: HuffMetrics,
+    },
+    ProtectionDecision {
+        timestamp: u64,
+        profit_usd: f64,
+        urgency: f64,
+        use_protection: bool,
+    },
+}
+
+pub fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn hash_leaf(record: &AuditRecord) -> H256 {
+    let encoded = serde_json::to_vec(record).expect("AuditRecord is always serializable");
+    ethers::utils::keccak256(&encoded).into()
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    let mut buf = Vec::with_capacity(64);
+    buf.extend_from_slice(left.as_bytes());
+    buf.extend_from_slice(right.as_bytes());
+    ethers::utils::keccak256(&buf).into()
+}
+
+/// Sibling hashes from a leaf up to the root, used by [`MerkleAuditLog::prove`]
+/// to let an external verifier confirm inclusion without the full history.
+#[derive(Debug, Clone)]
+pub struct InclusionProof {
+    pub leaf_index: usize,
+    pub leaf_hash: H256,
+    /// `(sibling_hash, sibling_is_left)` for each level, leaf to root.
+    pub siblings: Vec<(H256, bool)>,
+}
+
+impl InclusionProof {
+    /// Recompute the root implied by this proof; callers compare it against
+    /// the log's root at the time the proof was issued.
+    pub fn compute_root(&self) -> H256 {
+        let mut current = self.leaf_hash;
+        for (sibling, sibling_is_left) in &self.siblings {
+            current = if *sibling_is_left {
+                hash_pair(sibling, &current)
+            } else {
+                hash_pair(&current, sibling)
+            };
+        }
+        current
+    }
+}
+
+#[derive(Default)]
+struct Tree {
+    leaves: Vec<H256>,
+    records: Vec<AuditRecord>,
+}
+
+/// Append-only Merkle tree over [`AuditRecord`]s. Levels are rebuilt from the
+/// leaves on each query rather than maintained incrementally - the log is
+/// expected to hold thousands, not billions, of entries.
+pub struct MerkleAuditLog {
+    tree: RwLock<Tree>,
+}
+
+impl MerkleAuditLog {
+    pub fn new() -> Self {
+        Self {
+            tree: RwLock::new(Tree::default()),
+        }
+    }
+
+    /// Append a record and return its leaf index.
+    pub fn append(&self, record: AuditRecord) -> usize {
+        let leaf_hash = hash_leaf(&record);
+        let mut tree = self.tree.write();
+        tree.leaves.push(leaf_hash);
+        tree.records.push(record);
+        tree.leaves.len() - 1
+    }
+
+    /// Current Merkle root: the zero hash for an empty log, the leaf hash
+    /// itself for a single-leaf log.
+    pub fn root(&self) -> H256 {
+        let tree = self.tree.read();
+        Self::compute_root(&tree.leaves)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.read().leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn compute_root(leaves: &[H256]) -> H256 {
+        if leaves.is_empty() {
+            return H256::zero();
+        }
+        let mut level = leaves.to_vec();
+        while level.len() > 1 {
+            level = Self::next_level(&level);
+        }
+        level[0]
+    }
+
+    /// Hash adjacent pairs up one level, duplicating the last node when the
+    /// level has an odd count.
+    fn next_level(level: &[H256]) -> Vec<H256> {
+        let mut next = Vec::with_capacity((level.len() + 1) / 2);
+        let mut i = 0;
+        while i < level.len() {
+            let left = level[i];
+            let right = if i + 1 < level.len() { level[i + 1] } else { level[i] };
+            next.push(hash_pair(&left, &right));
+            i += 2;
+        }
+        next
+    }
+
+    /// Build an inclusion proof for `leaf_index`, or `None` if out of range.
+    pub fn prove(&self, leaf_index: usize) -> Option<InclusionProof> {
+        let tree = self.tree.read();
+        if leaf_index >= tree.leaves.len() {
+            return None;
+        }
+
+        let mut siblings = Vec::new();
+        let mut level = tree.leaves.clone();
+        let mut index = leaf_index;
+
+        while level.len() > 1 {
+            let is_right = index % 2 == 1;
+            let sibling_index = if is_right {
+                index - 1
+            } else if index + 1 < level.len() {
+                index + 1
+            } else {
+                index
+            };
+            siblings.push((level[sibling_index], is_right));
+
+            level = Self::next_level(&level);
+            index /= 2;
+        }
+
+        Some(InclusionProof {
+            leaf_index,
+            leaf_hash: tree.leaves[leaf_index],
+            siblings,
+        })
+    }
+}
+
+impl Default for MerkleAuditLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(use_protection: bool) -> AuditRecord {
+        AuditRecord::ProtectionDecision {
+            timestamp: 0,
+            profit_usd: 10.0,
+            urgency: 0.5,
+            use_protection,
+        }
+    }
+
+    #[test]
+    fn empty_log_has_zero_root() {
+        let log = MerkleAuditLog::new();
+        assert_eq!(log.root(), H256::zero());
+    }
+
+    #[test]
+    fn single_leaf_root_is_leaf_hash() {
+        let log = MerkleAuditLog::new();
+        let idx = log.append(decision(true));
+        let proof = log.prove(idx).unwrap();
+        assert_eq!(log.root(), proof.leaf_hash);
+    }
+
+    #[test]
+    fn inclusion_proof_verifies_against_root() {
+        let log = MerkleAuditLog::new();
+        for i in 0..5 {
+            log.append(decision(i % 2 == 0));
+        }
+
+        for i in 0..5 {
+            let proof = log.prove(i).unwrap();
+            assert_eq!(proof.compute_root(), log.root());
+        }
+    }
+
+    #[test]
+    fn out_of_range_proof_is_none() {
+        let log = MerkleAuditLog::new();
+        log.append(decision(true));
+        assert!(log.prove(5).is_none());
+    }
+}
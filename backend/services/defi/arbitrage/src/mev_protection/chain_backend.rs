@@ -0,0 +1,171 @@
+// Per-chain MEV-protection parameters, so `ProductionMevProtection`'s
+// economics (safety margins, protection cost, doomed thresholds) aren't
+// hardcoded to a single chain's block time and builder landscape. Follows
+// the same enum-of-implementations shape used elsewhere in this codebase
+// for adding a new chain/asset without forking the logic that consumes it.
+use std::sync::Arc;
+
+/// How concentrated block building is on a chain - a handful of dominant
+/// builders make private-order-flow advantages stronger (and public-mempool
+/// front-running easier). Each backend reports it; `ProductionMevProtection`
+/// reads only `block_time_ms` and `private_submission_endpoint`, so neither
+/// this nor `base_fee_gwei` affects its protection decision.
+#[derive(Debug, Clone, Copy)]
+pub struct BuilderLandscape {
+    pub relay_count: u32,
+    /// Share of blocks produced by the single largest builder/sequencer, in [0, 1].
+    pub dominant_builder_share: f64,
+}
+
+/// A chain's MEV-protection-relevant parameters: block cadence, builder/relay
+/// landscape, base-fee model, and where to submit a privately-protected
+/// transaction (if the chain supports one at all).
+pub trait MevProtectionBackend: Send + Sync {
+    fn chain_name(&self) -> &str;
+
+    /// Average time between blocks - faster blocks shrink the window a
+    /// front-runner has to react, so it scales the safety margin.
+    fn block_time_ms(&self) -> u64;
+
+    /// Estimate the base fee in gwei for a given block fullness in [0, 1].
+    fn base_fee_gwei(&self, block_fullness: f64) -> f64;
+
+    fn builder_landscape(&self) -> BuilderLandscape;
+
+    /// Endpoint for a native private-submission path (e.g. a Flashbots-style
+    /// relay), or `None` if this chain has no private mempool and protection
+    /// can only mean commit-reveal or timing strategies.
+    fn private_submission_endpoint(&self) -> Option<&str>;
+}
+
+/// Ethereum-mainnet-style chain: ~12s blocks, EIP-1559 base fee, a handful of
+/// dominant relays (Flashbots et al.), real private-mempool submission.
+pub struct EvmMainnetBackend {
+    relay_url: String,
+}
+
+impl EvmMainnetBackend {
+    pub fn new(relay_url: impl Into<String>) -> Self {
+        Self {
+            relay_url: relay_url.into(),
+        }
+    }
+}
+
+impl Default for EvmMainnetBackend {
+    fn default() -> Self {
+        Self::new("https://relay.flashbots.net")
+    }
+}
+
+impl MevProtectionBackend for EvmMainnetBackend {
+    fn chain_name(&self) -> &str {
+        "evm-mainnet"
+    }
+
+    fn block_time_ms(&self) -> u64 {
+        12_000
+    }
+
+    fn base_fee_gwei(&self, block_fullness: f64) -> f64 {
+        // EIP-1559: base fee moves up to 12.5% per block depending on how
+        // full the parent block was relative to the 50% target.
+        let baseline = 30.0;
+        let pressure = (block_fullness.clamp(0.0, 1.0) - 0.5) * 2.0;
+        (baseline * (1.0 + pressure * 0.125)).max(1.0)
+    }
+
+    fn builder_landscape(&self) -> BuilderLandscape {
+        BuilderLandscape {
+            relay_count: 6,
+            dominant_builder_share: 0.45,
+        }
+    }
+
+    fn private_submission_endpoint(&self) -> Option<&str> {
+        Some(&self.relay_url)
+    }
+}
+
+/// An L2-style chain (e.g. an Arbitrum/Optimism-class rollup): cheap, fast
+/// sequencer-ordered blocks, no public mempool auction in the Ethereum sense,
+/// and - because the sequencer is a single party - no private-relay path to
+/// submit through; protection there has to rely on other strategies
+/// (commit-reveal, timing) rather than a Flashbots-style bundle.
+pub struct L2Backend {
+    chain_name: String,
+    block_time_ms: u64,
+}
+
+impl L2Backend {
+    pub fn new(chain_name: impl Into<String>, block_time_ms: u64) -> Self {
+        Self {
+            chain_name: chain_name.into(),
+            block_time_ms,
+        }
+    }
+}
+
+impl Default for L2Backend {
+    fn default() -> Self {
+        Self::new("arbitrum", 250)
+    }
+}
+
+impl MevProtectionBackend for L2Backend {
+    fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+
+    fn block_time_ms(&self) -> u64 {
+        self.block_time_ms
+    }
+
+    fn base_fee_gwei(&self, _block_fullness: f64) -> f64 {
+        // L2 gas is priced in fractions of a gwei and barely reacts to
+        // fullness the way L1's EIP-1559 curve does.
+        0.1
+    }
+
+    fn builder_landscape(&self) -> BuilderLandscape {
+        BuilderLandscape {
+            relay_count: 1,
+            dominant_builder_share: 1.0,
+        }
+    }
+
+    fn private_submission_endpoint(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Default backend for a new `ProductionMevProtection` when the caller
+/// doesn't pick one explicitly.
+pub fn default_backend() -> Arc<dyn MevProtectionBackend> {
+    Arc::new(EvmMainnetBackend::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evm_mainnet_has_private_submission() {
+        let backend = EvmMainnetBackend::default();
+        assert!(backend.private_submission_endpoint().is_some());
+        assert_eq!(backend.block_time_ms(), 12_000);
+    }
+
+    #[test]
+    fn l2_backend_has_no_private_relay() {
+        let backend = L2Backend::default();
+        assert!(backend.private_submission_endpoint().is_none());
+        assert!(backend.block_time_ms() < EvmMainnetBackend::default().block_time_ms());
+    }
+
+    #[test]
+    fn base_fee_rises_with_block_fullness() {
+        let backend = EvmMainnetBackend::default();
+        assert!(backend.base_fee_gwei(0.9) > backend.base_fee_gwei(0.1));
+    }
+}
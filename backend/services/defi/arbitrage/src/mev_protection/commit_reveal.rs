@@ -0,0 +1,79 @@
+// Commit-reveal submission: when MEV protection is warranted but no trusted
+// private relay is available for the chain, broadcast only a commitment
+// hash first and reveal the real payload once that commitment is confirmed,
+// so front-runners have nothing actionable to copy from the mempool.
+use ethers::types::H256;
+use rand::Rng;
+
+/// Submission path chosen for a protected transaction, alongside the
+/// public-mempool and private-relay strategies this sits between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionStrategy {
+    PublicMempool,
+    PrivateRelay,
+    CommitReveal,
+}
+
+/// A prepared commit-reveal submission: `commitment` is what gets broadcast
+/// immediately, `payload`/`salt` are revealed only once the commitment is
+/// confirmed. The salt is kept in memory here; a real deployment would want
+/// it encrypted at rest until reveal time.
+#[derive(Debug, Clone)]
+pub struct CommitRevealSubmission {
+    pub commitment: H256,
+    payload: Vec<u8>,
+    salt: [u8; 32],
+}
+
+impl CommitRevealSubmission {
+    /// Commit to `payload` with a freshly generated salt.
+    pub fn commit(payload: Vec<u8>) -> Self {
+        let mut salt = [0u8; 32];
+        rand::thread_rng().fill(&mut salt);
+        let commitment = Self::hash(&payload, &salt);
+        Self {
+            commitment,
+            payload,
+            salt,
+        }
+    }
+
+    /// The `(payload, salt)` to broadcast once `commitment` is confirmed; an
+    /// executor contract checks `H(payload || salt) == commitment` before
+    /// executing.
+    pub fn reveal(&self) -> (&[u8], [u8; 32]) {
+        (&self.payload, self.salt)
+    }
+
+    /// Verify a revealed `(payload, salt)` pair against a previously
+    /// broadcast commitment, as the executor contract would.
+    pub fn verify(payload: &[u8], salt: &[u8; 32], commitment: H256) -> bool {
+        Self::hash(payload, salt) == commitment
+    }
+
+    fn hash(payload: &[u8], salt: &[u8; 32]) -> H256 {
+        let mut buf = Vec::with_capacity(payload.len() + salt.len());
+        buf.extend_from_slice(payload);
+        buf.extend_from_slice(salt);
+        ethers::utils::keccak256(&buf).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_verifies_against_commitment() {
+        let submission = CommitRevealSubmission::commit(b"tx payload".to_vec());
+        let (payload, salt) = submission.reveal();
+        assert!(CommitRevealSubmission::verify(payload, &salt, submission.commitment));
+    }
+
+    #[test]
+    fn tampered_payload_fails_verification() {
+        let submission = CommitRevealSubmission::commit(b"tx payload".to_vec());
+        let (_, salt) = submission.reveal();
+        assert!(!CommitRevealSubmission::verify(b"different payload", &salt, submission.commitment));
+    }
+}
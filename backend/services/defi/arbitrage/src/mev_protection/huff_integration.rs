@@ -10,6 +10,7 @@ use parking_lot::RwLock;
 use tracing::{debug, info, warn};
 
 use super::{MarketContext, Strategy};
+use super::audit_log::{now_unix, AuditRecord, InclusionProof, MerkleAuditLog};
 
 /// Real gas measurements for different Huff contract types
 #[derive(Debug, Clone)]
@@ -86,6 +87,10 @@ pub struct HuffMevIntegration {
     deployment_status: Arc<RwLock<HuffDeploymentStatus>>,
     metrics: Arc<RwLock<HuffMetrics>>,
     config: HuffIntegrationConfig,
+    /// Tamper-evident record of every deployment transition and protection
+    /// decision, so operators can verify the history without trusting this
+    /// process's in-memory state.
+    audit_log: MerkleAuditLog,
 }
 
 #[derive(Debug, Clone)]
@@ -128,20 +133,44 @@ impl HuffMevIntegration {
                 sample_count: 0,
             })),
             config,
+            audit_log: MerkleAuditLog::new(),
         }
     }
-    
-    /// Update deployment status from canary monitoring
+
+    /// Update deployment status from canary monitoring, recording the
+    /// transition (prior status, new status, current metrics snapshot) in
+    /// the audit log before applying it.
     pub fn update_deployment_status(&self, status: HuffDeploymentStatus) {
+        let prior_status = self.deployment_status.read().clone();
+        let metrics_snapshot = self.metrics.read().clone();
+        self.audit_log.append(AuditRecord::DeploymentTransition {
+            timestamp: now_unix(),
+            prior_status,
+            new_status: status.clone(),
+            metrics: metrics_snapshot,
+        });
+
         let mut current = self.deployment_status.write();
         *current = status;
-        
+
         info!(
             "Huff deployment updated: {}% deployed, {:.1}% gas reduction",
             current.deployment_percentage,
             current.gas_reduction_achieved * 100.0
         );
     }
+
+    /// Current Merkle root over the audit log, or the zero hash if nothing
+    /// has been recorded yet.
+    pub fn audit_root(&self) -> ethers::types::H256 {
+        self.audit_log.root()
+    }
+
+    /// Build an inclusion proof for a previously logged audit entry by its
+    /// leaf index (as returned implicitly by append order, starting at 0).
+    pub fn audit_proof(&self, leaf_index: usize) -> Option<InclusionProof> {
+        self.audit_log.prove(leaf_index)
+    }
     
     /// Update metrics from production monitoring
     pub fn update_metrics(&self, metrics: HuffMetrics) {
@@ -202,36 +231,50 @@ impl HuffMevIntegration {
         }
     }
     
-    /// Determine if we should use Huff implementation
+    /// Determine if we should use Huff implementation. The outcome is
+    /// recorded in the audit log alongside the parameters that produced it.
     pub fn should_use_huff(&self, profit_usd: f64, urgency: f64) -> bool {
+        let use_huff = self.decide_use_huff(profit_usd, urgency);
+
+        self.audit_log.append(AuditRecord::ProtectionDecision {
+            timestamp: now_unix(),
+            profit_usd,
+            urgency,
+            use_protection: use_huff,
+        });
+
+        use_huff
+    }
+
+    fn decide_use_huff(&self, profit_usd: f64, urgency: f64) -> bool {
         let status = self.deployment_status.read();
         let metrics = self.metrics.read();
-        
+
         // Not deployed or not verified
         if !status.is_deployed || !status.parity_verified {
             return false;
         }
-        
+
         // Insufficient data
         if metrics.sample_count < self.config.min_samples_for_confidence {
             return false;
         }
-        
+
         // Check success rate threshold
         if metrics.success_rate < 0.99 {
             warn!("Huff success rate {:.2}% below threshold", metrics.success_rate * 100.0);
             return false;
         }
-        
+
         // High urgency trades might use proven Solidity
         if urgency > 0.9 && status.deployment_percentage < 100 {
             return false;
         }
-        
+
         // Use deployment percentage as probability
         let deployment_factor = status.deployment_percentage as f64 / 100.0;
         let random_value = rand::random::<f64>();
-        
+
         random_value < deployment_factor
     }
     
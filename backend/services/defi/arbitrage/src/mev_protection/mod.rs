@@ -2,6 +2,11 @@ pub mod flashbots_client;
 pub mod production_mev;
 pub mod logging;
 pub mod config;
+pub mod amount;
+pub mod audit_log;
+pub mod chain_backend;
+pub mod commit_reveal;
+pub mod aggregator_quote;
 
 /// MEV protection strategy types
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -28,6 +33,11 @@ pub use integration::{MevProtectionSystem, MevSystemConfig, MevSystemStatistics,
 pub use logging::{MevLogger, MevDecisionLog, MevOutcomeLog, MevTransactionLog};
 pub use config::MevLoggingConfig;
 pub use huff_integration::{HuffMevIntegration, HuffMevReport, MevProtectionImpact, DeploymentSnapshot};
+pub use amount::Wei;
+pub use audit_log::{AuditRecord, InclusionProof, MerkleAuditLog};
+pub use chain_backend::{BuilderLandscape, EvmMainnetBackend, L2Backend, MevProtectionBackend};
+pub use commit_reveal::{CommitRevealSubmission, SubmissionStrategy};
+pub use aggregator_quote::{AggregatorQuote, AggregatorQuoteClient, QuoteGroundingRequest};
 
 use anyhow::{Result, Context};
 use ethers::prelude::*;
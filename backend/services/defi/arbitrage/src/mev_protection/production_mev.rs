@@ -1,4 +1,5 @@
 use anyhow::Result;
+use ethers::types::U256;
 use std::collections::{HashMap, VecDeque};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, warn};
@@ -6,6 +7,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::price_oracle::{LivePriceOracle, PriceManager};
 
+use super::aggregator_quote::{AggregatorQuoteClient, QuoteGroundingRequest};
+use super::amount::Wei;
+use super::chain_backend::{self, MevProtectionBackend};
+use super::commit_reveal::SubmissionStrategy;
+use std::sync::Arc;
+
+/// Extra round-trip latency a two-phase commit-reveal submission adds over a
+/// single-shot private-relay bundle.
+const COMMIT_REVEAL_EXTRA_LATENCY_MS: u64 = 150;
+
 // Huff migration integration types defined inline
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,8 +29,12 @@ pub enum HuffDeploymentStatus {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HuffMetrics {
-    pub measured_huff_gas: u64,
-    pub measured_solidity_gas: u64,
+    /// Measured gas usage, kept as an exact integer (rather than `f64`) so it
+    /// round-trips precisely with on-chain data and doesn't drift when
+    /// compared against fresh RPC measurements.
+    pub measured_huff_gas: Wei,
+    pub measured_solidity_gas: Wei,
+    // Derived from the two gas measurements above, so it stays a float.
     pub gas_improvement_ratio: f64,
     pub success_rate: f64,
     pub total_executions: u32,
@@ -54,6 +69,14 @@ pub struct ProductionMevProtection {
     signal_version: SignalVersion,
     huff_deployment_status: HuffDeploymentStatus,
     huff_metrics: Option<HuffMetrics>,
+    /// Per-chain block cadence, builder landscape, and private-submission
+    /// path - lets the same decision logic run on any chain without forking
+    /// the economics in `should_use_protection`.
+    backend: Arc<dyn MevProtectionBackend>,
+    /// Optional DEX-aggregator quote client for grounding `expected_mev_loss`
+    /// in a live best-execution quote instead of the heuristic alone. `None`
+    /// means every decision falls back to the heuristic model.
+    aggregator_quote_client: Option<Arc<AggregatorQuoteClient>>,
 }
 
 #[derive(Debug, Clone)]
@@ -274,9 +297,27 @@ impl ProductionMevProtection {
             signal_version: SignalVersion { block_number: 0, timestamp_ns: 0 },
             huff_deployment_status: HuffDeploymentStatus::NotDeployed,
             huff_metrics: None,
+            backend: chain_backend::default_backend(),
+            aggregator_quote_client: None,
         }
     }
 
+    /// Create with an explicit chain backend instead of the default
+    /// EVM-mainnet-style one (e.g. an [`super::chain_backend::L2Backend`]).
+    pub fn with_backend(execution_speed_ms: u64, backend: Arc<dyn MevProtectionBackend>) -> Self {
+        Self {
+            backend,
+            ..Self::new(execution_speed_ms)
+        }
+    }
+
+    /// Configure a DEX-aggregator quote client so decisions can be grounded
+    /// in a live best-execution quote. Without one, every decision falls
+    /// back to the existing heuristic model.
+    pub fn set_aggregator_quote_client(&mut self, client: Arc<AggregatorQuoteClient>) {
+        self.aggregator_quote_client = Some(client);
+    }
+
     /// Main decision function with all production fixes applied
     pub fn should_use_protection(
         &self,
@@ -405,11 +446,15 @@ impl ProductionMevProtection {
             GasTrend::Rising(r) | GasTrend::Falling(r) => r,
             GasTrend::Stable => 0.02,
         };
-        
+
         // Builder fee floor
         let builder_fee = 0.05; // 5%
-        
-        (gas_volatility + builder_fee).min(0.2) // Cap at 20%
+
+        // Faster blocks leave a front-runner less time to react, so a
+        // sub-second L2 block needs less margin than a 12s L1 block.
+        let block_time_factor = (self.backend.block_time_ms() as f64 / 12_000.0).min(1.0);
+
+        (gas_volatility + builder_fee * block_time_factor).min(0.2) // Cap at 20%
     }
 
     /// Gray zone assessment with calibrated probabilities
@@ -562,10 +607,15 @@ impl ProductionMevProtection {
     /// Production protection cost model
     fn estimate_protection_cost(&self, profit_usd: f64) -> f64 {
         let model = &self.protection_cost_model;
-        
-        // Base relay fee
-        let relay_fee = model.base_relay_fee_usd;
-        
+
+        // No native private-submission path on this chain (e.g. an L2 with
+        // a single sequencer) - there's no relay fee to pay.
+        let relay_fee = if self.backend.private_submission_endpoint().is_some() {
+            model.base_relay_fee_usd
+        } else {
+            0.0
+        };
+
         // Extra gas cost for bundle vs public
         let gas_overhead = self.market_context.current_gas_gwei * 
                           50_000.0 * // Extra gas for bundle
@@ -687,8 +737,8 @@ impl ProductionMevProtection {
             self.market_context.huff_efficiency_ratio = metrics.gas_improvement_ratio;
             
             // Update target gas usage if measurements differ from estimates
-            if metrics.measured_huff_gas > 0 {
-                self.market_context.huff_gas_usage = metrics.measured_huff_gas;
+            if !metrics.measured_huff_gas.is_zero() {
+                self.market_context.huff_gas_usage = metrics.measured_huff_gas.as_u64_saturating();
             }
         }
         
@@ -761,6 +811,92 @@ impl ProductionMevProtection {
         Ok(())
     }
     
+    /// Name of the chain backend currently in effect (e.g. "evm-mainnet").
+    pub fn chain_name(&self) -> &str {
+        self.backend.chain_name()
+    }
+
+    /// Pick how a protected decision should actually be submitted. Private
+    /// relay when the chain's backend has one; otherwise commit-reveal, but
+    /// only when its two-phase confirmation delay doesn't eat more of the
+    /// expected MEV savings than it protects.
+    pub fn choose_submission_strategy(&self, decision: &MevDecision) -> SubmissionStrategy {
+        if !decision.use_protection {
+            return SubmissionStrategy::PublicMempool;
+        }
+
+        if self.backend.private_submission_endpoint().is_some() {
+            return SubmissionStrategy::PrivateRelay;
+        }
+
+        let delay_risk = decision.expected_mev_loss * self.commit_reveal_delay_risk_factor();
+        if decision.expected_mev_loss - delay_risk > decision.protection_cost {
+            SubmissionStrategy::CommitReveal
+        } else {
+            SubmissionStrategy::PublicMempool
+        }
+    }
+
+    /// Same decision as [`Self::should_use_protection`], but when an
+    /// aggregator quote client is configured and `quote_request` is given,
+    /// grounds `threat_probability`/`expected_mev_loss` in the real slippage
+    /// gap between the aggregator's best-execution quote and the bot's own
+    /// computed output, instead of relying purely on the heuristic. Falls
+    /// back to the heuristic decision whenever no client/request is
+    /// configured, or the aggregator is unreachable.
+    pub async fn should_use_protection_with_quote(
+        &self,
+        profit_usd: f64,
+        path_complexity: usize,
+        execution_speed_ms: u64,
+        quote_request: Option<&QuoteGroundingRequest>,
+    ) -> MevDecision {
+        let heuristic = self.should_use_protection(profit_usd, path_complexity, execution_speed_ms);
+
+        let (Some(client), Some(request)) = (self.aggregator_quote_client.as_ref(), quote_request) else {
+            return heuristic;
+        };
+
+        match client
+            .get_quote(&request.sell_token, &request.buy_token, request.sell_amount)
+            .await
+        {
+            Ok(quote) => {
+                let extractable_fraction =
+                    quote.estimated_extractable_fraction(request.bot_computed_buy_amount);
+                let threat_probability = extractable_fraction.max(heuristic.threat_probability);
+                let expected_mev_loss = profit_usd * threat_probability;
+                let use_protection = expected_mev_loss > heuristic.protection_cost;
+
+                MevDecision {
+                    use_protection,
+                    threat_probability,
+                    expected_mev_loss,
+                    reasoning: format!(
+                        "QUOTE-GROUNDED: aggregator slippage gap {:.2}% -> threat_p={:.3}, mev_loss=${:.2}, prot_cost=${:.2} -> {}",
+                        extractable_fraction * 100.0,
+                        threat_probability,
+                        expected_mev_loss,
+                        heuristic.protection_cost,
+                        if use_protection { "PROTECT" } else { "PUBLIC" }
+                    ),
+                    ..heuristic
+                }
+            }
+            Err(e) => {
+                warn!("Aggregator quote unreachable, falling back to heuristic MEV estimate: {}", e);
+                heuristic
+            }
+        }
+    }
+
+    /// How much of the commit-reveal delay is "exposed" relative to a block:
+    /// a 150ms reveal gap matters far more on a 250ms L2 block than on a 12s
+    /// L1 block, so the risk scales with that ratio.
+    fn commit_reveal_delay_risk_factor(&self) -> f64 {
+        (COMMIT_REVEAL_EXTRA_LATENCY_MS as f64 / self.backend.block_time_ms() as f64).min(1.0)
+    }
+
     // Public getter methods for accessing private fields
     pub fn get_market_context(&self) -> &MarketContext {
         &self.market_context
@@ -895,8 +1031,8 @@ mod tests {
         
         // Deploy Huff at 25%
         let metrics = HuffMetrics {
-            measured_huff_gas: 47_000, // Slightly higher than target
-            measured_solidity_gas: 185_000,
+            measured_huff_gas: Wei::from(47_000u64), // Slightly higher than target
+            measured_solidity_gas: Wei::from(185_000u64),
             gas_improvement_ratio: 185_000.0 / 47_000.0, // ~3.9x
             success_rate: 0.98,
             total_executions: 150,
@@ -925,6 +1061,79 @@ mod tests {
         assert!(full_advantage.mev_advantage_factor > advantage.mev_advantage_factor);
     }
 
+    #[test]
+    fn test_l2_backend_has_no_relay_fee() {
+        use super::super::chain_backend::L2Backend;
+
+        let mainnet = ProductionMevProtection::new(100);
+        let l2 = ProductionMevProtection::with_backend(100, Arc::new(L2Backend::default()));
+
+        assert_eq!(mainnet.chain_name(), "evm-mainnet");
+        assert_eq!(l2.chain_name(), "arbitrum");
+        assert!(l2.estimate_protection_cost(100.0) < mainnet.estimate_protection_cost(100.0));
+    }
+
+    #[test]
+    fn test_commit_reveal_chosen_without_private_relay() {
+        use super::super::chain_backend::L2Backend;
+
+        let l2 = ProductionMevProtection::with_backend(100, Arc::new(L2Backend::default()));
+        let decision = MevDecision {
+            use_protection: true,
+            threat_probability: 0.8,
+            break_even_advantage: 0.0,
+            competition_factor: 0.5,
+            expected_mev_loss: 50.0,
+            protection_cost: 5.0,
+            reasoning: String::new(),
+            signal_version: l2.signal_version.clone(),
+        };
+
+        assert_eq!(l2.choose_submission_strategy(&decision), SubmissionStrategy::CommitReveal);
+
+        let mainnet = ProductionMevProtection::new(100);
+        assert_eq!(mainnet.choose_submission_strategy(&decision), SubmissionStrategy::PrivateRelay);
+
+        let no_protection = MevDecision { use_protection: false, ..decision };
+        assert_eq!(l2.choose_submission_strategy(&no_protection), SubmissionStrategy::PublicMempool);
+    }
+
+    #[tokio::test]
+    async fn test_quote_grounding_falls_back_without_client() {
+        let protection = ProductionMevProtection::new(100);
+        let heuristic = protection.should_use_protection(50.0, 3, 150);
+
+        let request = QuoteGroundingRequest {
+            sell_token: "0xSELL".to_string(),
+            buy_token: "0xBUY".to_string(),
+            sell_amount: U256::from(1_000u64),
+            bot_computed_buy_amount: U256::from(900u64),
+        };
+
+        let grounded = protection
+            .should_use_protection_with_quote(50.0, 3, 150, Some(&request))
+            .await;
+
+        assert_eq!(grounded.use_protection, heuristic.use_protection);
+        assert_eq!(grounded.threat_probability, heuristic.threat_probability);
+    }
+
+    #[tokio::test]
+    async fn test_quote_grounding_falls_back_without_request() {
+        let mut protection = ProductionMevProtection::new(100);
+        protection.set_aggregator_quote_client(Arc::new(AggregatorQuoteClient::new(
+            "https://example.invalid",
+        )));
+        let heuristic = protection.should_use_protection(50.0, 3, 150);
+
+        let grounded = protection
+            .should_use_protection_with_quote(50.0, 3, 150, None)
+            .await;
+
+        assert_eq!(grounded.use_protection, heuristic.use_protection);
+        assert_eq!(grounded.threat_probability, heuristic.threat_probability);
+    }
+
     #[test]
     fn test_dynamic_break_even_calculation() {
         let mut protection = ProductionMevProtection::new(100);
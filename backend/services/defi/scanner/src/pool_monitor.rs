@@ -325,8 +325,8 @@ impl PoolMonitor {
                             // Handle different message types
                             match processed_msg {
                                 ProcessedMessage::Trade(trade_data) => {
-                                    debug!("💱 New protocol trade: instrument={:?} price={} volume={}", 
-                                           trade_data.instrument_id, trade_data.price, trade_data.volume);
+                                    debug!("💱 New protocol trade: instrument={:?} price={} volume={}",
+                                           trade_data.instrument_id, trade_data.price.to_f64_lossy(), trade_data.volume.to_f64_lossy());
                                     
                                     // Trigger scan for this instrument if it's a pool
                                     if let Some(sender) = scan_trigger_sender.read().as_ref() {
@@ -347,8 +347,8 @@ impl PoolMonitor {
                                            quote_data.instrument_id, quote_data.bid_price, quote_data.ask_price);
                                 }
                                 ProcessedMessage::SwapEvent(swap_data) => {
-                                    debug!("🔄 New protocol swap: pool={:?} token0_in={} token1_out={}", 
-                                           swap_data.pool_id, swap_data.amount0_in, swap_data.amount1_out);
+                                    debug!("🔄 New protocol swap: pool={:?} token0_in={} token1_out={}",
+                                           swap_data.pool_id, swap_data.amount0_in.to_f64_lossy(), swap_data.amount1_out.to_f64_lossy());
                                     
                                     // Handle swap event for arbitrage detection
                                     if let Some(sender) = scan_trigger_sender.read().as_ref() {
@@ -365,8 +365,8 @@ impl PoolMonitor {
                                     }
                                 }
                                 ProcessedMessage::PoolUpdate(pool_data) => {
-                                    debug!("📊 New protocol pool update: pool={:?} reserve0={} reserve1={}", 
-                                           pool_data.pool_id, pool_data.reserve0, pool_data.reserve1);
+                                    debug!("📊 New protocol pool update: pool={:?} reserve0={} reserve1={}",
+                                           pool_data.pool_id, pool_data.reserve0.to_f64_lossy(), pool_data.reserve1.to_f64_lossy());
                                     
                                     // Update pool state for arbitrage calculations
                                     let pool_address = format!("{:?}", pool_data.pool_id);
@@ -379,8 +379,8 @@ impl PoolMonitor {
                                             exchange: "bijective_protocol".to_string(),
                                             token0: "UNKNOWN".to_string(), // Will be resolved from schema cache
                                             token1: "UNKNOWN".to_string(),
-                                            reserve0: rust_decimal::Decimal::try_from(pool_data.reserve0).unwrap_or_default(),
-                                            reserve1: rust_decimal::Decimal::try_from(pool_data.reserve1).unwrap_or_default(),
+                                            reserve0: rust_decimal::Decimal::try_from(pool_data.reserve0.to_f64_lossy()).unwrap_or_default(),
+                                            reserve1: rust_decimal::Decimal::try_from(pool_data.reserve1.to_f64_lossy()).unwrap_or_default(),
                                             fee: rust_decimal::Decimal::new(3000, 6), // Default 0.3%
                                             last_updated: current_time,
                                             block_number: 0,
@@ -391,8 +391,8 @@ impl PoolMonitor {
                                     });
                                     
                                     // Update pool state
-                                    pool_entry.value_mut().reserve0 = rust_decimal::Decimal::try_from(pool_data.reserve0).unwrap_or_default();
-                                    pool_entry.value_mut().reserve1 = rust_decimal::Decimal::try_from(pool_data.reserve1).unwrap_or_default();
+                                    pool_entry.value_mut().reserve0 = rust_decimal::Decimal::try_from(pool_data.reserve0.to_f64_lossy()).unwrap_or_default();
+                                    pool_entry.value_mut().reserve1 = rust_decimal::Decimal::try_from(pool_data.reserve1.to_f64_lossy()).unwrap_or_default();
                                     pool_entry.value_mut().last_updated = current_time;
                                     pool_entry.value_mut().v3_tick = Some(pool_data.tick);
                                     pool_entry.value_mut().v3_sqrt_price_x96 = Some(pool_data.sqrt_price_x96 as u128);
@@ -435,6 +435,9 @@ impl PoolMonitor {
                                 ProcessedMessage::Unknown { message_type, .. } => {
                                     debug!("❓ Unknown new protocol message type: {:?}", message_type);
                                 }
+                                ProcessedMessage::Dynamic { message_type, .. } => {
+                                    debug!("🧩 Dynamic template message type: {:?}", message_type);
+                                }
                             }
                             
                             // Skip to next message (find the message size from the header)
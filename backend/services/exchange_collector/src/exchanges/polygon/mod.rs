@@ -1,6 +1,7 @@
 pub mod dex;
 pub mod arbitrage_validator;
 pub mod v3_math;
+pub mod pricing;
 
 use crate::instruments::INSTRUMENTS;
 use crate::unix_socket::UnixSocketWriter;
@@ -34,6 +35,26 @@ use std::hash::{Hash, Hasher};
 
 use dex::{DexPool, PoolFactory, identify_pool_event};
 
+/// How long to wait before reconnecting after a DEX events WebSocket session ends.
+const DEX_WS_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// Maximum silence on the DEX events WebSocket before the connection is treated as
+/// stale and dropped, rather than left to hang indefinitely.
+const DEX_WS_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Per-pool V3 liquidity-at-price tracking, kept current by Mint/Burn/Swap events.
+///
+/// `tick_liquidity` stores SIGNED net-liquidity deltas the way Uniswap V3 itself
+/// does: a position contributes `+liquidity` at its lower tick and `-liquidity`
+/// at its upper tick, so walking ticks in order and summing deltas reproduces
+/// `active_liquidity` exactly without re-scanning every position.
+#[derive(Default)]
+struct PoolLiquidityState {
+    current_tick: i32,
+    current_sqrt_price: f64,
+    active_liquidity: u128,
+    tick_liquidity: HashMap<i32, i128>,
+}
+
 pub struct PolygonCollector {
     socket_writer: Arc<UnixSocketWriter>,
     pool_factory: Arc<PoolFactory>,
@@ -45,6 +66,13 @@ pub struct PolygonCollector {
     message_cache: Arc<RwLock<HashMap<String, Value>>>, // message_id -> original_data
     // Phase 3: New message protocol with schema cache
     schema_cache: Arc<alphapulse_protocol::SchemaTransformCache>, // Required for bijective IDs
+    // V3 tick-indexed liquidity, updated on Mint/Burn/Swap so depth/slippage
+    // estimation always reflects the liquidity in range at the current price
+    pool_liquidity: Arc<RwLock<HashMap<String, PoolLiquidityState>>>,
+    // Per-token decimals/symbol cache, queried from chain once per token
+    token_registry: Arc<crate::token_registry::TokenRegistry>,
+    // Latest spot price/reserves per pool, used to derive USD pricing
+    pool_prices: Arc<RwLock<HashMap<String, pricing::PoolPriceInfo>>>,
 }
 
 impl PolygonCollector {
@@ -87,6 +115,9 @@ impl PolygonCollector {
             message_cache: Arc::new(RwLock::new(HashMap::new())),
             // Use the schema cache with bijective IDs
             schema_cache,
+            pool_liquidity: Arc::new(RwLock::new(HashMap::new())),
+            token_registry: Arc::new(crate::token_registry::TokenRegistry::new(rpc_url.clone())),
+            pool_prices: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     
@@ -133,6 +164,9 @@ impl PolygonCollector {
             message_cache: Arc::new(RwLock::new(HashMap::new())),
             // Use the new schema cache with bijective IDs
             schema_cache,
+            pool_liquidity: Arc::new(RwLock::new(HashMap::new())),
+            token_registry: Arc::new(crate::token_registry::TokenRegistry::new(rpc_url.clone())),
+            pool_prices: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     
@@ -161,325 +195,257 @@ impl PolygonCollector {
         Ok(())
     }
     
+    /// The topic subscriptions sent on every (re)connect, each tagged with the
+    /// `id` Alchemy echoes back in its confirmation frame and a human-readable name.
+    fn dex_event_subscriptions() -> Vec<(u64, &'static str, Value)> {
+        vec![
+            (1, "V3 swaps", json!({
+                "jsonrpc": "2.0", "id": 1, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67"] }] // UniswapV3 Swap
+            })),
+            (2, "V2 swaps", json!({
+                "jsonrpc": "2.0", "id": 2, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0xd78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822"] }] // UniswapV2 Swap
+            })),
+            (3, "Sync events", json!({
+                "jsonrpc": "2.0", "id": 3, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0x1c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1"] }] // V2 Sync
+            })),
+            (4, "V2 Mint events", json!({
+                "jsonrpc": "2.0", "id": 4, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0x4c209b5fc8ad50758f13e2e1088ba56a560dff690a1c6fef26394f4c03821c4f"] }] // V2 Mint
+            })),
+            (5, "V2 Burn events", json!({
+                "jsonrpc": "2.0", "id": 5, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0xdccd412f0b1252819cb1fd330b93224ca42612892bb3f4f789976e6d81936496"] }] // V2 Burn
+            })),
+            (6, "V3 Mint events", json!({
+                "jsonrpc": "2.0", "id": 6, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0x7a53080ba414158be7ec69b987b5fb7d07dee101fe85488f0853ae16239d0bde"] }] // V3 Mint
+            })),
+            (7, "V3 Burn events", json!({
+                "jsonrpc": "2.0", "id": 7, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0x0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c"] }] // V3 Burn
+            })),
+            (8, "V3 Collect events", json!({
+                "jsonrpc": "2.0", "id": 8, "method": "eth_subscribe",
+                "params": ["logs", { "topics": ["0x70935338e69775456a85ddef226c395fb668b63fa0115f5f20610b388e6ca9c0"] }] // V3 Collect
+            })),
+            (9, "blocks", json!({
+                "jsonrpc": "2.0", "id": 9, "method": "eth_subscribe",
+                "params": ["newHeads"]
+            })),
+        ]
+    }
+
+    /// Long-lived DEX event monitor. Each WebSocket session runs until it
+    /// disconnects, closes, or goes stale; this loop then reconnects and
+    /// resubscribes from scratch rather than giving up and letting the
+    /// collector silently stop delivering logs.
     async fn monitor_dex_events(&self) -> Result<()> {
+        loop {
+            match self.run_dex_event_session().await {
+                Ok(()) => warn!("DEX events WebSocket session ended, reconnecting in {:?}", DEX_WS_RECONNECT_DELAY),
+                Err(e) => error!("DEX events WebSocket session failed: {}, reconnecting in {:?}", e, DEX_WS_RECONNECT_DELAY),
+            }
+            tokio::time::sleep(DEX_WS_RECONNECT_DELAY).await;
+        }
+    }
+
+    /// Connect once, (re)send every topic subscription, and demux frames until the
+    /// connection closes or falls silent for longer than `DEX_WS_HEARTBEAT_TIMEOUT`.
+    async fn run_dex_event_session(&self) -> Result<()> {
         info!("📡 Connecting to Polygon WebSocket for real-time DEX events");
-        
+
         let (ws_stream, _) = connect_async(&self.alchemy_ws_url).await?;
         let (mut ws_sender, mut ws_receiver) = ws_stream.split();
-        
-        // Split subscriptions for better reliability
-        // Subscription 1: V3 Swap events only (most common)
-        let v3_swap_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67"] // UniswapV3 Swap
-                }
-            ]
-        });
-        
-        // Subscription 2: V2 Swap events
-        let v2_swap_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 2,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0xd78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822"] // UniswapV2 Swap
-                }
-            ]
-        });
-        
-        // Subscription 3: Sync events (V2 liquidity updates - most frequent)
-        let sync_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 3,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0x1c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1"] // V2 Sync
-                }
-            ]
-        });
-        
-        // Subscription 4: V2 Mint events
-        let v2_mint_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 4,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0x4c209b5fc8ad50758f13e2e1088ba56a560dff690a1c6fef26394f4c03821c4f"] // V2 Mint
-                }
-            ]
-        });
-        
-        // Subscription 5: V2 Burn events  
-        let v2_burn_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 5,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0xdccd412f0b1252819cb1fd330b93224ca42612892bb3f4f789976e6d81936496"] // V2 Burn
-                }
-            ]
-        });
-        
-        // Subscription 6: V3 Mint events
-        let v3_mint_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 6,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0x7a53080ba414158be7ec69b987b5fb7d07dee101babe276914f785c42da22a1"] // V3 Mint
+
+        // Request `id` -> topic name, so the confirmation frame (keyed by the same
+        // `id`) can be matched back to what it subscribes to. Rebuilt fresh for
+        // every session, so a reconnect resubscribes to the identical topic set.
+        let mut pending_subscriptions: HashMap<u64, &'static str> = HashMap::new();
+        for (id, name, request) in Self::dex_event_subscriptions() {
+            ws_sender.send(Message::Text(request.to_string())).await?;
+            pending_subscriptions.insert(id, name);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        info!("✅ Subscribed to all DEX events: V3/V2 swaps, Sync, V2/V3 Mint/Burn, V3 Collect, and blocks");
+
+        let collector = self.clone();
+        let mut swap_count = 0;
+        let mut heartbeat_count = 0;
+
+        loop {
+            let msg = match tokio::time::timeout(DEX_WS_HEARTBEAT_TIMEOUT, ws_receiver.next()).await {
+                Ok(Some(msg)) => msg,
+                Ok(None) => {
+                    warn!("DEX events WebSocket stream ended after {} swaps", swap_count);
+                    return Ok(());
                 }
-            ]
-        });
-        
-        // Subscription 7: V3 Burn events
-        let v3_burn_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 7,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0x0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c"] // V3 Burn
+                Err(_) => {
+                    return Err(anyhow::anyhow!(
+                        "No DEX WebSocket activity for {:?}, treating connection as stale",
+                        DEX_WS_HEARTBEAT_TIMEOUT
+                    ));
                 }
-            ]
-        });
-        
-        // Subscription 8: V3 Collect events
-        let v3_collect_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 8,
-            "method": "eth_subscribe",
-            "params": [
-                "logs",
-                {
-                    "topics": ["0x70935338e69775456a85ddef226c395fb668b63fa0115f5f20610b388e6ca9c0"] // V3 Collect
+            };
+
+            match msg {
+                Ok(Message::Ping(payload)) => {
+                    ws_sender.send(Message::Pong(payload)).await?;
                 }
-            ]
-        });
-        
-        // Subscription 9: New blocks for gas prices
-        let block_subscription = json!({
-            "jsonrpc": "2.0",
-            "id": 9,
-            "method": "eth_subscribe",
-            "params": ["newHeads"]
-        });
-        
-        // Send subscriptions with small delay between each to ensure proper handling
-        ws_sender.send(Message::Text(v3_swap_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v2_swap_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(sync_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v2_mint_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v2_burn_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v3_mint_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v3_burn_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(v3_collect_subscription.to_string())).await?;
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        ws_sender.send(Message::Text(block_subscription.to_string())).await?;
-        
-        info!("✅ Subscribed to all DEX events: V3/V2 swaps, Sync, V2/V3 Mint/Burn, V3 Collect, and blocks");
-        
-        let collector = self.clone();
-        let handle = tokio::spawn(async move {
-            let mut swap_count = 0;
-            let mut heartbeat_count = 0;
-            
-            while let Some(msg) = ws_receiver.next().await {
-                match msg {
-                    Ok(Message::Text(text)) => {
-                        let ws_receive_time = std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs_f64();
-                        
-                        if let Ok(data) = serde_json::from_str::<Value>(&text) {
-                            // Check for subscription confirmation
-                            if data.get("id").is_some() && data.get("result").is_some() {
-                                let id = data.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
-                                let sub_name = match id {
-                                    1 => "V3 swaps",
-                                    2 => "V2 swaps",
-                                    3 => "Sync events",
-                                    4 => "V2 Mint events",
-                                    5 => "V2 Burn events",
-                                    6 => "V3 Mint events",
-                                    7 => "V3 Burn events",
-                                    8 => "V3 Collect events",
-                                    9 => "blocks",
-                                    _ => "unknown"
-                                };
-                                info!("🔗 WebSocket subscription confirmed: {}", sub_name);
-                                continue;
+                Ok(Message::Pong(_)) => {}
+                Ok(Message::Text(text)) => {
+                    let ws_receive_time = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs_f64();
+
+                    if let Ok(data) = serde_json::from_str::<Value>(&text) {
+                        // Check for subscription confirmation: `{"id": ..., "result": "0x..."}`.
+                        // `result` here is the eth_subscribe subscription id, not an event.
+                        if let Some(id) = data.get("id").and_then(|v| v.as_u64()) {
+                            if let Some(result) = data.get("result") {
+                                let sub_name = pending_subscriptions.get(&id).copied().unwrap_or("unknown");
+                                match result.as_str() {
+                                    Some(subscription_id) => {
+                                        info!("🔗 WebSocket subscription confirmed: {} ({})", sub_name, subscription_id);
+                                    }
+                                    None => info!("🔗 WebSocket subscription confirmed: {}", sub_name),
+                                }
                             }
-                            
-                            // Check for actual events
-                            if let Some(params) = data.get("params") {
-                                if let Some(result) = params.get("result") {
-                                    // Check if this is a block header update
-                                    if result.get("gasLimit").is_some() && result.get("number").is_some() {
-                                        // New block header received
-                                        if let Err(e) = collector.handle_new_block(result).await {
-                                            debug!("Failed to handle new block: {}", e);
-                                        }
-                                        continue;
+                            continue;
+                        }
+
+                        // Check for actual events
+                        if let Some(params) = data.get("params") {
+                            if let Some(result) = params.get("result") {
+                                // Check if this is a block header update
+                                if result.get("gasLimit").is_some() && result.get("number").is_some() {
+                                    // New block header received
+                                    if let Err(e) = collector.handle_new_block(result).await {
+                                        debug!("Failed to handle new block: {}", e);
                                     }
-                                    
-                                    // Check event type from topics[0]
-                                    if let Some(topics) = result.get("topics").and_then(|t| t.as_array()) {
-                                        if let Some(event_sig) = topics.get(0).and_then(|s| s.as_str()) {
-                                            match event_sig {
-                                                // Swap events
-                                                "0xd78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822" |
-                                                "0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67" |
-                                                "0x8b3e96f2b889fa771c53c981b40daf005f63f637f1869f707052d15a3dd97140" => {
-                                                    swap_count += 1;
-                                                    
-                                                    // Log WebSocket message timing to detect ANKR batching
-                                                    let time_str = chrono::DateTime::from_timestamp(ws_receive_time as i64, 
-                                                        ((ws_receive_time.fract() * 1_000_000_000.0) as u32))
-                                                        .map(|dt| dt.format("%H:%M:%S%.6f").to_string())
-                                                        .unwrap_or_else(|| format!("{:.6}", ws_receive_time));
-                                                    info!("🔍 Public WS delivered swap #{} at {}", swap_count, time_str);
-                                                    
-                                                    // PHASE 2: Generate unique message ID for deep equality tracking
-                                                    let message_id = Uuid::new_v4().to_string();
-                                                    
-                                                    // Cache original message for validation
-                                                    {
-                                                        let mut cache = collector.message_cache.write();
-                                                        cache.insert(message_id.clone(), result.clone());
-                                                        
-                                                        // Clean up old entries to prevent memory leak (keep last 1000)
-                                                        if cache.len() > 1000 {
-                                                            // Remove oldest entries (simplified cleanup)
-                                                            let keys_to_remove: Vec<_> = cache.keys().take(100).cloned().collect();
-                                                            for key in keys_to_remove {
-                                                                cache.remove(&key);
-                                                            }
+                                    continue;
+                                }
+
+                                // Check event type from topics[0]
+                                if let Some(topics) = result.get("topics").and_then(|t| t.as_array()) {
+                                    if let Some(event_sig) = topics.get(0).and_then(|s| s.as_str()) {
+                                        match event_sig {
+                                            // Swap events
+                                            "0xd78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822" |
+                                            "0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67" |
+                                            "0x8b3e96f2b889fa771c53c981b40daf005f63f637f1869f707052d15a3dd97140" => {
+                                                swap_count += 1;
+
+                                                // Log WebSocket message timing to detect ANKR batching
+                                                let time_str = chrono::DateTime::from_timestamp(ws_receive_time as i64,
+                                                    ((ws_receive_time.fract() * 1_000_000_000.0) as u32))
+                                                    .map(|dt| dt.format("%H:%M:%S%.6f").to_string())
+                                                    .unwrap_or_else(|| format!("{:.6}", ws_receive_time));
+                                                info!("🔍 Public WS delivered swap #{} at {}", swap_count, time_str);
+
+                                                // PHASE 2: Generate unique message ID for deep equality tracking
+                                                let message_id = Uuid::new_v4().to_string();
+
+                                                // Cache original message for validation
+                                                {
+                                                    let mut cache = collector.message_cache.write();
+                                                    cache.insert(message_id.clone(), result.clone());
+
+                                                    // Clean up old entries to prevent memory leak (keep last 1000)
+                                                    if cache.len() > 1000 {
+                                                        // Remove oldest entries (simplified cleanup)
+                                                        let keys_to_remove: Vec<_> = cache.keys().take(100).cloned().collect();
+                                                        for key in keys_to_remove {
+                                                            cache.remove(&key);
                                                         }
                                                     }
-                                                    
-                                                    debug!("🆔 Generated message ID {} for swap #{}", message_id, swap_count);
-                                                    
-                                                    if swap_count % 10 == 0 {
-                                                        debug!("📊 Processed {} swaps", swap_count);
-                                                    }
-                                                    // Spawn async task to process swap without blocking
-                                                    let collector_clone = collector.clone();
-                                                    let result_clone = result.clone();
-                                                    let swap_num = swap_count;
-                                                    let msg_id = message_id.clone();
-                                                    tokio::spawn(async move {
-                                                        if let Err(e) = collector_clone.process_swap_event_with_id(&result_clone, &msg_id).await {
-                                                            if let Some(addr) = result_clone.get("address").and_then(|v| v.as_str()) {
-                                                                debug!("Failed to process swap #{} for pool {}: {}", swap_num, addr, e);
-                                                            }
-                                                        }
-                                                    });
-                                                }
-                                                // Pool events (V2/V3 Mint/Burn/Collect/Sync)
-                                                "0x4c209b5fc8ad50758f13e2e1088ba56a560dff690a1c6fef26394f4c03821c4f" | // V2 Mint
-                                                "0xdccd412f0b1252819cb1fd330b93224ca42612892bb3f4f789976e6d8136129a" | // V2 Burn
-                                                "0x1c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1" | // V2 Sync
-                                                "0x7a53080ba414158be7ec69b987b5fb7d07dee101babe276914f785c42da22a01b" | // V3 Mint
-                                                "0x0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c" | // V3 Burn
-                                                "0x40d0efd1a53d60ecbf40971b9daf7dc90178c3aadc7aab1765632738fa8b8f01" => { // V3 Collect
-                                                    // Use unified pool event handler
-                                                    let collector_clone = collector.clone();
-                                                    let result_clone = result.clone();
-                                                    tokio::spawn(async move {
-                                                        if let Err(e) = collector_clone.handle_pool_event(&result_clone).await {
-                                                            debug!("Failed to process pool event: {}", e);
-                                                        }
-                                                    });
                                                 }
-                                                _ => {
-                                                    // Unknown event
-                                                    debug!("Unknown event signature: {}", event_sig);
+
+                                                debug!("🆔 Generated message ID {} for swap #{}", message_id, swap_count);
+
+                                                if swap_count % 10 == 0 {
+                                                    debug!("📊 Processed {} swaps", swap_count);
                                                 }
+                                                // Spawn async task to process swap without blocking
+                                                let collector_clone = collector.clone();
+                                                let result_clone = result.clone();
+                                                let swap_num = swap_count;
+                                                let msg_id = message_id.clone();
+                                                tokio::spawn(async move {
+                                                    if let Err(e) = collector_clone.process_swap_event_with_id(&result_clone, &msg_id).await {
+                                                        if let Some(addr) = result_clone.get("address").and_then(|v| v.as_str()) {
+                                                            debug!("Failed to process swap #{} for pool {}: {}", swap_num, addr, e);
+                                                        }
+                                                    }
+                                                });
                                             }
-                                            continue;
-                                        }
-                                    }
-                                    swap_count += 1;
-                                    if swap_count % 10 == 0 {
-                                        debug!("📊 Processed {} swaps", swap_count);
-                                    }
-                                    // Spawn async task to process swap without blocking
-                                    let collector_clone = collector.clone();
-                                    let result_clone = result.clone();
-                                    let swap_num = swap_count;
-                                    tokio::spawn(async move {
-                                        if let Err(e) = collector_clone.process_swap_event(&result_clone).await {
-                                            // Log more details about the failure
-                                            if let Some(addr) = result_clone.get("address").and_then(|v| v.as_str()) {
-                                                debug!("Failed to process swap #{} for pool {}: {}", swap_num, addr, e);
-                                            } else {
-                                                debug!("Failed to process swap #{}: {}", swap_num, e);
+                                            // Pool events (V2/V3 Mint/Burn/Collect/Sync)
+                                            "0x4c209b5fc8ad50758f13e2e1088ba56a560dff690a1c6fef26394f4c03821c4f" | // V2 Mint
+                                            "0xdccd412f0b1252819cb1fd330b93224ca42612892bb3f4f789976e6d81936496" | // V2 Burn
+                                            "0x1c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1" | // V2 Sync
+                                            "0x7a53080ba414158be7ec69b987b5fb7d07dee101fe85488f0853ae16239d0bde" | // V3 Mint
+                                            "0x0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c" | // V3 Burn
+                                            "0x70935338e69775456a85ddef226c395fb668b63fa0115f5f20610b388e6ca9c0" => { // V3 Collect
+                                                // Use unified pool event handler
+                                                let collector_clone = collector.clone();
+                                                let result_clone = result.clone();
+                                                tokio::spawn(async move {
+                                                    if let Err(e) = collector_clone.handle_pool_event(&result_clone).await {
+                                                        debug!("Failed to process pool event: {}", e);
+                                                    }
+                                                });
+                                            }
+                                            _ => {
+                                                // Unknown event
+                                                debug!("Unknown event signature: {}", event_sig);
                                             }
                                         }
-                                    });
+                                        continue;
+                                    }
                                 }
-                            } else {
-                                // Heartbeat or other message
-                                heartbeat_count += 1;
-                                if heartbeat_count % 100 == 0 {
-                                    debug!("💓 Received {} heartbeats", heartbeat_count);
+                                swap_count += 1;
+                                if swap_count % 10 == 0 {
+                                    debug!("📊 Processed {} swaps", swap_count);
                                 }
+                                // Spawn async task to process swap without blocking
+                                let collector_clone = collector.clone();
+                                let result_clone = result.clone();
+                                let swap_num = swap_count;
+                                tokio::spawn(async move {
+                                    if let Err(e) = collector_clone.process_swap_event(&result_clone).await {
+                                        // Log more details about the failure
+                                        if let Some(addr) = result_clone.get("address").and_then(|v| v.as_str()) {
+                                            debug!("Failed to process swap #{} for pool {}: {}", swap_num, addr, e);
+                                        } else {
+                                            debug!("Failed to process swap #{}: {}", swap_num, e);
+                                        }
+                                    }
+                                });
                             }
                         } else {
-                            debug!("Failed to parse WebSocket message: {}", text);
+                            // Heartbeat or other message
+                            heartbeat_count += 1;
+                            if heartbeat_count % 100 == 0 {
+                                debug!("💓 Received {} heartbeats", heartbeat_count);
+                            }
                         }
+                    } else {
+                        debug!("Failed to parse WebSocket message: {}", text);
                     }
-                    Ok(Message::Close(_)) => {
-                        warn!("WebSocket closed, reconnecting immediately for blazing fast recovery!");
-                        // No delay - immediate reconnection for real-time arbitrage!
-                        break;
-                    }
-                    Err(e) => {
-                        error!("WebSocket error: {}", e);
-                    }
-                    _ => {}
+                }
+                Ok(Message::Close(_)) => {
+                    warn!("DEX events WebSocket closed after {} swaps, reconnecting", swap_count);
+                    return Ok(());
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    return Err(anyhow::anyhow!("DEX events WebSocket error: {}", e));
                 }
             }
-            warn!("DEX monitoring loop exited after {} swaps", swap_count);
-        });
-        
-        // CRITICAL FIX: Wait for the WebSocket task to complete instead of returning immediately
-        // This prevents the infinite reconnection loop that was creating 100+ connections
-        handle.await.map_err(|e| anyhow::anyhow!("WebSocket task failed: {}", e))?;
-        
-        Ok(())
+        }
     }
     
     /// PHASE 2: Process swap event with message ID for deep equality validation
@@ -634,8 +600,8 @@ impl PolygonCollector {
                        raw_amount0_in, raw_amount1_in, raw_amount0_out, raw_amount1_out);
                 // Decimals handled by downstream services via bijective IDs
                 
-                // Get actual V3 liquidity
-                if let Ok(liq) = self.get_v3_active_liquidity(pool_address, v3.tick).await {
+                // Walk ticks crossed by this swap and get the resulting active liquidity
+                if let Ok(liq) = self.apply_v3_swap_tick_crossing(pool_address, v3.tick, v3.sqrt_price_x96).await {
                     v3.liquidity = liq;
                 }
             }
@@ -644,12 +610,17 @@ impl PolygonCollector {
             }
         }
         
+        // Derive a USD price for this pool from its post-swap reserves/sqrt-price
+        if let Err(e) = self.update_derived_price(pool_address, &swap_event, &token0_addr, &token1_addr).await {
+            debug!("Failed to derive USD price for pool {}: {}", pool_address, e);
+        }
+
         // Collector just forwards raw data - no validation or price calculation
         // Downstream services handle any analysis using bijective IDs
         let core = swap_event.core();
         debug!("Forwarding swap event for pool {}: amounts in0={}, out0={}, in1={}, out1={}",
                pool_address, core.amount0_in, core.amount0_out, core.amount1_in, core.amount1_out);
-        
+
         // Send swap event via binary protocol
         self.send_swap_event(&swap_event, &pool).await?;
         
@@ -843,11 +814,112 @@ impl PolygonCollector {
         Ok(())
     }
     
-    async fn get_v3_active_liquidity(&self, _pool_address: &str, _current_tick: i32) -> Result<u128> {
-        // Collector doesn't track liquidity
-        Ok(0)
+    /// Apply a V3 swap's tick-crossing to the pool's tracked liquidity state.
+    ///
+    /// A swap that moves price across one or more initialized ticks changes the
+    /// in-range liquidity even though no Mint/Burn fired. Walk every initialized
+    /// tick between the pool's last-known `current_tick` and `tick_after`,
+    /// applying each crossed tick's signed net-liquidity delta (add when moving
+    /// up through a tick, subtract when moving down), then persist the new
+    /// tick/sqrt-price so the next Mint/Burn/Swap continues from here.
+    async fn apply_v3_swap_tick_crossing(
+        &self,
+        pool_address: &str,
+        tick_after: i32,
+        sqrt_price_x96_after: u128,
+    ) -> Result<u128> {
+        let mut states = self.pool_liquidity.write();
+        let state = states.entry(pool_address.to_string()).or_insert_with(PoolLiquidityState::default);
+
+        let from_tick = state.current_tick;
+        if tick_after > from_tick {
+            // Price moved up: crossing a tick upward adds its net-liquidity delta
+            let mut crossed: Vec<i32> = state.tick_liquidity.keys()
+                .filter(|&&tick| tick > from_tick && tick <= tick_after)
+                .copied()
+                .collect();
+            crossed.sort_unstable();
+            for tick in crossed {
+                let delta = state.tick_liquidity[&tick];
+                state.active_liquidity = (state.active_liquidity as i128 + delta).max(0) as u128;
+            }
+        } else if tick_after < from_tick {
+            // Price moved down: crossing a tick downward subtracts its net-liquidity delta
+            let mut crossed: Vec<i32> = state.tick_liquidity.keys()
+                .filter(|&&tick| tick <= from_tick && tick > tick_after)
+                .copied()
+                .collect();
+            crossed.sort_unstable_by(|a, b| b.cmp(a));
+            for tick in crossed {
+                let delta = state.tick_liquidity[&tick];
+                state.active_liquidity = (state.active_liquidity as i128 - delta).max(0) as u128;
+            }
+        }
+
+        state.current_tick = tick_after;
+        state.current_sqrt_price = Self::sqrt_price_x96_to_f64(sqrt_price_x96_after);
+
+        Ok(state.active_liquidity)
     }
-    
+
+    /// Convert a Q64.96 sqrt price into an f64 for debug/telemetry use.
+    fn sqrt_price_x96_to_f64(sqrt_price_x96: u128) -> f64 {
+        (sqrt_price_x96 as f64) / 2_f64.powi(96)
+    }
+
+    /// Recompute this pool's spot price from its post-swap reserves/sqrt-price,
+    /// then resolve it to USD via the native-token/stablecoin anchor pools
+    /// tracked across the whole collector.
+    async fn update_derived_price(
+        &self,
+        pool_address: &str,
+        swap_event: &SwapEvent,
+        token0_addr: &str,
+        token1_addr: &str,
+    ) -> Result<()> {
+        let token0_info = self.token_registry.get_token_info(token0_addr).await?;
+        let token1_info = self.token_registry.get_token_info(token1_addr).await?;
+
+        let (price_token0_in_token1, reserve0, reserve1) = match swap_event {
+            SwapEvent::UniswapV2(v2) => {
+                let reserve0 = v2.reserves_after.0 as f64 / 10_f64.powi(token0_info.decimals as i32);
+                let reserve1 = v2.reserves_after.1 as f64 / 10_f64.powi(token1_info.decimals as i32);
+                let price = pricing::PriceOracle::spot_price_v2(
+                    v2.reserves_after.0 as f64, v2.reserves_after.1 as f64,
+                    token0_info.decimals, token1_info.decimals,
+                );
+                (price, reserve0, reserve1)
+            }
+            SwapEvent::UniswapV3(v3) => {
+                let price = pricing::PriceOracle::spot_price_v3(
+                    v3.sqrt_price_x96, token0_info.decimals, token1_info.decimals,
+                );
+                // V3 liquidity isn't reserves; fall back to the tracked active liquidity
+                // as a rough depth proxy since exact reserves require tick-range math.
+                let depth = v3.liquidity as f64 / 10_f64.powi(token0_info.decimals.max(token1_info.decimals) as i32);
+                (price, depth, depth)
+            }
+            SwapEvent::Curve(_) => return Ok(()),
+        };
+
+        let mut prices = self.pool_prices.write();
+        prices.insert(pool_address.to_string(), pricing::PoolPriceInfo {
+            token0_symbol: token0_info.symbol.clone(),
+            token1_symbol: token1_info.symbol.clone(),
+            reserve0,
+            reserve1,
+            price_token0_in_token1,
+        });
+
+        let native_usd = pricing::PriceOracle::native_usd_price(&prices);
+        let derived_price_usd = pricing::PriceOracle::derive_usd_price(&token0_info.symbol, native_usd, &prices);
+
+        debug!("💵 Derived price for {} ({}/{}): token0_in_token1={:.8} derived_price_usd={:?}",
+               pool_address, token0_info.symbol, token1_info.symbol, price_token0_in_token1, derived_price_usd);
+
+        Ok(())
+    }
+
     async fn handle_new_block(&self, _block: &Value) -> Result<()> {
         // Collector just forwards events
         Ok(())
@@ -1395,6 +1467,18 @@ async fn validate_token_authenticity(address: &str, expected_symbol: &str, rpc_u
     true // Default to valid for unknown tokens
 }
 
+/// Decodes a 32-byte (64-hex-char) big-endian ABI word encoding a Solidity
+/// `int24` tick into a sign-extended `i32`. The tick occupies the word's low
+/// 24 bits; `i32::from_str_radix` on the raw hex would read it as unsigned
+/// and overflow for any negative tick (the common case for ticks below a
+/// pool's current price), so we parse the low 6 hex digits as a `u32` and
+/// sign-extend from bit 23 by shifting it to the top of a 32-bit word and
+/// back down arithmetically.
+fn decode_int24_tick(hex_word: &str) -> Result<i32> {
+    let raw = u32::from_str_radix(&hex_word[hex_word.len() - 6..], 16)?;
+    Ok(((raw << 8) as i32) >> 8)
+}
+
 impl PolygonCollector {
     
     /// Handle new block header from WebSocket subscription
@@ -1650,11 +1734,86 @@ impl PolygonCollector {
         Ok(())
     }
 
-    /// Unified pool event handler using new pool event system
+    /// Record a V3 Mint's liquidity as signed net-liquidity deltas: `+liquidity`
+    /// at the position's lower tick, `-liquidity` at its upper tick. If the
+    /// position straddles the pool's current tick, the minted liquidity is
+    /// already in range, so fold it into `active_liquidity` immediately.
+    fn record_v3_mint(&self, pool_address: &str, tick_lower: i32, tick_upper: i32, liquidity: u128) {
+        let mut states = self.pool_liquidity.write();
+        let state = states.entry(pool_address.to_string()).or_insert_with(PoolLiquidityState::default);
+
+        *state.tick_liquidity.entry(tick_lower).or_insert(0) += liquidity as i128;
+        *state.tick_liquidity.entry(tick_upper).or_insert(0) -= liquidity as i128;
+
+        if tick_lower <= state.current_tick && state.current_tick < tick_upper {
+            state.active_liquidity += liquidity;
+        }
+
+        debug!("💧 V3 Mint: {} added {} liquidity to ticks [{}, {}]",
+               pool_address, liquidity, tick_lower, tick_upper);
+    }
+
+    /// Reverse a V3 Burn's liquidity: undo the signed deltas applied at Mint
+    /// time and, if the burned range covers the current tick, remove it from
+    /// `active_liquidity` (clamped at zero to absorb any prior drift).
+    fn record_v3_burn(&self, pool_address: &str, tick_lower: i32, tick_upper: i32, liquidity: u128) {
+        let mut states = self.pool_liquidity.write();
+        let state = states.entry(pool_address.to_string()).or_insert_with(PoolLiquidityState::default);
+
+        *state.tick_liquidity.entry(tick_lower).or_insert(0) -= liquidity as i128;
+        *state.tick_liquidity.entry(tick_upper).or_insert(0) += liquidity as i128;
+
+        if tick_lower <= state.current_tick && state.current_tick < tick_upper {
+            state.active_liquidity = (state.active_liquidity as i128 - liquidity as i128).max(0) as u128;
+        }
+
+        debug!("🔥 V3 Burn: {} removed {} liquidity from ticks [{}, {}]",
+               pool_address, liquidity, tick_lower, tick_upper);
+    }
+
+    /// Unified pool event handler using new pool event system.
+    /// V3 Mint/Burn update the tick-indexed liquidity map that swap
+    /// tick-crossing (see `apply_v3_swap_tick_crossing`) walks; other pool
+    /// events are just forwarded.
     async fn handle_pool_event(&self, log: &Value) -> Result<()> {
-        // Pool events are processed but collector doesn't track state
-        // Just forward the raw event data
-        debug!("Pool event received, forwarding raw data");
+        let pool_address = match log["address"].as_str() {
+            Some(addr) => addr,
+            None => {
+                debug!("Pool event missing address, forwarding raw data");
+                return Ok(());
+            }
+        };
+        let event_sig = log["topics"].as_array()
+            .and_then(|topics| topics.get(0))
+            .and_then(|s| s.as_str());
+        let data = log["data"].as_str().unwrap_or("0x");
+        let hex_data = data.strip_prefix("0x").unwrap_or(data);
+        let topic = |i: usize| log["topics"][i].as_str()
+            .ok_or_else(|| anyhow::anyhow!("Pool event missing topic {}", i));
+
+        match event_sig {
+            Some("0x7a53080ba414158be7ec69b987b5fb7d07dee101fe85488f0853ae16239d0bde") if hex_data.len() >= 256 => {
+                // V3 Mint: owner, tickLower, tickUpper are indexed (topics 1-3);
+                // data is sender, amount, amount0, amount1
+                let tick_lower = decode_int24_tick(topic(2)?)?;
+                let tick_upper = decode_int24_tick(topic(3)?)?;
+                let liquidity = u128::from_str_radix(&hex_data[64..128], 16)?;
+                self.record_v3_mint(pool_address, tick_lower, tick_upper, liquidity);
+            }
+            Some(dex::UNISWAP_V3_BURN_SIGNATURE) if hex_data.len() >= 192 => {
+                // V3 Burn: owner, tickLower, tickUpper are indexed (topics 1-3);
+                // data is amount, amount0, amount1
+                let tick_lower = decode_int24_tick(topic(2)?)?;
+                let tick_upper = decode_int24_tick(topic(3)?)?;
+                let liquidity = u128::from_str_radix(&hex_data[0..64], 16)?;
+                self.record_v3_burn(pool_address, tick_lower, tick_upper, liquidity);
+            }
+            _ => {
+                // V2 Mint/Burn/Sync and V3 Collect don't change tick-indexed liquidity
+                debug!("Pool event received, forwarding raw data");
+            }
+        }
+
         Ok(())
     }
 }
@@ -2038,6 +2197,9 @@ impl Clone for PolygonCollector {
             sequence: Arc::clone(&self.sequence),
             message_cache: Arc::clone(&self.message_cache),
             schema_cache: Arc::clone(&self.schema_cache),
+            pool_liquidity: Arc::clone(&self.pool_liquidity),
+            token_registry: Arc::clone(&self.token_registry),
+            pool_prices: Arc::clone(&self.pool_prices),
         }
     }
 }
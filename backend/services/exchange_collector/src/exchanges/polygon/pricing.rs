@@ -0,0 +1,157 @@
+/// USD price derivation for tracked pools.
+///
+/// Raw reserves (V2 `reserves_after`) and sqrt-prices (V3 `sqrt_price_x96`) only
+/// give the price of one pool token in terms of the other; every downstream
+/// consumer was left to re-derive a USD figure by hand. This module turns a
+/// pool's spot price into a `derived_price_usd` by resolving it through a
+/// small set of whitelisted native-token/stablecoin anchor pools: the
+/// native token's USD price is the liquidity-weighted average across those
+/// anchors, and any other token is valued via whichever tracked pool pairs it
+/// most deeply with the native token or a stablecoin.
+use std::collections::HashMap;
+
+/// Stablecoins treated as pegged to $1 when found on one side of a pool.
+pub const STABLE_SYMBOLS: &[&str] = &["USDC", "USDT", "DAI", "BUSD"];
+
+/// Wrapped-native tokens whose USD price anchors every other token's valuation.
+pub const NATIVE_SYMBOLS: &[&str] = &["WMATIC", "WPOL", "WETH"];
+
+/// Spot price + reserves for one tracked pool, keyed by pool address.
+/// Refreshed from the live Sync (V2) / Swap (V3) event stream.
+#[derive(Debug, Clone, Default)]
+pub struct PoolPriceInfo {
+    pub token0_symbol: String,
+    pub token1_symbol: String,
+    pub reserve0: f64,
+    pub reserve1: f64,
+    /// Price of token0 denominated in token1 (decimal-adjusted)
+    pub price_token0_in_token1: f64,
+}
+
+impl PoolPriceInfo {
+    /// Price of `symbol` denominated in the other side of this pool, if it's one of the two.
+    fn price_of(&self, symbol: &str) -> Option<f64> {
+        if self.token0_symbol == symbol {
+            Some(self.price_token0_in_token1)
+        } else if self.token1_symbol == symbol && self.price_token0_in_token1 > 0.0 {
+            Some(1.0 / self.price_token0_in_token1)
+        } else {
+            None
+        }
+    }
+
+    /// Reserve depth on the side opposite `symbol`, used to weight anchor averaging.
+    fn counter_depth(&self, symbol: &str) -> Option<f64> {
+        if self.token0_symbol == symbol {
+            Some(self.reserve1)
+        } else if self.token1_symbol == symbol {
+            Some(self.reserve0)
+        } else {
+            None
+        }
+    }
+
+    /// Symbol on the opposite side of this pool from `symbol`.
+    fn counterparty(&self, symbol: &str) -> Option<&str> {
+        if self.token0_symbol == symbol {
+            Some(&self.token1_symbol)
+        } else if self.token1_symbol == symbol {
+            Some(&self.token0_symbol)
+        } else {
+            None
+        }
+    }
+}
+
+/// Stateless USD pricing helpers; all state lives in the caller's
+/// `HashMap<String, PoolPriceInfo>` of tracked pools.
+pub struct PriceOracle;
+
+impl PriceOracle {
+    /// V2 spot price of token0 in terms of token1, decimal-adjusted.
+    pub fn spot_price_v2(reserve0_raw: f64, reserve1_raw: f64, decimals0: u8, decimals1: u8) -> f64 {
+        if reserve0_raw == 0.0 {
+            return 0.0;
+        }
+        let decimal_adjustment = 10_f64.powi(decimals0 as i32 - decimals1 as i32);
+        (reserve1_raw / reserve0_raw) * decimal_adjustment
+    }
+
+    /// V3 spot price of token0 in terms of token1 from sqrtPriceX96, decimal-adjusted.
+    pub fn spot_price_v3(sqrt_price_x96: u128, decimals0: u8, decimals1: u8) -> f64 {
+        let sqrt_price = sqrt_price_x96 as f64 / 2_f64.powi(96);
+        let decimal_adjustment = 10_f64.powi(decimals0 as i32 - decimals1 as i32);
+        sqrt_price * sqrt_price * decimal_adjustment
+    }
+
+    /// Liquidity-weighted native-token USD price across whitelisted native/stable anchor pools.
+    pub fn native_usd_price(pools: &HashMap<String, PoolPriceInfo>) -> Option<f64> {
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+
+        for info in pools.values() {
+            let native = match NATIVE_SYMBOLS.iter().find(|s| info.token0_symbol == **s || info.token1_symbol == **s) {
+                Some(native) => *native,
+                None => continue,
+            };
+            match info.counterparty(native) {
+                Some(c) if STABLE_SYMBOLS.contains(&c) => {}
+                _ => continue,
+            }
+
+            let (Some(price), Some(depth)) = (info.price_of(native), info.counter_depth(native)) else { continue };
+            if depth <= 0.0 {
+                continue;
+            }
+
+            weighted_sum += price * depth;
+            weight_total += depth;
+        }
+
+        if weight_total > 0.0 {
+            Some(weighted_sum / weight_total)
+        } else {
+            None
+        }
+    }
+
+    /// Value `symbol` in USD via whichever tracked pool pairs it most deeply
+    /// with the native token or a stablecoin.
+    pub fn derive_usd_price(
+        symbol: &str,
+        native_usd: Option<f64>,
+        pools: &HashMap<String, PoolPriceInfo>,
+    ) -> Option<f64> {
+        if STABLE_SYMBOLS.contains(&symbol) {
+            return Some(1.0);
+        }
+
+        let mut best: Option<(f64, f64)> = None; // (price_usd, depth)
+        for info in pools.values() {
+            let Some(price_in_counter) = info.price_of(symbol) else { continue };
+            let Some(depth) = info.counter_depth(symbol) else { continue };
+            let Some(counterparty) = info.counterparty(symbol) else { continue };
+
+            let price_usd = if STABLE_SYMBOLS.contains(&counterparty) {
+                price_in_counter
+            } else if NATIVE_SYMBOLS.contains(&counterparty) {
+                match native_usd {
+                    Some(native_usd) => price_in_counter * native_usd,
+                    None => continue,
+                }
+            } else {
+                continue;
+            };
+
+            let is_better = match best {
+                Some((_, best_depth)) => depth > best_depth,
+                None => true,
+            };
+            if is_better {
+                best = Some((price_usd, depth));
+            }
+        }
+
+        best.map(|(price, _)| price)
+    }
+}
@@ -5,4 +5,5 @@ pub mod dex_registry;
 pub mod pool_discovery;
 pub mod graph_client;
 pub mod validation;
-pub mod connection_manager;
\ No newline at end of file
+pub mod connection_manager;
+pub mod token_registry;
\ No newline at end of file
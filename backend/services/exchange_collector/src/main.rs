@@ -6,6 +6,7 @@ mod pool_discovery;
 mod dex_registry;
 mod graph_client;
 mod connection_manager;
+mod token_registry;
 
 use alphapulse_protocol::*;
 use alphapulse_protocol::{
@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use ethers::types::U256;
 use tracing::{info, error};
 
 #[derive(Debug, Clone)]
@@ -21,6 +22,98 @@ pub struct SwapEvent {
     pub block_number: u64,
 }
 
+impl SwapEvent {
+    /// Build from raw on-chain log fields, which may arrive hex-encoded (`0x...`, as
+    /// `eth_getLogs` emits them) or as plain decimal strings. Amounts are parsed
+    /// straight into `u128` and never pass through `f64`, so a stray `"0x"` prefix
+    /// can't silently truncate or zero out a token amount.
+    pub fn from_raw_fields(
+        pool_address: String,
+        token0: TokenInfo,
+        token1: TokenInfo,
+        amount0_in_raw: &str,
+        amount1_in_raw: &str,
+        amount0_out_raw: &str,
+        amount1_out_raw: &str,
+        tx_hash: String,
+        block_number: u64,
+    ) -> Result<Self> {
+        Ok(Self {
+            pool_address,
+            token0,
+            token1,
+            amount0_in_raw: parse_onchain_amount(amount0_in_raw)?,
+            amount1_in_raw: parse_onchain_amount(amount1_in_raw)?,
+            amount0_out_raw: parse_onchain_amount(amount0_out_raw)?,
+            amount1_out_raw: parse_onchain_amount(amount1_out_raw)?,
+            tx_hash,
+            block_number,
+        })
+    }
+}
+
+/// Parse a single on-chain amount field, accepting either a `0x`-prefixed hex string
+/// or a plain decimal string.
+fn parse_onchain_amount(s: &str) -> Result<u128> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u128::from_str_radix(hex, 16).with_context(|| format!("invalid hex amount: {}", s))
+    } else {
+        s.parse::<u128>().with_context(|| format!("invalid decimal amount: {}", s))
+    }
+}
+
+/// Fixed-point amount, scaled to 1e18 regardless of the token's native `decimals`, so
+/// amounts of tokens with different decimals (POL's 18 vs USDC's 6) can be compared
+/// and divided without ever round-tripping through `f64` and losing precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UQ(U256);
+
+impl UQ {
+    /// Internal fixed-point scale.
+    pub const SCALE: u128 = 1_000_000_000_000_000_000;
+
+    /// Normalize a raw on-chain amount (in the token's native `decimals`) to the
+    /// common 1e18 scale.
+    pub fn from_raw(raw_amount: u128, decimals: u8) -> Result<Self> {
+        let diff = 18i32 - decimals as i32;
+        let value = U256::from(raw_amount);
+        let scaled = if diff > 0 {
+            value
+                .checked_mul(U256::from(10u128.pow(diff as u32)))
+                .context("Overflow normalizing amount to fixed point")?
+        } else if diff < 0 {
+            value / U256::from(10u128.pow((-diff) as u32))
+        } else {
+            value
+        };
+        Ok(Self(scaled))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// `self / other`, computed in fixed-point before the single, display-only
+    /// conversion to `f64`.
+    pub fn ratio(&self, other: &UQ) -> Result<f64> {
+        if other.is_zero() {
+            return Err(anyhow::anyhow!("Division by zero computing UQ ratio"));
+        }
+        let scaled = self
+            .0
+            .checked_mul(U256::from(Self::SCALE))
+            .context("Overflow computing UQ ratio")?
+            / other.0;
+        Ok(Self(scaled).as_f64())
+    }
+
+    /// Convert to `f64` for display/logging only — never feed this back into further
+    /// fixed-point arithmetic.
+    pub fn as_f64(&self) -> f64 {
+        self.0.as_u128() as f64 / Self::SCALE as f64
+    }
+}
+
 #[derive(Debug)]
 pub struct PriceCalculation {
     pub raw_price: f64,
@@ -49,35 +142,35 @@ impl POLPriceCalculator {
         println!("  Token1: {} ({} decimals)", swap.token1.symbol, swap.token1.decimals);
         println!("  TX: {}", swap.tx_hash);
 
-        // Step 1: Convert raw amounts to decimal-adjusted amounts
-        let amount0_in = self.convert_raw_amount(swap.amount0_in_raw, swap.token0.decimals);
-        let amount1_in = self.convert_raw_amount(swap.amount1_in_raw, swap.token1.decimals);
-        let amount0_out = self.convert_raw_amount(swap.amount0_out_raw, swap.token0.decimals);
-        let amount1_out = self.convert_raw_amount(swap.amount1_out_raw, swap.token1.decimals);
+        // Step 1: Normalize raw amounts to fixed-point, never through f64
+        let amount0_in = UQ::from_raw(swap.amount0_in_raw, swap.token0.decimals)?;
+        let amount1_in = UQ::from_raw(swap.amount1_in_raw, swap.token1.decimals)?;
+        let amount0_out = UQ::from_raw(swap.amount0_out_raw, swap.token0.decimals)?;
+        let amount1_out = UQ::from_raw(swap.amount1_out_raw, swap.token1.decimals)?;
 
-        steps.push(format!("Raw amounts: 0_in={}, 1_in={}, 0_out={}, 1_out={}", 
+        steps.push(format!("Raw amounts: 0_in={}, 1_in={}, 0_out={}, 1_out={}",
             swap.amount0_in_raw, swap.amount1_in_raw, swap.amount0_out_raw, swap.amount1_out_raw));
-        steps.push(format!("Decimal-adjusted: 0_in={:.6}, 1_in={:.6}, 0_out={:.6}, 1_out={:.6}", 
-            amount0_in, amount1_in, amount0_out, amount1_out));
+        steps.push(format!("Decimal-adjusted: 0_in={:.6}, 1_in={:.6}, 0_out={:.6}, 1_out={:.6}",
+            amount0_in.as_f64(), amount1_in.as_f64(), amount0_out.as_f64(), amount1_out.as_f64()));
 
         println!("  📊 AMOUNTS:");
-        println!("    Raw: amount0_in={}, amount1_in={}, amount0_out={}, amount1_out={}", 
+        println!("    Raw: amount0_in={}, amount1_in={}, amount0_out={}, amount1_out={}",
             swap.amount0_in_raw, swap.amount1_in_raw, swap.amount0_out_raw, swap.amount1_out_raw);
-        println!("    Adjusted: amount0_in={:.6}, amount1_in={:.6}, amount0_out={:.6}, amount1_out={:.6}", 
-            amount0_in, amount1_in, amount0_out, amount1_out);
+        println!("    Adjusted: amount0_in={:.6}, amount1_in={:.6}, amount0_out={:.6}, amount1_out={:.6}",
+            amount0_in.as_f64(), amount1_in.as_f64(), amount0_out.as_f64(), amount1_out.as_f64());
 
         // Step 2: Determine swap direction and calculate raw price
-        let (raw_price, swap_direction) = if amount0_in > 0.0 && amount1_out > 0.0 {
+        let (raw_price, swap_direction) = if !amount0_in.is_zero() && !amount1_out.is_zero() {
             // Selling token0 for token1: price = token1_out / token0_in
-            let price = amount1_out / amount0_in;
-            steps.push(format!("Swap direction: Selling {} for {} (price = {}/{} = {:.6})", 
-                swap.token0.symbol, swap.token1.symbol, amount1_out, amount0_in, price));
+            let price = amount1_out.ratio(&amount0_in)?;
+            steps.push(format!("Swap direction: Selling {} for {} (price = {:.6}/{:.6} = {:.6})",
+                swap.token0.symbol, swap.token1.symbol, amount1_out.as_f64(), amount0_in.as_f64(), price));
             (price, format!("{}->{}", swap.token0.symbol, swap.token1.symbol))
-        } else if amount1_in > 0.0 && amount0_out > 0.0 {
+        } else if !amount1_in.is_zero() && !amount0_out.is_zero() {
             // Selling token1 for token0: price = token1_in / token0_out
-            let price = amount1_in / amount0_out;
-            steps.push(format!("Swap direction: Selling {} for {} (price = {}/{} = {:.6})", 
-                swap.token1.symbol, swap.token0.symbol, amount1_in, amount0_out, price));
+            let price = amount1_in.ratio(&amount0_out)?;
+            steps.push(format!("Swap direction: Selling {} for {} (price = {:.6}/{:.6} = {:.6})",
+                swap.token1.symbol, swap.token0.symbol, amount1_in.as_f64(), amount0_out.as_f64(), price));
             (price, format!("{}->{}", swap.token1.symbol, swap.token0.symbol))
         } else {
             return Err(anyhow::anyhow!("Invalid swap: no clear direction"));
@@ -128,10 +221,6 @@ impl POLPriceCalculator {
         })
     }
 
-    fn convert_raw_amount(&self, raw_amount: u128, decimals: u8) -> f64 {
-        (raw_amount as f64) / (10_f64.powi(decimals as i32))
-    }
-
     fn determine_price_orientation(&self, token0: &str, token1: &str, swap_direction: &str) 
         -> Result<(String, String, bool)> {
         
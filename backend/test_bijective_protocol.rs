@@ -179,8 +179,8 @@ async fn test_schema_cache_processing() -> Result<()> {
         Ok(ProcessedMessage::Trade(trade_data)) => {
             println!("  🎯 Processed trade message:");
             println!("    Instrument: {}", trade_data.instrument_id.debug_info());
-            println!("    Price: ${:.3}", trade_data.price);
-            println!("    Volume: {:.1}", trade_data.volume);
+            println!("    Price: ${:.3}", trade_data.price.to_f64_lossy());
+            println!("    Volume: {:.1}", trade_data.volume.to_f64_lossy());
         }
         Ok(msg) => println!("  ❓ Unexpected message type: {:?}", msg),
         Err(e) => return Err(e.into()),
@@ -58,6 +58,10 @@ fn test_synthetic_swap(calculator: &POLPriceCalculator) -> Result<()> {
         amount1_in_raw: 0,
         amount0_out_raw: 0,
         amount1_out_raw: 230_000_000, // 230 USDC (6 decimals)
+        pool_balance0_raw: None,
+        pool_balance1_raw: None,
+        amplification: None,
+        fee_tier_bps: None,
         tx_hash: "0xtest1".to_string(),
         block_number: 12345,
     };
@@ -97,6 +101,10 @@ fn test_reverse_swap(calculator: &POLPriceCalculator) -> Result<()> {
         amount1_in_raw: 230_000_000, // 230 USDC (6 decimals)
         amount0_out_raw: 1_000_000_000_000_000_000_000,  // 1000 POL (18 decimals)
         amount1_out_raw: 0,
+        pool_balance0_raw: None,
+        pool_balance1_raw: None,
+        amplification: None,
+        fee_tier_bps: None,
         tx_hash: "0xtest2".to_string(),
         block_number: 12346,
     };
@@ -136,6 +144,10 @@ fn test_small_amounts(calculator: &POLPriceCalculator) -> Result<()> {
         amount1_in_raw: 0,
         amount0_out_raw: 0,
         amount1_out_raw: 230_000, // 0.23 USDC (6 decimals)
+        pool_balance0_raw: None,
+        pool_balance1_raw: None,
+        amplification: None,
+        fee_tier_bps: None,
         tx_hash: "0xtest3".to_string(),
         block_number: 12347,
     };
@@ -177,6 +189,10 @@ fn test_wrong_calculation(calculator: &POLPriceCalculator) -> Result<()> {
         amount1_in_raw: 0,
         amount0_out_raw: 0,
         amount1_out_raw: 12_500_000, // ~12.5 USDC instead of 230 USDC
+        pool_balance0_raw: None,
+        pool_balance1_raw: None,
+        amplification: None,
+        fee_tier_bps: None,
         tx_hash: "0xtest4".to_string(),
         block_number: 12348,
     };
@@ -19,47 +19,63 @@ use zerocopy::{AsBytes, FromBytes, FromZeroes};
 /// - Timing information (valid_until, timestamp_ns)
 ///
 /// Uses Q64.64 fixed-point encoding for all financial values to maintain precision.
-/// Fixed size with proper alignment for zero-copy serialization.
-#[repr(C, packed)] // Use packed to avoid alignment padding issues with manual serialization
+///
+/// Field order is cache-line-conscious rather than declaration-order-packed: the
+/// dashboard's Q64.64 conversion loop (`expected_profit_usd`, `required_capital_usd`,
+/// `estimated_gas_cost_native`, `slippage_percentage`) targets <100ns per conversion
+/// and reads exactly these four fields, so they're placed first and padded out to a
+/// full 64-byte line, the same manual cache-line layout technique `ChannelInfo`
+/// uses. The cold validity/metadata fields (expiry, priority, signal id, ...) follow
+/// on subsequent lines, where an extra cache miss doesn't sit in the hot loop.
+///
+/// `align(64)` means `ref_from` needs a 64-byte-aligned buffer and rejects an
+/// arbitrary network-received `&[u8]`; `read_from` copies the bytes into an owned,
+/// aligned `DemoDeFiArbitrageTLV` and accepts any slice of the right length.
+#[repr(C, align(64))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DemoDeFiArbitrageTLV {
-    // Strategy Identity (12 bytes)
-    pub strategy_id: u16, // Flash arbitrage strategy = 21
+    // --- Cache line 0 (bytes 0..64): the Q64.64 conversion hot loop's fields ---
+    pub expected_profit_q: i128,    // Expected profit in Q64.64 USD (16 bytes)
+    pub required_capital_q: u128,   // Required capital in Q64.64 USD (16 bytes)
+    pub estimated_gas_cost_q: u128, // Estimated gas cost in Q64.64 ETH/MATIC (16 bytes)
+    pub slippage_tolerance: u16,    // Slippage tolerance in basis points, e.g. 50 = 0.5% (2 bytes)
+    _hot_line_padding: [u8; 14],    // Pads cache line 0 out to 64 bytes
+
+    // --- Cache line 1+ (bytes 64..): cold validity/metadata and trade description ---
+    pub valid_until: u32, // Unix timestamp when opportunity expires
     pub signal_id: u64,   // Unique signal identifier
+    pub priority: u8,     // Priority level 0-255 (higher = more urgent)
     pub confidence: u8,   // Confidence level 0-100
     pub chain_id: u8,     // Chain ID (1=Ethereum, 137=Polygon)
+    pub reserved: u8,     // Reserved for alignment
+    pub strategy_id: u16, // Flash arbitrage strategy = 21
 
-    // Economics in Q64.64 format (48 bytes)
-    pub expected_profit_q: i128,    // Expected profit in Q64.64 USD
-    pub required_capital_q: u128,   // Required capital in Q64.64 USD
-    pub estimated_gas_cost_q: u128, // Estimated gas cost in Q64.64 ETH/MATIC
-
-    // Pool Information (72 bytes total)
-    pub venue_a: u16,                // First pool venue as u16
-    pub venue_b: u16,                // Second pool venue as u16
-    pub pool_a: EthAddress,          // First pool address (20 bytes)
+    pub venue_a: u16,                   // First pool venue as u16
+    pub venue_b: u16,                   // Second pool venue as u16
+    pub pool_a: EthAddress,             // First pool address (20 bytes)
     pub pool_a_padding: AddressPadding, // Explicit padding (12 bytes)
-    pub pool_b: EthAddress,          // Second pool address (20 bytes)
+    pub pool_b: EthAddress,             // Second pool address (20 bytes)
     pub pool_b_padding: AddressPadding, // Explicit padding (12 bytes)
 
-    // Trade Execution (32 bytes)
     pub token_in: u64,          // Input token address (truncated to 64-bit)
     pub token_out: u64,         // Output token address (truncated to 64-bit)
     pub optimal_amount_q: u128, // Optimal trade amount in Q64.64
 
-    // Risk Parameters (12 bytes)
-    pub slippage_tolerance: u16, // Slippage tolerance in basis points (e.g., 50 = 0.5%)
     pub max_gas_price_gwei: u32, // Maximum gas price in Gwei
-    pub valid_until: u32,        // Unix timestamp when opportunity expires
-    pub priority: u8,            // Priority level 0-255 (higher = more urgent)
-    pub reserved: u8,            // Reserved for alignment
-
-    // Timing (8 bytes)
-    pub timestamp_ns: u64, // Nanoseconds since epoch when detected
-
-                           // Total: 12 + 48 + 72 + 32 + 12 + 8 = 184 bytes (packed, no padding)
+    pub timestamp_ns: u64,       // Nanoseconds since epoch when detected
 }
 
+// Compile-time guard: the four hot fields the Q64.64 conversion loop reads must
+// stay on the struct's first 64-byte cache line. If a future field addition pushes
+// one of them past byte 64, this fails to compile instead of silently regressing
+// the conversion loop back to a second cache miss.
+const _: () = {
+    assert!(std::mem::offset_of!(DemoDeFiArbitrageTLV, expected_profit_q) + 16 <= 64);
+    assert!(std::mem::offset_of!(DemoDeFiArbitrageTLV, required_capital_q) + 16 <= 64);
+    assert!(std::mem::offset_of!(DemoDeFiArbitrageTLV, estimated_gas_cost_q) + 16 <= 64);
+    assert!(std::mem::offset_of!(DemoDeFiArbitrageTLV, slippage_tolerance) + 2 <= 64);
+};
+
 // Manual implementation of zero-copy traits for packed struct
 unsafe impl zerocopy::AsBytes for DemoDeFiArbitrageTLV {
     fn only_derive_is_allowed_to_implement_this_trait() {}
@@ -129,6 +145,7 @@ impl DemoDeFiArbitrageTLV {
             expected_profit_q,
             required_capital_q,
             estimated_gas_cost_q,
+            _hot_line_padding: [0u8; 14],
             venue_a: venue_a as u16,
             venue_b: venue_b as u16,
             pool_a,
@@ -157,6 +174,7 @@ impl DemoDeFiArbitrageTLV {
             expected_profit_q: config.expected_profit_q,
             required_capital_q: config.required_capital_q,
             estimated_gas_cost_q: config.estimated_gas_cost_q,
+            _hot_line_padding: [0u8; 14],
             venue_a: config.venue_a as u16,
             venue_b: config.venue_b as u16,
             pool_a: config.pool_a,
@@ -628,11 +628,15 @@ define_tlv! {
             block_number: u64  // Block number of swap
         }
         u32: { tick_after: i32 } // New tick after swap (V3)
-        u16: { venue: u16 } // NOT VenueId enum! Direct u16 for zero-copy
+        u16: {
+            venue: u16,         // NOT VenueId enum! Direct u16 for zero-copy
+            fee_tier_bps: u16   // Pool's swap fee in basis points (e.g. 5/30/100 for V3's 0.05%/0.30%/1.00% tiers, 30 for V2)
+        }
         u8: {
-            amount_in_decimals: u8,  // Decimals for amount_in (e.g., WMATIC=18)
-            amount_out_decimals: u8, // Decimals for amount_out (e.g., USDC=6)
-            _padding: [u8; 8]        // Required for alignment to 208 bytes
+            amount_in_decimals: u8,      // Decimals for amount_in (e.g., WMATIC=18)
+            amount_out_decimals: u8,     // Decimals for amount_out (e.g., USDC=6)
+            is_economically_viable: u8,  // 1 if amount_in clears the fee+gas dust threshold, 0 otherwise
+            _padding: [u8; 5]            // Required for alignment to 208 bytes
         }
         special: {
             pool_address: [u8; 32],      // Full pool contract address
@@ -672,9 +676,11 @@ impl PoolSwapTLV {
             block_number,
             tick_after,
             venue_id as u16,
+            0, // fee_tier_bps unknown here; set via `with_fee_info`
             amount_in_decimals,
             amount_out_decimals,
-            [0u8; 8], // padding
+            0, // is_economically_viable unknown here; set via `with_fee_info`
+            [0u8; 5], // padding
             pool.to_padded(),
             token_in.to_padded(),
             token_out.to_padded(),
@@ -682,6 +688,35 @@ impl PoolSwapTLV {
         )
     }
 
+    /// Attach the pool's fee tier and a precomputed dust-threshold viability flag,
+    /// so the flash-arbitrage consumer reads a net price and a viable/not-viable
+    /// signal straight off the wire instead of recomputing fees itself.
+    pub fn with_fee_info(mut self, fee_tier_bps: u16, is_economically_viable: bool) -> Self {
+        self.fee_tier_bps = fee_tier_bps;
+        self.is_economically_viable = if is_economically_viable { 1 } else { 0 };
+        self
+    }
+
+    /// The pool's swap fee, in basis points.
+    #[inline(always)]
+    pub fn fee_bps(&self) -> u16 {
+        self.fee_tier_bps
+    }
+
+    /// `true` if `amount_in` was judged large enough to clear this pool's fee and
+    /// gas costs, per `with_fee_info`.
+    #[inline(always)]
+    pub fn is_economically_viable(&self) -> bool {
+        self.is_economically_viable != 0
+    }
+
+    /// `amount_out` after deducting this pool's own swap fee, i.e. the price an
+    /// arbitrageur would actually realize trading through this pool again.
+    pub fn net_amount_out(&self) -> u128 {
+        let fee_bps = self.fee_tier_bps as u128;
+        self.amount_out - (self.amount_out * fee_bps) / 10_000
+    }
+
     /// Create a new PoolSwapTLV from Ethereum addresses
     #[allow(clippy::too_many_arguments)]
     pub fn from_addresses(
@@ -0,0 +1,94 @@
+//! Fans decoded DEX logs from a single upstream [`DexLogSubscriber`] out to many
+//! local consumers, so the strategy engine, archiver, and metrics tasks don't
+//! each need their own upstream WebSocket subscription.
+//!
+//! Mirrors Flodgatt's fan-out architecture: exactly one upstream connection runs
+//! in a dedicated task and publishes every decoded `eth_subscription`
+//! notification onto a broadcast channel; each consumer holds its own receiver
+//! and filters for the topic0 signatures it cares about locally, so upstream
+//! bandwidth stays constant as the consumer count grows.
+
+use super::dex_log_subscriber::{DexLogEvent, DexLogSubscriber};
+use futures_util::stream::{self, Stream};
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
+use tracing::warn;
+use web3::types::{Log, H256};
+
+const BROADCAST_CHANNEL_CAPACITY: usize = 4096;
+
+/// A decoded log paired with its first topic, so `subscribe` can filter without
+/// re-parsing the log.
+#[derive(Debug, Clone)]
+struct TopicLog {
+    topic0: H256,
+    log: Log,
+}
+
+/// Runs exactly one upstream [`DexLogSubscriber`] connection and fans its
+/// decoded logs out to any number of local consumers.
+pub struct DexLogBroadcaster {
+    sender: broadcast::Sender<TopicLog>,
+}
+
+impl DexLogBroadcaster {
+    /// Spawn `subscriber`'s connection in a dedicated task and start
+    /// broadcasting its decoded logs.
+    pub fn spawn(subscriber: DexLogSubscriber) -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let broadcast_tx = sender.clone();
+
+        tokio::spawn(async move {
+            let (event_tx, mut event_rx) = mpsc::channel(1024);
+            let upstream = tokio::spawn(subscriber.run(event_tx));
+
+            while let Some(event) = event_rx.recv().await {
+                if let DexLogEvent::Log(value) = event {
+                    match parse_topic_log(&value) {
+                        Ok(topic_log) => {
+                            // No receivers (or all lagging) isn't an upstream
+                            // error - there's just nothing to deliver to right now.
+                            let _ = broadcast_tx.send(topic_log);
+                        }
+                        Err(e) => warn!("Failed to decode DEX log notification: {}", e),
+                    }
+                }
+            }
+
+            let _ = upstream.await;
+        });
+
+        Self { sender }
+    }
+
+    /// Subscribe to every log whose first topic is in `topics`. Each call opens
+    /// its own broadcast receiver against the single upstream connection; a
+    /// consumer that falls behind skips the messages it missed rather than
+    /// blocking the others.
+    pub fn subscribe(&self, topics: &[H256]) -> impl Stream<Item = Log> {
+        let topics = topics.to_vec();
+        let receiver = self.sender.subscribe();
+
+        stream::unfold((receiver, topics), |(mut receiver, topics)| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(topic_log) if topics.contains(&topic_log.topic0) => {
+                        return Some((topic_log.log, (receiver, topics)));
+                    }
+                    Ok(_) => continue, // Not a topic this consumer cares about.
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
+
+fn parse_topic_log(value: &Value) -> anyhow::Result<TopicLog> {
+    let log: Log = serde_json::from_value(value.clone())?;
+    let topic0 = *log
+        .topics
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("log notification has no topics"))?;
+    Ok(TopicLog { topic0, log })
+}
@@ -0,0 +1,182 @@
+//! Resilient `eth_subscribe`/`logs` subscription with automatic reconnect.
+//!
+//! [`collector::PolygonCollector`](super::collector::PolygonCollector) connects
+//! once and has no recovery path if the WebSocket drops. `DexLogSubscriber`
+//! mirrors the WS reconnection logic added to rust-web3 (reattaching active log
+//! filters after the transport drops): on any disconnect it reconnects with
+//! exponential backoff and automatically re-issues the stored `eth_subscribe`
+//! request and topic filters, so callers never have to re-register. The
+//! server-assigned subscription id from the previous connection is discarded on
+//! reconnect and replaced with whatever the new confirmation returns.
+
+use anyhow::{Context, Result};
+use futures_util::{SinkExt, StreamExt};
+use rand::Rng;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{info, warn};
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// One event delivered from the subscription.
+#[derive(Debug, Clone)]
+pub enum DexLogEvent {
+    /// A decoded `eth_subscription` notification payload (the `result` field).
+    Log(Value),
+    /// The subscription (re)connected and the server confirmed a new
+    /// subscription id.
+    Subscribed { subscription_id: String },
+}
+
+/// Subscribes to `eth_subscribe`/`logs` on a Polygon-style JSON-RPC WebSocket,
+/// reconnecting with exponential backoff (500ms doubling to a 30s cap, with
+/// jitter) and re-subscribing to the same topic/address filters on every
+/// reconnect.
+pub struct DexLogSubscriber {
+    rpc_url: String,
+    /// The `params` array passed to `eth_subscribe`, kept around so a reconnect
+    /// can re-issue the exact same subscription request.
+    subscribe_params: Value,
+    next_request_id: u64,
+    subscription_id: Option<String>,
+}
+
+impl DexLogSubscriber {
+    pub fn new(rpc_url: impl Into<String>, topics: Value, addresses: Value) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            subscribe_params: serde_json::json!(["logs", { "topics": topics, "address": addresses }]),
+            next_request_id: 1,
+            subscription_id: None,
+        }
+    }
+
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.subscription_id.as_deref()
+    }
+
+    /// Run forever, reconnecting and re-subscribing on every disconnect, and
+    /// forwarding each event to `tx`. Returns only once `tx`'s receiver is
+    /// dropped.
+    pub async fn run(mut self, tx: mpsc::Sender<DexLogEvent>) {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            match self.run_session(&tx).await {
+                Ok(()) => return, // Receiver dropped; nothing left to deliver to.
+                Err(e) => {
+                    warn!(
+                        "DEX log subscription dropped ({}); reconnecting in {:?}",
+                        e,
+                        backoff
+                    );
+                }
+            }
+
+            tokio::time::sleep(jittered(backoff)).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Connect once, (re)subscribe, and forward events until the connection
+    /// drops or `tx`'s receiver is dropped.
+    async fn run_session(&mut self, tx: &mpsc::Sender<DexLogEvent>) -> Result<()> {
+        let (ws_stream, _) = connect_async(&self.rpc_url)
+            .await
+            .context("Failed to connect to DEX log WebSocket")?;
+        let (mut sender, mut receiver) = ws_stream.split();
+
+        // Discard the old connection's subscription id; the server assigns a
+        // fresh one for this session.
+        self.subscription_id = None;
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        let subscribe_msg = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": request_id,
+            "method": "eth_subscribe",
+            "params": self.subscribe_params,
+        });
+        sender
+            .send(Message::Text(subscribe_msg.to_string()))
+            .await
+            .context("Failed to send DEX log subscription")?;
+
+        while let Some(message) = receiver.next().await {
+            match message.context("DEX log WebSocket error")? {
+                Message::Text(text) => {
+                    let value: Value = serde_json::from_str(&text)?;
+
+                    if value.get("id").and_then(Value::as_u64) == Some(request_id) {
+                        if let Some(result) = value.get("result").and_then(Value::as_str) {
+                            self.subscription_id = Some(result.to_string());
+                            info!("DEX log subscription confirmed: {}", result);
+                            if tx
+                                .send(DexLogEvent::Subscribed {
+                                    subscription_id: result.to_string(),
+                                })
+                                .await
+                                .is_err()
+                            {
+                                return Ok(());
+                            }
+                        }
+                        continue;
+                    }
+
+                    let Some(result) = value.get("params").and_then(|p| p.get("result")) else {
+                        continue;
+                    };
+                    if tx.send(DexLogEvent::Log(result.clone())).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                Message::Ping(payload) => {
+                    sender.send(Message::Pong(payload)).await?;
+                }
+                Message::Close(_) => {
+                    anyhow::bail!("DEX log WebSocket closed by server");
+                }
+                _ => {}
+            }
+        }
+
+        anyhow::bail!("DEX log WebSocket stream ended")
+    }
+}
+
+/// Adds up to 20% random jitter on top of `backoff`, so many reconnecting
+/// subscribers don't all retry in lockstep.
+fn jittered(backoff: Duration) -> Duration {
+    let jitter_frac: f64 = rand::thread_rng().gen_range(0.0..0.2);
+    backoff + Duration::from_secs_f64(backoff.as_secs_f64() * jitter_frac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jittered_backoff_never_shrinks_and_adds_at_most_20_percent() {
+        let base = Duration::from_millis(500);
+        for _ in 0..100 {
+            let jittered = jittered(base);
+            assert!(jittered >= base);
+            assert!(jittered <= base + base.mul_f64(0.2));
+        }
+    }
+
+    #[test]
+    fn test_new_subscriber_has_no_subscription_id_until_confirmed() {
+        let subscriber = DexLogSubscriber::new(
+            "wss://example.invalid",
+            serde_json::json!(["0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67"]),
+            serde_json::json!(["0x45dda9cb7c25131df268515131f647d726f50608"]),
+        );
+        assert!(subscriber.subscription_id().is_none());
+    }
+}
@@ -4,7 +4,11 @@
 //! Processes Uniswap V2, V3, and other AMM events from Polygon chain.
 
 pub mod collector;
+pub mod dex_log_broadcaster;
+pub mod dex_log_subscriber;
 pub mod parser;
+pub mod racing_subscriber;
+pub mod rpc_client;
 pub mod types;
 
 use alphapulse_types::protocol::{MessageHeader, TLVType};
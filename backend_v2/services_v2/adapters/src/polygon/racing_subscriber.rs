@@ -0,0 +1,202 @@
+//! Races [`DexLogSubscriber`] connections across several RPC endpoints so a
+//! single slow or stalled provider can't stall the whole pipeline.
+//!
+//! Each endpoint gets its own `DexLogSubscriber` running concurrently; logs
+//! are deduplicated by `(block_number, log_index, transaction_hash)` so
+//! whichever endpoint delivers an event first wins and the rest serve as hot
+//! standbys. Per endpoint, the messages delivered, races won and timeout count
+//! are kept as running totals since start; message latency is not measured.
+//! An endpoint that stays stale past [`STALENESS_THRESHOLD`] is evicted and
+//! replaced with a fresh connection to the same URL.
+
+use super::dex_log_subscriber::{DexLogEvent, DexLogSubscriber};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::time::interval;
+use tracing::{info, warn};
+use web3::types::Log;
+
+/// How often every endpoint is checked for staleness; each check that finds
+/// an endpoint stale adds one to its `timeout_count` and evicts it.
+const STALENESS_WINDOW: Duration = Duration::from_secs(60);
+/// An endpoint with no successful message in this long is evicted and
+/// reconnected, regardless of its timeout count.
+const STALENESS_THRESHOLD: Duration = Duration::from_secs(60);
+/// How often the health report is logged.
+const REPORT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The dedup key for a single log: the only fields that are stable across
+/// providers returning the same on-chain event.
+type LogKey = (u64, u64, String);
+
+fn log_key(log: &Log) -> Option<LogKey> {
+    Some((
+        log.block_number?.as_u64(),
+        log.log_index?.as_u64(),
+        format!("{:?}", log.transaction_hash?),
+    ))
+}
+
+/// Rolling health stats for one racing endpoint.
+#[derive(Debug, Clone)]
+struct EndpointHealth {
+    url: String,
+    last_message_at: Instant,
+    /// Timeouts (stale windows with no message) observed against
+    /// [`STALENESS_WINDOW`].
+    timeout_count: u32,
+    messages_delivered: u64,
+    /// Of `messages_delivered`, how many were the winning (first) copy of an
+    /// event rather than a duplicate from a slower endpoint.
+    races_won: u64,
+}
+
+impl EndpointHealth {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            last_message_at: Instant::now(),
+            timeout_count: 0,
+            messages_delivered: 0,
+            races_won: 0,
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        self.last_message_at.elapsed() >= STALENESS_THRESHOLD
+    }
+}
+
+/// Spawns the forwarding task for one racing endpoint: connects a fresh
+/// `DexLogSubscriber` to `url` and relays its events, tagged with `index`,
+/// onto `event_tx`. Used both for the initial connection and to respawn a
+/// replacement when the existing one is evicted for staleness.
+fn spawn_endpoint(
+    index: usize,
+    url: String,
+    topics: Value,
+    addresses: Value,
+    event_tx: mpsc::Sender<(usize, DexLogEvent)>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let subscriber = DexLogSubscriber::new(url, topics, addresses);
+        let (inner_tx, mut inner_rx) = mpsc::channel(1024);
+        let upstream = tokio::spawn(subscriber.run(inner_tx));
+        while let Some(event) = inner_rx.recv().await {
+            if event_tx.send((index, event)).await.is_err() {
+                break;
+            }
+        }
+        let _ = upstream.await;
+    })
+}
+
+/// Races `DexLogSubscriber` connections across several endpoints and
+/// deduplicates their output into a single stream of logs.
+pub struct RacingSubscriber {
+    endpoints: Vec<String>,
+    topics: Value,
+    addresses: Value,
+}
+
+impl RacingSubscriber {
+    pub fn new(endpoints: Vec<String>, topics: Value, addresses: Value) -> Self {
+        Self {
+            endpoints,
+            topics,
+            addresses,
+        }
+    }
+
+    /// Run all endpoints concurrently, forwarding the first copy of each
+    /// distinct log to `tx` and logging a per-endpoint health report (messages
+    /// delivered, races won, timeouts, time since the last message) every
+    /// [`REPORT_INTERVAL`]. Runs forever; returns only once `tx`'s
+    /// receiver is dropped.
+    pub async fn run(self, tx: mpsc::Sender<Log>) {
+        let (event_tx, mut event_rx) = mpsc::channel::<(usize, DexLogEvent)>(4096);
+
+        let mut health: Vec<EndpointHealth> = self
+            .endpoints
+            .iter()
+            .map(|url| EndpointHealth::new(url.clone()))
+            .collect();
+
+        // One `JoinHandle` per endpoint, so a stale endpoint's task can be
+        // aborted and replaced with a fresh connection to the same URL
+        // without disturbing the others.
+        let mut handles: Vec<tokio::task::JoinHandle<()>> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, url)| {
+                spawn_endpoint(index, url.clone(), self.topics.clone(), self.addresses.clone(), event_tx.clone())
+            })
+            .collect();
+
+        let mut seen: HashSet<LogKey> = HashSet::new();
+        let mut report_timer = interval(REPORT_INTERVAL);
+        let mut staleness_timer = interval(STALENESS_WINDOW);
+
+        loop {
+            tokio::select! {
+                event = event_rx.recv() => {
+                    let Some((index, event)) = event else { break };
+                    let DexLogEvent::Log(value) = event else { continue };
+                    health[index].last_message_at = Instant::now();
+                    health[index].messages_delivered += 1;
+
+                    let Ok(log) = serde_json::from_value::<Log>(value) else { continue };
+                    let Some(key) = log_key(&log) else { continue };
+
+                    if seen.insert(key) {
+                        health[index].races_won += 1;
+                        if tx.send(log).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                _ = staleness_timer.tick() => {
+                    for (index, endpoint) in health.iter_mut().enumerate() {
+                        if endpoint.is_stale() {
+                            endpoint.timeout_count += 1;
+                            warn!(
+                                "Racing endpoint {} stale for {:?}; evicting and reconnecting (timeout_count={})",
+                                endpoint.url,
+                                endpoint.last_message_at.elapsed(),
+                                endpoint.timeout_count,
+                            );
+
+                            handles[index].abort();
+                            handles[index] = spawn_endpoint(
+                                index,
+                                endpoint.url.clone(),
+                                self.topics.clone(),
+                                self.addresses.clone(),
+                                event_tx.clone(),
+                            );
+                            // Treat the fresh connection as live as of now so it
+                            // isn't immediately re-evicted next tick before it's
+                            // had a chance to deliver anything.
+                            endpoint.last_message_at = Instant::now();
+                        }
+                    }
+                }
+                _ = report_timer.tick() => {
+                    for endpoint in &health {
+                        info!(
+                            "Racing endpoint health: url={} delivered={} races_won={} timeout_count={} last_message={:?} ago",
+                            endpoint.url,
+                            endpoint.messages_delivered,
+                            endpoint.races_won,
+                            endpoint.timeout_count,
+                            endpoint.last_message_at.elapsed(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Typed `eth_subscribe` client built on jsonrpsee, replacing the hand-rolled
+//! JSON-RPC envelope construction and raw `Value` pattern matching used
+//! elsewhere in this module ([`collector`](super::collector),
+//! [`dex_log_subscriber`](super::dex_log_subscriber)).
+//!
+//! Those build the `{"jsonrpc":"2.0","method":"eth_subscribe",...}` envelope by
+//! hand, match the confirmation on the request id they sent, and match on `method ==
+//! "eth_subscription"` / `params.result.topics` directly. jsonrpsee's
+//! `SubscriptionClientT` does request-id correlation and subscription
+//! confirmation internally and yields a typed `Subscription<Log>` stream, with
+//! `eth_unsubscribe` sent automatically when the subscription is dropped.
+
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use jsonrpsee::core::client::{Subscription, SubscriptionClientT};
+use jsonrpsee::rpc_params;
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use serde_json::Value;
+use web3::types::Log;
+
+/// Thin wrapper over a jsonrpsee WS client for Polygon's `eth_subscribe`
+/// endpoint.
+pub struct PolygonLogClient {
+    client: WsClient,
+}
+
+impl PolygonLogClient {
+    pub async fn connect(rpc_url: &str) -> Result<Self> {
+        let client = WsClientBuilder::default()
+            .build(rpc_url)
+            .await
+            .context("Failed to connect to Polygon JSON-RPC WebSocket")?;
+        Ok(Self { client })
+    }
+
+    /// Subscribe to logs matching `filter` (the same `{"topics": [...],
+    /// "address": [...]}` shape previously built inline). `eth_unsubscribe` is
+    /// sent automatically once the returned subscription is dropped.
+    pub async fn subscribe_logs(&self, filter: Value) -> Result<Subscription<Log>> {
+        self.client
+            .subscribe::<Log, _>("eth_subscribe", rpc_params!["logs", filter], "eth_unsubscribe")
+            .await
+            .context("Failed to subscribe to Polygon logs")
+    }
+}
+
+/// Pull the next decoded `Log` off `subscription`, surfacing jsonrpsee's
+/// structured `ErrorObject` instead of formatting transport/decode failures
+/// into a string by hand.
+pub async fn next_log(subscription: &mut Subscription<Log>) -> Option<Result<Log>> {
+    subscription
+        .next()
+        .await
+        .map(|item| item.map_err(|e| anyhow::anyhow!("Polygon log subscription error: {}", e)))
+}
@@ -391,7 +391,12 @@ fn map_token_symbol(token_id: u64) -> &'static str {
 /// Convert DemoDeFiArbitrageTLV to arbitrage opportunity JSON with enhanced metrics
 fn convert_demo_defi_arbitrage_tlv(payload: &[u8], timestamp_ns: u64) -> Result<Value> {
     use zerocopy::FromBytes;
-    let arbitrage_tlv = DemoDeFiArbitrageTLV::ref_from(payload).ok_or_else(|| {
+    // `DemoDeFiArbitrageTLV` is `repr(align(64))`, but `payload` is a `Vec<u8>`
+    // slice off the wire with no alignment guarantee, so `ref_from` would
+    // reject virtually every real message. `read_from` copies into an owned,
+    // properly-aligned value instead, which is all we need since every field
+    // is copied out to a local below anyway.
+    let arbitrage_tlv = DemoDeFiArbitrageTLV::read_from(payload).ok_or_else(|| {
         DashboardError::Protocol(protocol_v2::ProtocolError::Parse(
             ParseError::MessageTooSmall {
                 need: std::mem::size_of::<DemoDeFiArbitrageTLV>(),
@@ -265,6 +265,33 @@ fn benchmark_validation_performance(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark the Q64.64 conversion hot loop as a batch: all four accessors
+/// (`expected_profit_usd`, `required_capital_usd`, `estimated_gas_cost_native`,
+/// `slippage_percentage`) back-to-back on the same TLV, the way the dashboard
+/// actually calls them. Contrast against `benchmark_q64_conversions` above, which
+/// times each accessor in isolation and so can't show whether the four together
+/// fit in a single cache line - this is the number that should drop once the hot
+/// fields share `DemoDeFiArbitrageTLV`'s first 64-byte cache line.
+fn benchmark_hot_field_batch_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("q64_conversions_hot_loop");
+    group.throughput(Throughput::Elements(4)); // 4 accessors per iteration
+
+    let arbitrage_tlv = create_benchmark_arbitrage_tlv();
+
+    group.bench_function("all_four_accessors", |b| {
+        b.iter(|| {
+            black_box((
+                arbitrage_tlv.expected_profit_usd(),
+                arbitrage_tlv.required_capital_usd(),
+                arbitrage_tlv.estimated_gas_cost_native(),
+                arbitrage_tlv.slippage_percentage(),
+            ))
+        });
+    });
+
+    group.finish();
+}
+
 /// Load test: simulate high-frequency arbitrage signal generation
 fn load_test_arbitrage_signals(c: &mut Criterion) {
     let mut group = c.benchmark_group("load_test");
@@ -312,6 +339,7 @@ criterion_group!(
     benchmark_tlv_serialization,
     benchmark_tlv_message_building,
     benchmark_q64_conversions,
+    benchmark_hot_field_batch_read,
     benchmark_signal_output_throughput,
     benchmark_memory_usage,
     benchmark_validation_performance,
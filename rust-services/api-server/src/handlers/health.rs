@@ -1,24 +1,51 @@
 // Health check handler
-use axum::{extract::State, Json};
+use alphapulse_common::DEFAULT_STALE_THRESHOLD;
+use axum::{extract::State, http::StatusCode, Json};
 use serde_json::{json, Value};
 use crate::state::AppState;
 
-pub async fn health_check(State(state): State<AppState>) -> Json<Value> {
+pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
     // Test Redis connection
     let redis_healthy = state.redis.get_available_symbols("coinbase").await.is_ok();
-    
+
+    let chains = state.liveness.snapshot(DEFAULT_STALE_THRESHOLD);
+    let any_stale = chains.iter().any(|chain| chain.stale);
+
+    let collectors: Value = chains
+        .iter()
+        .map(|chain| {
+            (
+                chain.chain.clone(),
+                json!({
+                    "status": if chain.stale { "stale" } else { "healthy" },
+                    "messages_received": chain.messages_received,
+                    "timeouts": chain.timeouts,
+                    "seconds_since_last_event": chain.seconds_since_last_event,
+                }),
+            )
+        })
+        .collect::<serde_json::Map<String, Value>>()
+        .into();
+
+    let status_code = if redis_healthy && !any_stale {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
     let response = json!({
-        "status": "ok",
+        "status": if status_code == StatusCode::OK { "ok" } else { "degraded" },
         "service": "alphapulse-api-server",
         "version": "0.1.0",
         "timestamp": chrono::Utc::now().timestamp(),
         "components": {
-            "redis": if redis_healthy { "healthy" } else { "unhealthy" }
+            "redis": if redis_healthy { "healthy" } else { "unhealthy" },
+            "collectors": collectors
         }
     });
-    
+
     // Record health check metric
-    state.metrics.record_http_request("GET", "/health", 200);
-    
-    Json(response)
-}
\ No newline at end of file
+    state.metrics.record_http_request("GET", "/health", status_code.as_u16());
+
+    (status_code, Json(response))
+}
@@ -1,5 +1,5 @@
 // Application state for the API server
-use alphapulse_common::{Result, MetricsCollector};
+use alphapulse_common::{CollectorLiveness, Result, MetricsCollector};
 use crate::redis_client::RedisClient;
 use std::sync::Arc;
 
@@ -7,16 +7,20 @@ use std::sync::Arc;
 pub struct AppState {
     pub redis: Arc<RedisClient>,
     pub metrics: Arc<MetricsCollector>,
+    /// Per-chain collector liveness, updated by each collector loop and read
+    /// by the `/health` handler to detect a silently-dead subscription.
+    pub liveness: Arc<CollectorLiveness>,
 }
 
 impl AppState {
     pub async fn new() -> Result<Self> {
         let redis_url = std::env::var("REDIS_URL")
             .unwrap_or_else(|_| "redis://localhost:6379".to_string());
-        
+
         let redis = Arc::new(RedisClient::new(&redis_url).await?);
         let metrics = Arc::new(MetricsCollector::new());
-        
-        Ok(Self { redis, metrics })
+        let liveness = Arc::new(CollectorLiveness::new());
+
+        Ok(Self { redis, metrics, liveness })
     }
 }
\ No newline at end of file
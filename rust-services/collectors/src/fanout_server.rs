@@ -0,0 +1,125 @@
+// Orderbook delta fan-out server: lets external services consume the same
+// delta stream over the network (subscribe/unsubscribe per symbol, with an
+// on-connect checkpoint) instead of only via in-process channels or the
+// shared-memory ring on this host.
+use alphapulse_common::{OrderBookDelta, OrderBookUpdate};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tracing::{info, warn};
+
+/// Each connected peer's outgoing-message channel plus the set of symbols
+/// ("marketId"s) it's currently subscribed to.
+type PeerMap = Arc<Mutex<HashMap<SocketAddr, (mpsc::UnboundedSender<Message>, HashSet<String>)>>>;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "lowercase")]
+enum ClientCommand {
+    Subscribe {
+        #[serde(rename = "marketId")]
+        market_id: String,
+    },
+    Unsubscribe {
+        #[serde(rename = "marketId")]
+        market_id: String,
+    },
+}
+
+/// Run the fan-out server: accept WebSocket clients on `addr`, let each
+/// subscribe/unsubscribe to symbols, and stream `OrderBookDelta`s received on
+/// `delta_rx` only to peers subscribed to that symbol. `orderbooks` is the
+/// collector's own cache, used to send a full checkpoint the moment a client
+/// subscribes so it doesn't have to wait for the next delta to see a
+/// complete book.
+pub async fn run_fanout_server(
+    addr: &str,
+    mut delta_rx: mpsc::Receiver<OrderBookDelta>,
+    orderbooks: Arc<RwLock<HashMap<String, OrderBookUpdate>>>,
+) -> std::io::Result<()> {
+    let peers: PeerMap = Arc::new(Mutex::new(HashMap::new()));
+    let listener = TcpListener::bind(addr).await?;
+    info!("Orderbook fan-out server listening on {}", addr);
+
+    let broadcast_peers = peers.clone();
+    tokio::spawn(async move {
+        while let Some(delta) = delta_rx.recv().await {
+            let msg = Message::Text(json!({ "type": "delta", "data": &delta }).to_string());
+            let mut peers = broadcast_peers.lock().await;
+            peers.retain(|_, (tx, subscriptions)| {
+                if !subscriptions.contains(&delta.symbol) {
+                    return true;
+                }
+                tx.send(msg.clone()).is_ok()
+            });
+        }
+    });
+
+    while let Ok((stream, peer_addr)) = listener.accept().await {
+        tokio::spawn(handle_peer(stream, peer_addr, peers.clone(), orderbooks.clone()));
+    }
+
+    Ok(())
+}
+
+async fn handle_peer(
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+    peers: PeerMap,
+    orderbooks: Arc<RwLock<HashMap<String, OrderBookUpdate>>>,
+) {
+    let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            warn!("Fan-out handshake failed for {}: {}", peer_addr, e);
+            return;
+        }
+    };
+
+    let (mut outgoing, mut incoming) = ws_stream.split();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    peers.lock().await.insert(peer_addr, (tx, HashSet::new()));
+    info!("Fan-out peer {} connected", peer_addr);
+
+    let forward_task = tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
+            if outgoing.send(msg).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(Ok(msg)) = incoming.next().await {
+        if let Message::Text(text) = msg {
+            match serde_json::from_str::<ClientCommand>(&text) {
+                Ok(ClientCommand::Subscribe { market_id }) => {
+                    let checkpoint = orderbooks.read().await.get(&market_id).cloned();
+                    let mut peers_guard = peers.lock().await;
+                    if let Some((tx, subscriptions)) = peers_guard.get_mut(&peer_addr) {
+                        subscriptions.insert(market_id.clone());
+                        if let Some(book) = checkpoint {
+                            let _ = tx.send(Message::Text(
+                                json!({ "type": "checkpoint", "marketId": market_id, "data": book }).to_string(),
+                            ));
+                        }
+                    }
+                }
+                Ok(ClientCommand::Unsubscribe { market_id }) => {
+                    if let Some((_, subscriptions)) = peers.lock().await.get_mut(&peer_addr) {
+                        subscriptions.remove(&market_id);
+                    }
+                }
+                Err(e) => warn!("Unrecognized fan-out command from {}: {}", peer_addr, e),
+            }
+        }
+    }
+
+    forward_task.abort();
+    peers.lock().await.remove(&peer_addr);
+    info!("Fan-out peer {} disconnected", peer_addr);
+}
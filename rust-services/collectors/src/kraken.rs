@@ -1,21 +1,39 @@
 // Kraken WebSocket collector with L2 orderbook support
 use alphapulse_common::{
     Result, Trade, KrakenTradeMessage, MetricsCollector,
-    OrderBookUpdate, OrderBookLevel, OrderBookTracker, 
-    OrderBookSnapshot, OrderBookDelta,
+    OrderBookUpdate, OrderBookLevel, OrderBookTracker,
+    OrderBookSnapshot, OrderBookDelta, FundingRate,
     shared_memory::{OrderBookDeltaWriter, SharedOrderBookDelta}
 };
 use crate::collector_trait::MarketDataCollector;
+use crate::exchange_parser::{calc_quantity_and_volume, ContractSizeTable, MarketType};
 use std::collections::HashMap;
 use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
+use rand::Rng;
 use serde_json::json;
-use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+use std::sync::{Arc, atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering}};
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use tracing::{info, warn, error, debug};
 use url::Url;
 
+/// Default exponential-backoff starting delay between reconnect attempts.
+const DEFAULT_BACKOFF_BASE: Duration = Duration::from_secs(1);
+/// Backoff never grows past this, however many consecutive failures occur.
+const DEFAULT_BACKOFF_CAP: Duration = Duration::from_secs(60);
+/// If no trade/orderbook/heartbeat message arrives within this long, the
+/// watchdog treats the socket as silently stalled and forces a reconnect.
+const DEFAULT_STALENESS_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn now_unix_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
 pub struct KrakenCollector {
     symbols: Vec<String>,
     ws_url: String,
@@ -26,6 +44,14 @@ pub struct KrakenCollector {
     orderbooks: Arc<tokio::sync::RwLock<HashMap<String, OrderBookUpdate>>>,
     orderbook_tracker: OrderBookTracker,
     delta_writer: Option<Arc<tokio::sync::Mutex<OrderBookDeltaWriter>>>,
+    funding_tx: Option<mpsc::Sender<FundingRate>>,
+    market_type: MarketType,
+    contract_sizes: ContractSizeTable,
+    reconnect_backoff_base: Duration,
+    reconnect_backoff_cap: Duration,
+    staleness_timeout: Duration,
+    backoff_ms: Arc<AtomicU64>,
+    last_message_at_ms: Arc<AtomicI64>,
 }
 
 impl KrakenCollector {
@@ -46,6 +72,14 @@ impl KrakenCollector {
             orderbooks: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             orderbook_tracker: OrderBookTracker::new(50), // Track top 50 levels
             delta_writer: None,
+            funding_tx: None,
+            market_type: MarketType::Spot,
+            contract_sizes: ContractSizeTable::new(),
+            reconnect_backoff_base: DEFAULT_BACKOFF_BASE,
+            reconnect_backoff_cap: DEFAULT_BACKOFF_CAP,
+            staleness_timeout: DEFAULT_STALENESS_TIMEOUT,
+            backoff_ms: Arc::new(AtomicU64::new(DEFAULT_BACKOFF_BASE.as_millis() as u64)),
+            last_message_at_ms: Arc::new(AtomicI64::new(0)),
         }
     }
     
@@ -75,6 +109,39 @@ impl KrakenCollector {
         self.delta_tx = Some(tx);
         self
     }
+
+    /// Subscribe to perpetual/swap funding-rate updates alongside trades and
+    /// forward each one to `tx`, for strategies that track carry cost.
+    pub fn with_funding_sender(mut self, tx: mpsc::Sender<FundingRate>) -> Self {
+        self.funding_tx = Some(tx);
+        self
+    }
+
+    /// Set the market type and per-symbol contract-size table used to turn a
+    /// raw trade `size` (contracts, on derivatives) into a consistent
+    /// base-quantity/quote-volume pair. Defaults to `MarketType::Spot` with
+    /// an empty table, which passes sizes through unchanged.
+    pub fn with_market_type(mut self, market_type: MarketType, contract_sizes: ContractSizeTable) -> Self {
+        self.market_type = market_type;
+        self.contract_sizes = contract_sizes;
+        self
+    }
+
+    /// Override the exponential-backoff bounds used between reconnect
+    /// attempts (defaults: 1s doubling up to a 60s cap).
+    pub fn with_reconnect_backoff(mut self, base: Duration, cap: Duration) -> Self {
+        self.backoff_ms = Arc::new(AtomicU64::new(base.as_millis() as u64));
+        self.reconnect_backoff_base = base;
+        self.reconnect_backoff_cap = cap;
+        self
+    }
+
+    /// Override how long the watchdog waits without a trade/orderbook/
+    /// heartbeat message before treating the socket as stalled (default 30s).
+    pub fn with_staleness_timeout(mut self, timeout: Duration) -> Self {
+        self.staleness_timeout = timeout;
+        self
+    }
     
     pub fn with_shared_memory_writer(mut self) -> Result<Self> {
         // Create shared memory writer for orderbook deltas
@@ -129,13 +196,22 @@ impl KrakenCollector {
                                 // Kraken trade format: [price, volume, timestamp, side, orderType, misc]
                                 if trade_values.len() >= 4 {
                                     let price = trade_values[0].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
-                                    let volume = trade_values[1].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
+                                    let raw_size = trade_values[1].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
                                     let timestamp = trade_values[2].as_f64().unwrap_or(0.0);
                                     let side = trade_values[3].as_str().unwrap_or("unknown").to_string();
-                                    
+                                    let symbol = trade_msg.pair.clone().unwrap_or_default();
+
+                                    // On derivatives feeds `raw_size` counts contracts, not
+                                    // base-asset units - normalize via the configured market
+                                    // type and per-symbol contract size (1.0, i.e. a no-op, for
+                                    // spot and for symbols missing from the table).
+                                    let contract_size = self.contract_sizes.get(&symbol).copied().unwrap_or(1.0);
+                                    let (volume, _quote_volume) =
+                                        calc_quantity_and_volume(self.market_type, contract_size, price, raw_size);
+
                                     let trade = Trade {
                                         timestamp,
-                                        symbol: trade_msg.pair.clone().unwrap_or_default(),
+                                        symbol,
                                         exchange: "kraken".to_string(),
                                         price,
                                         volume,
@@ -166,6 +242,15 @@ impl KrakenCollector {
                         }
                     }
                 }
+                // Try parsing as a perpetual/swap funding-rate update
+                else if text.contains("\"channel\":\"funding_rate\"") {
+                    if let Ok(funding_msg) = serde_json::from_str::<serde_json::Value>(&text) {
+                        if let Some(tx) = &self.funding_tx {
+                            self.handle_funding_rate(funding_msg, tx).await?;
+                            self.metrics.record_websocket_message("kraken", "funding_rate");
+                        }
+                    }
+                }
                 else {
                     // Handle subscription confirmations and other messages
                     if text.contains("\"method\":\"subscribe\"") && text.contains("\"result\":\"success\"") {
@@ -216,10 +301,19 @@ impl MarketDataCollector for KrakenCollector {
                     self.healthy.store(false, Ordering::Relaxed);
                     self.metrics.record_websocket_connection_status("kraken", false);
                     self.metrics.record_websocket_reconnection("kraken");
-                    
-                    // Wait before reconnecting
-                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                    info!("Attempting to reconnect to Kraken...");
+
+                    // Exponential backoff with jitter, reset to the base delay once
+                    // `run_collector` reconnects and has sent its subscriptions.
+                    let backoff_ms = self.backoff_ms.load(Ordering::Relaxed);
+                    let jitter_ms = rand::thread_rng().gen_range(0..250);
+                    let sleep_for = Duration::from_millis(backoff_ms + jitter_ms);
+                    info!("Reconnecting to Kraken in {:?}...", sleep_for);
+                    tokio::time::sleep(sleep_for).await;
+
+                    let next_backoff_ms = backoff_ms
+                        .saturating_mul(2)
+                        .min(self.reconnect_backoff_cap.as_millis() as u64);
+                    self.backoff_ms.store(next_backoff_ms, Ordering::Relaxed);
                 }
             }
         }
@@ -279,12 +373,30 @@ impl KrakenCollector {
             write.send(Message::Text(book_subscribe_msg.to_string())).await?;
             info!("Subscribed to Kraken orderbooks for symbols: {:?}", self.symbols);
         }
-        
+
+        // Subscribe to perpetual/swap funding-rate updates if a caller wants them
+        if self.funding_tx.is_some() {
+            let funding_subscribe_msg = json!({
+                "method": "subscribe",
+                "params": {
+                    "channel": "funding_rate",
+                    "symbol": self.symbols
+                }
+            });
+
+            write.send(Message::Text(funding_subscribe_msg.to_string())).await?;
+            info!("Subscribed to Kraken funding rates for symbols: {:?}", self.symbols);
+        }
+
         info!("Connected and subscribed to Kraken for symbols: {:?}", self.symbols);
-        
+
         self.healthy.store(true, Ordering::Relaxed);
         self.metrics.record_websocket_connection_status("kraken", true);
-        
+        self.last_message_at_ms.store(now_unix_ms(), Ordering::Relaxed);
+        // Reset backoff now that the connection is back up; it'll climb again
+        // only if this connection also fails.
+        self.backoff_ms.store(self.reconnect_backoff_base.as_millis() as u64, Ordering::Relaxed);
+
         // Store write half in Arc<Mutex> for sharing
         let write_shared = Arc::new(tokio::sync::Mutex::new(write));
         let write_heartbeat = write_shared.clone();
@@ -307,24 +419,49 @@ impl KrakenCollector {
             }
         });
         
+        // Watchdog: a socket that stalls without ever sending a close frame
+        // would otherwise look "healthy" forever since we'd just be blocked
+        // on `read.next()`. Poll periodically and force a reconnect if too
+        // long has passed since the last message of any kind.
+        let mut watchdog = tokio::time::interval(self.staleness_timeout / 3);
+
         // Process incoming messages
-        while let Some(msg_result) = read.next().await {
-            match msg_result {
-                Ok(msg) => {
-                    if let Err(e) = self.handle_message(msg, tx).await {
-                        error!("Error handling Kraken message: {}", e);
+        loop {
+            tokio::select! {
+                msg_result = read.next() => {
+                    match msg_result {
+                        Some(Ok(msg)) => {
+                            self.last_message_at_ms.store(now_unix_ms(), Ordering::Relaxed);
+                            // Propagate so a checksum-mismatch error forces the reconnect
+                            // path below, which resubscribes to level2 with a fresh snapshot.
+                            self.handle_message(msg, tx).await?;
+                        }
+                        Some(Err(e)) => {
+                            error!("WebSocket error from Kraken: {}", e);
+                            return Err(e.into());
+                        }
+                        None => break,
                     }
                 }
-                Err(e) => {
-                    error!("WebSocket error from Kraken: {}", e);
-                    return Err(e.into());
+                _ = watchdog.tick() => {
+                    let since_last_ms = now_unix_ms() - self.last_message_at_ms.load(Ordering::Relaxed);
+                    if since_last_ms > self.staleness_timeout.as_millis() as i64 {
+                        warn!(
+                            "Kraken connection stale ({}ms since last message), forcing reconnect",
+                            since_last_ms
+                        );
+                        return Err(alphapulse_common::AlphaPulseError::ServiceUnavailable(format!(
+                            "Kraken connection stale: no message in {}ms",
+                            since_last_ms
+                        )));
+                    }
                 }
             }
         }
-        
+
         self.healthy.store(false, Ordering::Relaxed);
         self.metrics.record_websocket_connection_status("kraken", false);
-        
+
         Ok(())
     }
     
@@ -339,31 +476,42 @@ impl KrakenCollector {
                         // Parse bids and asks
                         let mut bids = Vec::new();
                         let mut asks = Vec::new();
-                        
+                        // Original wire-format price/size strings, kept alongside the
+                        // reparsed floats above so the checksum (below) can hash the
+                        // exact digits Kraken sent rather than a reformatted float.
+                        let mut raw_bids = Vec::new();
+                        let mut raw_asks = Vec::new();
+
                         if let Some(bid_array) = item.get("bids").and_then(|b| b.as_array()) {
                             for bid in bid_array.iter() {
                                 if let Some(bid_data) = bid.as_array() {
                                     if bid_data.len() >= 2 {
-                                        let price = bid_data[0].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
-                                        let size = bid_data[1].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
+                                        let price_str = bid_data[0].as_str().unwrap_or_default();
+                                        let size_str = bid_data[1].as_str().unwrap_or_default();
+                                        let price = price_str.parse::<f64>().unwrap_or(0.0);
+                                        let size = size_str.parse::<f64>().unwrap_or(0.0);
                                         bids.push([price, size]);
+                                        raw_bids.push((price_str.to_string(), size_str.to_string()));
                                     }
                                 }
                             }
                         }
-                        
+
                         if let Some(ask_array) = item.get("asks").and_then(|a| a.as_array()) {
                             for ask in ask_array.iter() {
                                 if let Some(ask_data) = ask.as_array() {
                                     if ask_data.len() >= 2 {
-                                        let price = ask_data[0].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
-                                        let size = ask_data[1].as_str().and_then(|s| s.parse::<f64>().ok()).unwrap_or(0.0);
+                                        let price_str = ask_data[0].as_str().unwrap_or_default();
+                                        let size_str = ask_data[1].as_str().unwrap_or_default();
+                                        let price = price_str.parse::<f64>().unwrap_or(0.0);
+                                        let size = size_str.parse::<f64>().unwrap_or(0.0);
                                         asks.push([price, size]);
+                                        raw_asks.push((price_str.to_string(), size_str.to_string()));
                                     }
                                 }
                             }
                         }
-                        
+
                         // Create OrderBookSnapshot for delta tracking
                         let snapshot = OrderBookSnapshot {
                             symbol: Self::convert_symbol_from_kraken(symbol),
@@ -376,7 +524,32 @@ impl KrakenCollector {
                         
                         // Update OrderBookTracker with snapshot
                         self.orderbook_tracker.update_snapshot(&snapshot.symbol, "kraken", snapshot.clone()).await;
-                        
+                        self.orderbook_tracker
+                            .update_checksum_levels(&snapshot.symbol, "kraken", raw_bids, raw_asks)
+                            .await;
+
+                        // Kraken stamps "update" messages with a checksum over the top of
+                        // book so clients can detect silent corruption/missed updates.
+                        if let Some(checksum) = item.get("checksum").and_then(|c| c.as_i64()) {
+                            let checksum_ok = self
+                                .orderbook_tracker
+                                .verify_checksum("kraken", &snapshot.symbol, checksum as i32)
+                                .await;
+                            if !checksum_ok {
+                                warn!(
+                                    "Kraken orderbook checksum mismatch for {}, dropping cached book and forcing resubscribe",
+                                    snapshot.symbol
+                                );
+                                self.metrics.record_orderbook_checksum_failure("kraken", &snapshot.symbol);
+                                self.orderbooks.write().await.remove(&snapshot.symbol);
+                                self.orderbook_tracker.forget("kraken", &snapshot.symbol).await;
+                                return Err(alphapulse_common::AlphaPulseError::InvalidData(format!(
+                                    "Kraken orderbook checksum mismatch for {}",
+                                    snapshot.symbol
+                                )));
+                            }
+                        }
+
                         // Compute delta if this is an update (not first snapshot)
                         if let Some(delta) = self.orderbook_tracker.compute_delta(&snapshot, &snapshot.symbol).await {
                             // Send delta update via channel
@@ -431,7 +604,36 @@ impl KrakenCollector {
                 }
             }
         }
-        
+
+        Ok(())
+    }
+
+    /// Parse an OKX/Deribit-style funding-rate payload and forward it to
+    /// `tx`, so strategies can track perpetual/swap carry cost alongside L2
+    /// data.
+    async fn handle_funding_rate(&self, msg: serde_json::Value, tx: &mpsc::Sender<FundingRate>) -> Result<()> {
+        if let Some(data_array) = msg.get("data").and_then(|d| d.as_array()) {
+            for item in data_array {
+                if let Some(symbol) = item.get("symbol").and_then(|s| s.as_str()) {
+                    let funding_rate = FundingRate {
+                        symbol: Self::convert_symbol_from_kraken(symbol),
+                        exchange: "kraken".to_string(),
+                        rate: item.get("funding_rate").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                        next_rate: item.get("next_funding_rate").and_then(|v| v.as_f64()),
+                        funding_timestamp: item.get("funding_time").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                        mark_price: item.get("mark_price").and_then(|v| v.as_f64()),
+                        index_price: item.get("index_price").and_then(|v| v.as_f64()),
+                    };
+
+                    self.metrics.record_funding_rate_update("kraken", &funding_rate.symbol);
+
+                    if let Err(e) = tx.send(funding_rate).await {
+                        warn!("Failed to send Kraken funding rate update: {}", e);
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,353 @@
+// Redis Streams reader for trade data - the consumer-group counterpart to
+// `RedisStreamsWriter`.
+use alphapulse_common::{Result, Trade, MetricsCollector};
+use redis::aio::ConnectionManager;
+use redis::streams::{StreamAutoClaimReply, StreamId, StreamPendingReply, StreamReadOptions, StreamReadReply};
+use redis::{AsyncCommands, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+use tokio::time::interval;
+use tracing::{info, warn, error, debug};
+
+/// Reads the `trades:{exchange}:{symbol}` streams `RedisStreamsWriter`
+/// produces via a named consumer group, reconstructing `Trade` values and
+/// forwarding them on an `mpsc::Sender`. Entries are only `XACK`'d after the
+/// send succeeds, so a crash between delivery and ack leaves the entry
+/// pending in the group's PEL for `reclaim_task` (`XAUTOCLAIM`) to hand to
+/// another consumer - an at-least-once pipeline, mirroring the
+/// consumer-group pattern grpc-ingest uses on the read side of its streams.
+pub struct RedisStreamsReader {
+    connection: Arc<RwLock<Option<ConnectionManager>>>,
+    redis_url: String,
+    group: String,
+    consumer: String,
+    streams: Vec<String>,
+    block_timeout: Duration,
+    /// Minimum idle time before `reclaim_task` will `XAUTOCLAIM` an entry
+    /// away from whatever consumer last held it; also doubles as the
+    /// reclaim task's poll interval.
+    claim_min_idle: Duration,
+    metrics: Arc<MetricsCollector>,
+}
+
+impl RedisStreamsReader {
+    pub fn new(
+        redis_url: String,
+        group: impl Into<String>,
+        consumer: impl Into<String>,
+        streams: Vec<String>,
+        block_timeout_ms: u64,
+        claim_min_idle_ms: u64,
+    ) -> Self {
+        Self {
+            connection: Arc::new(RwLock::new(None)),
+            redis_url,
+            group: group.into(),
+            consumer: consumer.into(),
+            streams,
+            block_timeout: Duration::from_millis(block_timeout_ms),
+            claim_min_idle: Duration::from_millis(claim_min_idle_ms),
+            metrics: Arc::new(MetricsCollector::new()),
+        }
+    }
+
+    pub async fn start(&self, tx: mpsc::Sender<Trade>) -> Result<()> {
+        info!(
+            "Starting Redis Streams reader (group={}, consumer={})",
+            self.group, self.consumer
+        );
+
+        self.connect().await?;
+        self.ensure_groups().await?;
+
+        let reclaimer = self.clone();
+        let reclaim_tx = tx.clone();
+        tokio::spawn(async move {
+            reclaimer.reclaim_task(reclaim_tx).await;
+        });
+
+        let lag_reporter = self.clone();
+        tokio::spawn(async move {
+            lag_reporter.lag_report_task().await;
+        });
+
+        loop {
+            if let Err(e) = self.read_once(&tx).await {
+                error!("Stream read failed: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+
+    async fn connect(&self) -> Result<()> {
+        let client = redis::Client::open(self.redis_url.as_str())?;
+        let connection = client.get_connection_manager().await?;
+
+        let mut conn = connection.clone();
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
+
+        *self.connection.write().await = Some(connection);
+        info!("Connected to Redis at {}", self.redis_url);
+
+        Ok(())
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager> {
+        let guard = self.connection.read().await;
+        guard.as_ref().cloned().ok_or_else(|| {
+            alphapulse_common::AlphaPulseError::RedisError(redis::RedisError::from((
+                redis::ErrorKind::IoError,
+                "No Redis connection",
+            )))
+        })
+    }
+
+    /// Creates each stream's consumer group starting from the beginning of
+    /// the stream, tolerating `BUSYGROUP` when the group already exists.
+    async fn ensure_groups(&self) -> Result<()> {
+        let mut conn = self.connection().await?;
+
+        for stream in &self.streams {
+            let result: std::result::Result<(), redis::RedisError> = redis::cmd("XGROUP")
+                .arg("CREATE")
+                .arg(stream)
+                .arg(&self.group)
+                .arg("0")
+                .arg("MKSTREAM")
+                .query_async(&mut conn)
+                .await;
+
+            if let Err(e) = result {
+                if !e.to_string().contains("BUSYGROUP") {
+                    return Err(e.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn read_once(&self, tx: &mpsc::Sender<Trade>) -> Result<()> {
+        let mut conn = self.connection().await?;
+
+        let ids: Vec<&str> = self.streams.iter().map(|_| ">").collect();
+        let options = StreamReadOptions::default()
+            .group(&self.group, &self.consumer)
+            .count(100)
+            .block(self.block_timeout.as_millis() as usize);
+
+        let reply: StreamReadReply = conn.xread_options(&self.streams, &ids, &options).await?;
+
+        for stream_key_entries in reply.keys {
+            let stream_key = stream_key_entries.key;
+            for entry in stream_key_entries.ids {
+                self.handle_entry(&mut conn, &stream_key, &entry, tx).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_entry(
+        &self,
+        conn: &mut ConnectionManager,
+        stream_key: &str,
+        entry: &StreamId,
+        tx: &mpsc::Sender<Trade>,
+    ) {
+        match trade_from_entry(stream_key, entry) {
+            Some(trade) => {
+                if tx.send(trade).await.is_ok() {
+                    let _: std::result::Result<i64, redis::RedisError> =
+                        conn.xack(stream_key, &self.group, &[&entry.id]).await;
+                    self.metrics.record_redis_operation("xack", true);
+                } else {
+                    warn!(
+                        "Trade receiver dropped, leaving entry {} pending on {} for reclaim",
+                        entry.id, stream_key
+                    );
+                }
+            }
+            None => {
+                warn!("Skipping malformed stream entry {} on {}", entry.id, stream_key);
+                let _: std::result::Result<i64, redis::RedisError> =
+                    conn.xack(stream_key, &self.group, &[&entry.id]).await;
+            }
+        }
+    }
+
+    /// Periodically claims entries that have been pending (delivered to a
+    /// consumer but never acked) for longer than `claim_min_idle`, so a
+    /// crashed consumer doesn't strand trades in its PEL forever.
+    async fn reclaim_task(&self, tx: mpsc::Sender<Trade>) {
+        let mut ticker = interval(self.claim_min_idle.max(Duration::from_secs(1)));
+
+        loop {
+            ticker.tick().await;
+
+            for stream in &self.streams {
+                if let Err(e) = self.reclaim_stream(stream, &tx).await {
+                    warn!("Failed to reclaim pending entries on {}: {}", stream, e);
+                }
+            }
+        }
+    }
+
+    async fn reclaim_stream(&self, stream_key: &str, tx: &mpsc::Sender<Trade>) -> Result<()> {
+        let mut conn = self.connection().await?;
+
+        let pending: StreamPendingReply = redis::cmd("XPENDING")
+            .arg(stream_key)
+            .arg(&self.group)
+            .query_async(&mut conn)
+            .await?;
+
+        let stuck = match pending {
+            StreamPendingReply::Empty => 0,
+            StreamPendingReply::Data(data) => data.count,
+        };
+        if stuck == 0 {
+            return Ok(());
+        }
+        debug!("{} pending entries on {}, attempting XAUTOCLAIM", stuck, stream_key);
+
+        let mut cursor = "0-0".to_string();
+        let mut reclaimed = 0usize;
+
+        loop {
+            let claimed: StreamAutoClaimReply = redis::cmd("XAUTOCLAIM")
+                .arg(stream_key)
+                .arg(&self.group)
+                .arg(&self.consumer)
+                .arg(self.claim_min_idle.as_millis() as usize)
+                .arg(&cursor)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut conn)
+                .await?;
+
+            for entry in &claimed.claimed {
+                self.handle_entry(&mut conn, stream_key, entry, tx).await;
+                reclaimed += 1;
+            }
+
+            let done = claimed.cursor == "0-0" || claimed.claimed.is_empty();
+            cursor = claimed.cursor;
+            if done {
+                break;
+            }
+        }
+
+        if reclaimed > 0 {
+            self.metrics.record_stream_reclaimed(stream_key, reclaimed);
+            info!("Reclaimed {} abandoned entries on {}", reclaimed, stream_key);
+        }
+
+        Ok(())
+    }
+
+    /// Periodically records each stream's consumer-group lag (entries the
+    /// group hasn't yet delivered, i.e. the gap to the stream tail).
+    async fn lag_report_task(&self) {
+        let mut ticker = interval(self.block_timeout.max(Duration::from_secs(1)));
+
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.record_lag().await {
+                warn!("Failed to record stream lag: {}", e);
+            }
+        }
+    }
+
+    async fn record_lag(&self) -> Result<()> {
+        let mut conn = self.connection().await?;
+
+        for stream in &self.streams {
+            let groups: Vec<HashMap<String, Value>> = redis::cmd("XINFO")
+                .arg("GROUPS")
+                .arg(stream)
+                .query_async(&mut conn)
+                .await?;
+
+            for group_info in groups {
+                let name = group_info.get("name").and_then(value_as_string);
+                if name.as_deref() != Some(self.group.as_str()) {
+                    continue;
+                }
+
+                // Redis 7+ reports `lag` (entries not yet delivered)
+                // directly; older servers only give us `pending`, which
+                // undercounts lag but is still a useful backpressure signal.
+                let lag = group_info
+                    .get("lag")
+                    .and_then(value_as_i64)
+                    .or_else(|| group_info.get("pending").and_then(value_as_i64))
+                    .unwrap_or(0)
+                    .max(0) as usize;
+
+                self.metrics.record_stream_lag(stream, lag);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Clone for RedisStreamsReader {
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+            redis_url: self.redis_url.clone(),
+            group: self.group.clone(),
+            consumer: self.consumer.clone(),
+            streams: self.streams.clone(),
+            block_timeout: self.block_timeout,
+            claim_min_idle: self.claim_min_idle,
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+/// Reconstructs a `Trade` from a stream entry's fields plus the
+/// `trades:{exchange}:{symbol}` key it was read from - the writer encodes
+/// exchange/symbol in the key rather than duplicating them into every entry.
+fn trade_from_entry(stream_key: &str, entry: &StreamId) -> Option<Trade> {
+    let (exchange, symbol) = parse_stream_key(stream_key)?;
+
+    let mut fields: HashMap<String, String> = HashMap::new();
+    for (key, value) in &entry.map {
+        if let Some(s) = value_as_string(value) {
+            fields.insert(key.clone(), s);
+        }
+    }
+
+    Some(Trade {
+        timestamp: fields.get("timestamp")?.parse().ok()?,
+        price: fields.get("price")?.parse().ok()?,
+        volume: fields.get("volume")?.parse().ok()?,
+        side: fields.get("side").filter(|s| !s.is_empty()).cloned(),
+        trade_id: fields.get("trade_id").filter(|s| !s.is_empty()).cloned(),
+        symbol,
+        exchange,
+    })
+}
+
+fn parse_stream_key(stream_key: &str) -> Option<(String, String)> {
+    let mut parts = stream_key.splitn(3, ':');
+    if parts.next()? != "trades" {
+        return None;
+    }
+    let exchange = parts.next()?.to_string();
+    let symbol = parts.next()?.to_string();
+    Some((exchange, symbol))
+}
+
+fn value_as_string(value: &Value) -> Option<String> {
+    redis::from_redis_value::<String>(value).ok()
+}
+
+fn value_as_i64(value: &Value) -> Option<i64> {
+    redis::from_redis_value::<i64>(value).ok()
+}
@@ -0,0 +1,288 @@
+// Compact, fixed-width encodings for the fields `Trade` otherwise stores as heap
+// `String`s: `Exchange` and `Side` as a single byte each, and ticker symbols
+// interned into a `u32` id through a shared registry. Used by anything that embeds
+// a trade in a binary record or wants a leaner JSON representation than `Trade`'s
+// own `String` fields.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::num::{NonZeroU32, NonZeroU8};
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("unknown exchange code: {0}")]
+    UnknownExchange(u8),
+    #[error("unknown side code: {0}")]
+    UnknownSide(u8),
+}
+
+/// The venues collectors currently publish trades for. Code `0` is reserved as an
+/// invalid/sentinel value so the on-wire field can be a `NonZeroU8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Exchange {
+    BinanceUs = 1,
+    Coinbase = 2,
+    Kraken = 3,
+}
+
+impl Exchange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Exchange::BinanceUs => "binance_us",
+            Exchange::Coinbase => "coinbase",
+            Exchange::Kraken => "kraken",
+        }
+    }
+}
+
+impl From<&Exchange> for u8 {
+    fn from(value: &Exchange) -> Self {
+        *value as u8
+    }
+}
+
+impl TryFrom<u8> for Exchange {
+    type Error = DecodeError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            1 => Ok(Exchange::BinanceUs),
+            2 => Ok(Exchange::Coinbase),
+            3 => Ok(Exchange::Kraken),
+            other => Err(DecodeError::UnknownExchange(other)),
+        }
+    }
+}
+
+impl fmt::Display for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Exchange {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binance_us" => Ok(Exchange::BinanceUs),
+            "coinbase" => Ok(Exchange::Coinbase),
+            "kraken" => Ok(Exchange::Kraken),
+            _ => Err(DecodeError::UnknownExchange(0)),
+        }
+    }
+}
+
+/// Trade side. Code `0` is reserved as an invalid/sentinel value so the on-wire
+/// field can be a `NonZeroU8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 1,
+    Sell = 2,
+}
+
+impl From<&Side> for u8 {
+    fn from(value: &Side) -> Self {
+        *value as u8
+    }
+}
+
+impl TryFrom<u8> for Side {
+    type Error = DecodeError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            1 => Ok(Side::Buy),
+            2 => Ok(Side::Sell),
+            other => Err(DecodeError::UnknownSide(other)),
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        })
+    }
+}
+
+impl FromStr for Side {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "buy" | "BUY" => Ok(Side::Buy),
+            "sell" | "SELL" => Ok(Side::Sell),
+            _ => Err(DecodeError::UnknownSide(0)),
+        }
+    }
+}
+
+/// Serde helper for `#[serde(with = "u8_code")]`, so `Exchange`/`Side` use the same
+/// single-byte representation in JSON that they do in a binary record.
+pub mod u8_code {
+    use super::DecodeError;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::convert::TryFrom;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        for<'a> &'a T: Into<u8>,
+        S: Serializer,
+    {
+        serializer.serialize_u8(value.into())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<u8, Error = DecodeError>,
+        D: Deserializer<'de>,
+    {
+        let code = u8::deserialize(deserializer)?;
+        T::try_from(code).map_err(serde::de::Error::custom)
+    }
+}
+
+/// An interned ticker symbol, e.g. `"BTC/USDT"`. Wraps a `NonZeroU32` so `id` `0`
+/// stays an invalid/sentinel value and `Option<TickerId>` is the same size as a
+/// bare `u32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TickerId(NonZeroU32);
+
+impl TickerId {
+    pub fn get(self) -> u32 {
+        self.0.get()
+    }
+}
+
+impl fmt::Display for TickerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match ticker_registry().resolve(*self) {
+            Some(symbol) => f.write_str(&symbol),
+            None => write!(f, "<unknown ticker {}>", self.0),
+        }
+    }
+}
+
+impl FromStr for TickerId {
+    type Err = std::convert::Infallible;
+
+    /// Interns `s` in the global ticker registry, assigning it a fresh id the
+    /// first time it's seen. Always succeeds.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ticker_registry().intern(s))
+    }
+}
+
+/// Bidirectional symbol <-> id mapping. Ids are assigned sequentially starting at
+/// `1`, so they can be stored as `NonZeroU32`.
+pub struct TickerRegistry {
+    by_id: RwLock<Vec<String>>,
+    by_symbol: RwLock<HashMap<String, TickerId>>,
+}
+
+impl TickerRegistry {
+    pub fn new() -> Self {
+        Self {
+            by_id: RwLock::new(vec![String::new()]), // index 0 is the reserved sentinel
+            by_symbol: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Return the id for `symbol`, interning it if this is the first time it's
+    /// been seen.
+    pub fn intern(&self, symbol: &str) -> TickerId {
+        if let Some(id) = self.by_symbol.read().unwrap().get(symbol) {
+            return *id;
+        }
+
+        let mut by_id = self.by_id.write().unwrap();
+        let mut by_symbol = self.by_symbol.write().unwrap();
+        // Re-check under the write lock in case another thread interned it first.
+        if let Some(id) = by_symbol.get(symbol) {
+            return *id;
+        }
+
+        by_id.push(symbol.to_string());
+        let id = TickerId(NonZeroU32::new((by_id.len() - 1) as u32).expect("index 0 is reserved"));
+        by_symbol.insert(symbol.to_string(), id);
+        id
+    }
+
+    /// Look up the symbol `id` was interned from.
+    pub fn resolve(&self, id: TickerId) -> Option<String> {
+        self.by_id.read().unwrap().get(id.get() as usize).cloned()
+    }
+}
+
+impl Default for TickerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TICKER_REGISTRY: OnceLock<TickerRegistry> = OnceLock::new();
+
+/// The process-wide ticker registry used by `TickerId`'s `Display`/`FromStr` impls.
+pub fn ticker_registry() -> &'static TickerRegistry {
+    TICKER_REGISTRY.get_or_init(TickerRegistry::new)
+}
+
+/// A `Trade` compacted to a fixed ~24-byte record: `Exchange`/`Side` as a byte each
+/// and the symbol as an interned `TickerId` instead of two heap `String`s.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CompactTrade {
+    pub timestamp_ns: u64,
+    pub symbol: u32, // TickerId::get(); ids are resolved against the global registry
+    pub price: f32,
+    pub volume: f32,
+    #[serde(with = "u8_code")]
+    pub exchange: Exchange,
+    #[serde(with = "u8_code")]
+    pub side: Side,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_u8_round_trip() {
+        for exchange in [Exchange::BinanceUs, Exchange::Coinbase, Exchange::Kraken] {
+            let code = u8::from(&exchange);
+            assert_eq!(Exchange::try_from(code).unwrap(), exchange);
+        }
+    }
+
+    #[test]
+    fn test_unknown_exchange_code_is_a_decode_error() {
+        assert_eq!(Exchange::try_from(0), Err(DecodeError::UnknownExchange(0)));
+        assert_eq!(Exchange::try_from(99), Err(DecodeError::UnknownExchange(99)));
+    }
+
+    #[test]
+    fn test_side_display_from_str_round_trip() {
+        assert_eq!(Side::from_str("buy").unwrap(), Side::Buy);
+        assert_eq!(Side::Buy.to_string(), "buy");
+        assert_eq!(Side::from_str("sell").unwrap(), Side::Sell);
+        assert_eq!(Side::Sell.to_string(), "sell");
+    }
+
+    #[test]
+    fn test_ticker_id_interning_round_trips_through_resolve() {
+        let registry = TickerRegistry::new();
+        let id = registry.intern("BTC/USDT");
+        assert_eq!(registry.resolve(id).as_deref(), Some("BTC/USDT"));
+        // Interning the same symbol again returns the same id.
+        assert_eq!(registry.intern("BTC/USDT"), id);
+    }
+}
@@ -11,13 +11,21 @@ pub mod shared_memory;
 pub mod shared_memory_v2;
 pub mod shared_memory_registry;
 pub mod event_driven_shm;
+pub mod trade_log;
+pub mod compact;
+pub mod ohlcv;
+pub mod liveness;
 
 pub use types::*;
 pub use error::*;
 pub use metrics::MetricsCollector;
 pub use config::{Config, SymbolConverter};
-pub use orderbook_delta::{OrderBookTracker, OrderBookSnapshot, OrderBookDelta};
+pub use orderbook_delta::{OrderBookTracker, OrderBookSnapshot, OrderBookDelta, SequenceGap};
 pub use retry::{RetryPolicy, CircuitBreaker};
 pub use shared_memory::{SharedMemoryWriter, SharedMemoryReader, SharedTrade};
 pub use shared_memory_registry::{SharedMemoryRegistry, FeedMetadata, FeedType, create_feed_metadata, update_feed_heartbeat};
-pub use event_driven_shm::{EventDrivenTradeWriter, EventDrivenTradeReader, AtomicReaderRegistry};
\ No newline at end of file
+pub use event_driven_shm::{EventDrivenTradeWriter, EventDrivenTradeReader, AtomicReaderRegistry};
+pub use trade_log::{TradeLogWriter, TradeLogReader, tee_to_trade_log};
+pub use compact::{Exchange, Side, TickerId, TickerRegistry, CompactTrade, DecodeError, ticker_registry};
+pub use ohlcv::{WeightedMeanWindow, BucketWidth, run_live as run_ohlcv_aggregation, aggregate_trade_log};
+pub use liveness::{CollectorLiveness, ChainLivenessCounters, ChainHealth, DEFAULT_STALE_THRESHOLD};
\ No newline at end of file
@@ -0,0 +1,111 @@
+//! Per-chain collector liveness tracking, so a health endpoint can tell a
+//! silently-dead subscription (process alive, no events flowing) from an
+//! actually-healthy one.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// A collector that hasn't delivered a message within this long is considered
+/// stale.
+pub const DEFAULT_STALE_THRESHOLD: Duration = Duration::from_secs(60);
+
+fn now_unix_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// Running liveness counters for a single chain's collector loop.
+#[derive(Debug, Default)]
+pub struct ChainLivenessCounters {
+    last_event_unix_ms: AtomicI64,
+    messages_received: AtomicU64,
+    timeouts: AtomicU64,
+}
+
+impl ChainLivenessCounters {
+    /// Record that an event just arrived.
+    pub fn record_message(&self) {
+        self.last_event_unix_ms.store(now_unix_ms(), Ordering::Relaxed);
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record that the collector loop observed a timeout waiting for the next
+    /// event.
+    pub fn record_timeout(&self) {
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn seconds_since_last_event(&self) -> Option<i64> {
+        let last = self.last_event_unix_ms.load(Ordering::Relaxed);
+        if last == 0 {
+            return None;
+        }
+        Some(((now_unix_ms() - last).max(0)) / 1000)
+    }
+}
+
+/// A point-in-time liveness snapshot for one chain, ready to serialize into a
+/// health response.
+#[derive(Debug, Clone)]
+pub struct ChainHealth {
+    pub chain: String,
+    pub messages_received: u64,
+    pub timeouts: u64,
+    pub seconds_since_last_event: Option<i64>,
+    pub stale: bool,
+}
+
+/// Shared registry of per-chain liveness counters, held in `AppState` and
+/// updated by each collector loop as events arrive.
+#[derive(Default)]
+pub struct CollectorLiveness {
+    chains: RwLock<HashMap<String, Arc<ChainLivenessCounters>>>,
+}
+
+impl CollectorLiveness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get (or create) the counters for `chain`, so a collector loop can hold
+    /// onto a cheap `Arc` and call `record_message`/`record_timeout` directly.
+    pub fn chain(&self, chain: &str) -> Arc<ChainLivenessCounters> {
+        if let Some(counters) = self.chains.read().unwrap().get(chain) {
+            return counters.clone();
+        }
+        let mut chains = self.chains.write().unwrap();
+        chains
+            .entry(chain.to_string())
+            .or_insert_with(|| Arc::new(ChainLivenessCounters::default()))
+            .clone()
+    }
+
+    /// Snapshot every registered chain's liveness, marking a chain `stale` if
+    /// it has never delivered a message or hasn't within `stale_after`.
+    pub fn snapshot(&self, stale_after: Duration) -> Vec<ChainHealth> {
+        let stale_after_secs = stale_after.as_secs() as i64;
+        self.chains
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(chain, counters)| {
+                let seconds_since_last_event = counters.seconds_since_last_event();
+                let stale = match seconds_since_last_event {
+                    Some(seconds) => seconds >= stale_after_secs,
+                    None => true,
+                };
+                ChainHealth {
+                    chain: chain.clone(),
+                    messages_received: counters.messages_received.load(Ordering::Relaxed),
+                    timeouts: counters.timeouts.load(Ordering::Relaxed),
+                    seconds_since_last_event,
+                    stale,
+                }
+            })
+            .collect()
+    }
+}
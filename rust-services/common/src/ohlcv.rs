@@ -0,0 +1,271 @@
+// Streaming OHLCV + volume-weighted mean price bar aggregation over a `Trade`
+// stream. Sits between a `MarketDataCollector` and downstream consumers so they
+// get time-bucketed bars instead of rolling their own candles from raw trades.
+
+use crate::trade_log::TradeLogReader;
+use crate::{OHLCVBar, Result, Trade};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// A bucket width in nanoseconds, with a few common presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BucketWidth(u64);
+
+impl BucketWidth {
+    pub const SECOND: BucketWidth = BucketWidth(1_000_000_000);
+    pub const MINUTE: BucketWidth = BucketWidth(60 * 1_000_000_000);
+    pub const HOUR: BucketWidth = BucketWidth(60 * 60 * 1_000_000_000);
+
+    pub fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    pub fn as_nanos(self) -> u64 {
+        self.0
+    }
+}
+
+/// Running OHLCV + volume-weighted price accumulator for one `(exchange, symbol)`
+/// bucket.
+struct Accumulator {
+    bucket_index: u64,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    price_volume_sum: f64,
+}
+
+impl Accumulator {
+    fn start(bucket_index: u64, price: f64, volume: f64) -> Self {
+        Self {
+            bucket_index,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+            price_volume_sum: price * volume,
+        }
+    }
+
+    fn fold(&mut self, price: f64, volume: f64) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += volume;
+        self.price_volume_sum += price * volume;
+    }
+
+    fn into_bar(self, exchange: String, symbol: String, bucket_ns: u64) -> OHLCVBar {
+        let vwap = if self.volume > 0.0 {
+            self.price_volume_sum / self.volume
+        } else {
+            self.close
+        };
+
+        OHLCVBar {
+            timestamp: (self.bucket_index * bucket_ns) as f64 / 1_000_000_000.0,
+            symbol,
+            exchange,
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            vwap,
+        }
+    }
+}
+
+/// Aggregates a `Trade` stream into time-bucketed OHLCV + VWAP bars, one active
+/// bucket per `(exchange, symbol)`. A bar is emitted whenever an incoming trade's
+/// bucket (`floor(timestamp / bucket_width)`) is later than the bucket currently
+/// open for that key, carrying the completed bucket's `close` forward as the next
+/// bucket's `open`.
+///
+/// Trades that arrive slightly out of order (timestamp behind the currently open
+/// bucket) are folded into the open bucket instead of reopening one that's already
+/// been flushed, provided their bucket is at most `grace_ns / bucket_width` whole
+/// buckets behind it; `grace_ns` is rounded down, so a grace shorter than one
+/// bucket folds nothing. Later trades outside that window are dropped.
+pub struct WeightedMeanWindow {
+    bucket_ns: u64,
+    grace_buckets: u64,
+    buckets: HashMap<(String, String), Accumulator>,
+}
+
+impl WeightedMeanWindow {
+    pub fn new(bucket_width: BucketWidth) -> Self {
+        Self::with_grace_period(bucket_width, 0)
+    }
+
+    pub fn with_grace_period(bucket_width: BucketWidth, grace_ns: u64) -> Self {
+        let bucket_ns = bucket_width.as_nanos().max(1);
+        Self {
+            bucket_ns,
+            grace_buckets: grace_ns / bucket_ns,
+            buckets: HashMap::new(),
+        }
+    }
+
+    fn timestamp_ns(trade: &Trade) -> u64 {
+        (trade.timestamp * 1_000_000_000.0) as u64
+    }
+
+    /// Fold one trade into the window. Returns a completed bar if this trade
+    /// crossed its key's bucket boundary.
+    pub fn ingest(&mut self, trade: &Trade) -> Option<OHLCVBar> {
+        let key = (trade.exchange.clone(), trade.symbol.clone());
+        let bucket_index = Self::timestamp_ns(trade) / self.bucket_ns;
+        let current_bucket = self.buckets.get(&key).map(|acc| acc.bucket_index);
+
+        match current_bucket {
+            None => {
+                self.buckets
+                    .insert(key, Accumulator::start(bucket_index, trade.price, trade.volume));
+                None
+            }
+            Some(current) if bucket_index == current => {
+                self.buckets.get_mut(&key).unwrap().fold(trade.price, trade.volume);
+                None
+            }
+            Some(current) if bucket_index < current => {
+                if current - bucket_index <= self.grace_buckets {
+                    self.buckets.get_mut(&key).unwrap().fold(trade.price, trade.volume);
+                }
+                None
+            }
+            Some(_) => {
+                let completed = self.buckets.remove(&key).unwrap();
+                let carry_open = completed.close;
+                let bar = completed.into_bar(key.0.clone(), key.1.clone(), self.bucket_ns);
+
+                let mut next = Accumulator::start(bucket_index, trade.price, trade.volume);
+                next.open = carry_open;
+                self.buckets.insert(key, next);
+
+                Some(bar)
+            }
+        }
+    }
+
+    /// Flush every still-open bucket as a completed bar, e.g. at shutdown so the
+    /// last partial bucket per key isn't silently dropped.
+    pub fn flush_all(&mut self) -> Vec<OHLCVBar> {
+        let bucket_ns = self.bucket_ns;
+        self.buckets
+            .drain()
+            .map(|((exchange, symbol), acc)| acc.into_bar(exchange, symbol, bucket_ns))
+            .collect()
+    }
+}
+
+/// Aggregate a live `Trade` stream into bars, forwarding each completed bar to the
+/// returned receiver as soon as its bucket closes.
+pub async fn run_live(
+    mut trades: mpsc::Receiver<Trade>,
+    bucket_width: BucketWidth,
+    grace_ns: u64,
+) -> mpsc::Receiver<OHLCVBar> {
+    let (tx, rx) = mpsc::channel(1024);
+
+    tokio::spawn(async move {
+        let mut window = WeightedMeanWindow::with_grace_period(bucket_width, grace_ns);
+
+        while let Some(trade) = trades.recv().await {
+            if let Some(bar) = window.ingest(&trade) {
+                if tx.send(bar).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        for bar in window.flush_all() {
+            if tx.send(bar).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    rx
+}
+
+/// Batch mode: replay an entire archived trade log (see [`crate::trade_log`]) and
+/// aggregate it into bars in one pass, for backtesting against historical data
+/// instead of a live stream.
+pub async fn aggregate_trade_log(path: &str, bucket_width: BucketWidth, grace_ns: u64) -> Result<Vec<OHLCVBar>> {
+    let reader = TradeLogReader::open(path)?;
+    let (tx, mut rx) = mpsc::channel(1024);
+
+    let replay = tokio::spawn(async move {
+        let _ = reader.replay_all(&tx).await;
+    });
+
+    let mut window = WeightedMeanWindow::with_grace_period(bucket_width, grace_ns);
+    let mut bars = Vec::new();
+
+    while let Some(trade) = rx.recv().await {
+        if let Some(bar) = window.ingest(&trade) {
+            bars.push(bar);
+        }
+    }
+
+    let _ = replay.await;
+    bars.extend(window.flush_all());
+
+    Ok(bars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(timestamp: f64, price: f64, volume: f64) -> Trade {
+        Trade {
+            timestamp,
+            price,
+            volume,
+            side: None,
+            trade_id: None,
+            symbol: "BTC/USDT".to_string(),
+            exchange: "binance_us".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_bar_emitted_on_bucket_crossing_with_carried_open() {
+        let mut window = WeightedMeanWindow::new(BucketWidth::SECOND);
+
+        assert!(window.ingest(&trade(0.1, 100.0, 1.0)).is_none());
+        assert!(window.ingest(&trade(0.5, 102.0, 1.0)).is_none());
+
+        let bar = window.ingest(&trade(1.2, 101.0, 2.0)).unwrap();
+        assert_eq!(bar.open, 100.0);
+        assert_eq!(bar.high, 102.0);
+        assert_eq!(bar.low, 100.0);
+        assert_eq!(bar.close, 102.0);
+        assert_eq!(bar.volume, 2.0);
+        assert!((bar.vwap - 101.0).abs() < 1e-9);
+
+        // The next bucket's open carries the completed bucket's close forward.
+        let bars = window.flush_all();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, 102.0);
+    }
+
+    #[test]
+    fn test_out_of_order_trade_within_grace_folds_into_open_bucket() {
+        let mut window = WeightedMeanWindow::with_grace_period(BucketWidth::SECOND, 1_000_000_000);
+
+        assert!(window.ingest(&trade(1.1, 100.0, 1.0)).is_none());
+        // Arrives one bucket "behind" but within the grace window.
+        assert!(window.ingest(&trade(0.9, 90.0, 1.0)).is_none());
+
+        let bars = window.flush_all();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, 2.0);
+        assert_eq!(bars[0].low, 90.0);
+    }
+}
@@ -40,9 +40,39 @@ pub enum DeltaAction {
     Remove,
 }
 
+/// Returned by [`OrderBookTracker::apply_incremental`] when an exchange's
+/// `prev_change_id`/`prev_version` doesn't chain onto the last change this
+/// tracker applied (e.g. Deribit's `change_id`, OKX depth updates). The local
+/// book can no longer be trusted and the caller must re-request a snapshot.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("sequence gap for {symbol}: expected prev_version {expected_prev}, got {got_prev}")]
+pub struct SequenceGap {
+    pub symbol: String,
+    pub expected_prev: u64,
+    pub got_prev: u64,
+}
+
+/// Number of top-of-book levels an OKX/Deribit-style checksum is computed
+/// over.
+const CHECKSUM_DEPTH: usize = 25;
+
+/// A book level's price/size exactly as the exchange sent it on the wire,
+/// kept alongside the reparsed `f64` in [`OrderBookSnapshot`] so a checksum
+/// can be computed over the same digits the exchange used. Reparsing a
+/// string like `"50000.10000"` into `f64` and calling `to_string()` on it
+/// again yields `"50000.1"` - the minimal-digit representation, not the
+/// exchange's fixed-decimal wire format - which would never match a
+/// real exchange-supplied checksum.
+#[derive(Debug, Clone, Default)]
+pub struct RawChecksumLevels {
+    pub bids: Vec<(String, String)>,
+    pub asks: Vec<(String, String)>,
+}
+
 pub struct OrderBookTracker {
     snapshots: Arc<RwLock<HashMap<String, OrderBookSnapshot>>>,
     version_counter: Arc<RwLock<HashMap<String, u64>>>,
+    checksum_levels: Arc<RwLock<HashMap<String, RawChecksumLevels>>>,
     max_depth: usize,
 }
 
@@ -51,9 +81,30 @@ impl OrderBookTracker {
         Self {
             snapshots: Arc::new(RwLock::new(HashMap::new())),
             version_counter: Arc::new(RwLock::new(HashMap::new())),
+            checksum_levels: Arc::new(RwLock::new(HashMap::new())),
             max_depth,
         }
     }
+
+    /// Record the exchange's original (pre-`f64`-parse) price/size strings
+    /// for `exchange`/`symbol`'s top-of-book levels, so a subsequent
+    /// [`Self::verify_checksum`] call hashes the exchange's own wire format
+    /// instead of a reparsed-and-reformatted float. Call this alongside
+    /// [`Self::update_snapshot`] for exchanges (e.g. Kraken) that stamp
+    /// messages with a checksum.
+    pub async fn update_checksum_levels(
+        &self,
+        symbol: &str,
+        exchange: &str,
+        bids: Vec<(String, String)>,
+        asks: Vec<(String, String)>,
+    ) {
+        let key = format!("{}:{}", exchange, symbol);
+        self.checksum_levels
+            .write()
+            .await
+            .insert(key, RawChecksumLevels { bids, asks });
+    }
     
     pub async fn update_snapshot(&self, symbol: &str, exchange: &str, snapshot: OrderBookSnapshot) {
         let key = format!("{}:{}", exchange, symbol);
@@ -182,4 +233,133 @@ impl OrderBookTracker {
         
         None
     }
+
+    /// Drop the cached baseline for `exchange`/`symbol`, so the next message
+    /// can't be diffed against a (possibly corrupt) prior book and instead
+    /// requires a fresh snapshot before deltas resume.
+    pub async fn forget(&self, exchange: &str, symbol: &str) {
+        let key = format!("{}:{}", exchange, symbol);
+        self.snapshots.write().await.remove(&key);
+        self.version_counter.write().await.remove(&key);
+        self.checksum_levels.write().await.remove(&key);
+    }
+
+    /// Verify an OKX/Deribit-style CRC32 checksum for `exchange`/`symbol`'s
+    /// currently tracked book.
+    ///
+    /// Interleaves the top [`CHECKSUM_DEPTH`] bid/ask levels as
+    /// `bidPrice:bidSize:askPrice:askSize:...` (a side is skipped at a given
+    /// depth once it runs out of levels), CRC32s the resulting string, and
+    /// compares it - reinterpreted as a signed `i32` the way the exchange
+    /// encodes it - against `expected`. Returns `false` if the symbol isn't
+    /// tracked yet, which callers should treat the same as a mismatch.
+    ///
+    /// Uses the raw wire strings recorded via [`Self::update_checksum_levels`]
+    /// when available, since a real exchange checksum is computed over its
+    /// own fixed-decimal price/size format, not over `f64::to_string()` of a
+    /// reparsed float (which drops trailing/insignificant-looking digits and
+    /// would never match). Falls back to the reparsed floats only for
+    /// callers that haven't recorded raw levels.
+    pub async fn verify_checksum(&self, exchange: &str, symbol: &str, expected: i32) -> bool {
+        let key = format!("{}:{}", exchange, symbol);
+        let snapshots = self.snapshots.read().await;
+        let Some(book) = snapshots.get(&key) else {
+            return false;
+        };
+
+        let checksum_levels = self.checksum_levels.read().await;
+        let raw = checksum_levels.get(&key);
+
+        let mut parts = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        for depth in 0..CHECKSUM_DEPTH {
+            if let Some((bid_price, bid_size)) = raw.and_then(|r| r.bids.get(depth)) {
+                parts.push(bid_price.clone());
+                parts.push(bid_size.clone());
+            } else if let Some(bid) = book.bids.get(depth) {
+                parts.push(bid[0].to_string());
+                parts.push(bid[1].to_string());
+            }
+            if let Some((ask_price, ask_size)) = raw.and_then(|r| r.asks.get(depth)) {
+                parts.push(ask_price.clone());
+                parts.push(ask_size.clone());
+            } else if let Some(ask) = book.asks.get(depth) {
+                parts.push(ask[0].to_string());
+                parts.push(ask[1].to_string());
+            }
+        }
+
+        let canonical = parts.join(":");
+        (crc32fast::hash(canonical.as_bytes()) as i32) == expected
+    }
+
+    /// Apply a true incremental update (as opposed to diffing two full
+    /// snapshots) for exchanges that stream changes directly, such as
+    /// Deribit's `type: "change"` (`change_id`/`prev_change_id`) or OKX depth
+    /// updates.
+    ///
+    /// `prev_version` must equal the version this tracker last applied for
+    /// `exchange`/`symbol`, or the sequence has gapped and this returns
+    /// [`SequenceGap`] without mutating the local book - the caller should
+    /// discard it and re-request a snapshot. A change with zero size deletes
+    /// that price level; otherwise it replaces the level in place.
+    pub async fn apply_incremental(
+        &self,
+        symbol: &str,
+        exchange: &str,
+        prev_version: u64,
+        version: u64,
+        bid_changes: Vec<PriceLevel>,
+        ask_changes: Vec<PriceLevel>,
+    ) -> Result<Option<OrderBookDelta>, SequenceGap> {
+        let key = format!("{}:{}", exchange, symbol);
+        let mut versions = self.version_counter.write().await;
+        let last_applied = *versions.get(&key).unwrap_or(&0);
+
+        if last_applied != prev_version {
+            return Err(SequenceGap {
+                symbol: symbol.to_string(),
+                expected_prev: last_applied,
+                got_prev: prev_version,
+            });
+        }
+
+        let mut snapshots = self.snapshots.write().await;
+        let Some(book) = snapshots.get_mut(&key) else {
+            return Err(SequenceGap {
+                symbol: symbol.to_string(),
+                expected_prev: last_applied,
+                got_prev: prev_version,
+            });
+        };
+
+        apply_changes_in_place(&mut book.bids, &bid_changes);
+        apply_changes_in_place(&mut book.asks, &ask_changes);
+        book.version = version;
+        versions.insert(key, version);
+
+        if bid_changes.is_empty() && ask_changes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(OrderBookDelta {
+            symbol: symbol.to_string(),
+            exchange: exchange.to_string(),
+            version,
+            prev_version,
+            timestamp: book.timestamp,
+            bid_changes,
+            ask_changes,
+        }))
+    }
+}
+
+/// Apply in-place changes to one side of a book: a zero-size change removes
+/// the level at that price, otherwise it replaces (or adds) it.
+fn apply_changes_in_place(levels: &mut Vec<[f64; 2]>, changes: &[PriceLevel]) {
+    for change in changes {
+        levels.retain(|level| level[0] != change.price);
+        if change.volume > 0.0 {
+            levels.push([change.price, change.volume]);
+        }
+    }
 }
\ No newline at end of file
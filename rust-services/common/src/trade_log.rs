@@ -0,0 +1,231 @@
+// Append-only binary trade archive, with memory-mapped zero-copy replay.
+//
+// Unlike shared_memory's ring buffers, this log never wraps: every `Trade` a
+// collector sees is appended to disk as a fixed-size `SharedTrade` record, so the
+// file can be indexed by `offset = i * SharedTrade::SIZE` and a time range located
+// by binary search over the timestamp column without parsing a single record.
+
+use crate::shared_memory::SharedTrade;
+use crate::{AlphaPulseError, Result, Trade};
+use memmap2::{Mmap, MmapOptions};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+impl SharedTrade {
+    /// Build a log record from a `Trade`, truncating `timestamp` to nanoseconds and
+    /// defaulting an unset `side`/`trade_id` the same way the ring-buffer writers do.
+    pub fn from_trade(trade: &Trade) -> Self {
+        let is_buy = !matches!(trade.side.as_deref(), Some("sell") | Some("SELL") | Some("s"));
+        Self::new(
+            (trade.timestamp * 1_000_000_000.0) as u64,
+            &trade.symbol,
+            &trade.exchange,
+            trade.price,
+            trade.volume,
+            is_buy,
+            trade.trade_id.as_deref().unwrap_or(""),
+        )
+    }
+
+    /// Reconstruct the `Trade` this record was built from.
+    pub fn to_trade(&self) -> Trade {
+        let trade_id = String::from_utf8_lossy(&self.trade_id)
+            .trim_end_matches('\0')
+            .to_string();
+        Trade {
+            timestamp: self.timestamp_ns as f64 / 1_000_000_000.0,
+            price: self.price,
+            volume: self.volume,
+            side: Some(if self.side == 0 { "buy" } else { "sell" }.to_string()),
+            trade_id: if trade_id.is_empty() { None } else { Some(trade_id) },
+            symbol: self.symbol_str(),
+            exchange: self.exchange_str(),
+        }
+    }
+}
+
+/// Appends `Trade`s to a growing, densely-packed archive file of `SharedTrade`
+/// records. Records must be appended in non-decreasing timestamp order for
+/// `TradeLogReader`'s binary search to locate time ranges correctly.
+pub struct TradeLogWriter {
+    writer: BufWriter<File>,
+    record_count: u64,
+}
+
+impl TradeLogWriter {
+    /// Open `path` for appending, creating it (and its parent directory) if needed.
+    /// Existing records are preserved and counted.
+    pub fn create(path: &str) -> Result<Self> {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = OpenOptions::new().create(true).append(true).read(true).open(path)?;
+        let len = file.metadata()?.len();
+        if len % SharedTrade::SIZE as u64 != 0 {
+            return Err(AlphaPulseError::InvalidMemoryLayout {
+                expected: SharedTrade::SIZE,
+                actual: (len % SharedTrade::SIZE as u64) as usize,
+            });
+        }
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+            record_count: len / SharedTrade::SIZE as u64,
+        })
+    }
+
+    /// Append one trade, converting it to a fixed-size record first.
+    pub fn append(&mut self, trade: &Trade) -> Result<()> {
+        let record = SharedTrade::from_trade(trade);
+        let bytes = unsafe {
+            std::slice::from_raw_parts(&record as *const SharedTrade as *const u8, SharedTrade::SIZE)
+        };
+        self.writer.write_all(bytes)?;
+        self.record_count += 1;
+        Ok(())
+    }
+
+    /// Flush buffered writes to disk. Callers that append in a tight loop should
+    /// call this periodically rather than per-record.
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+}
+
+/// Tees a live `Trade` stream: every trade received from `rx` is appended to the
+/// archive before being forwarded unchanged to `tx`, so collectors that already
+/// forward trades into an mpsc channel gain archival for free by routing through
+/// this instead of sending to `tx` directly.
+pub async fn tee_to_trade_log(mut rx: mpsc::Receiver<Trade>, tx: mpsc::Sender<Trade>, mut writer: TradeLogWriter) {
+    while let Some(trade) = rx.recv().await {
+        if let Err(e) = writer.append(&trade) {
+            warn!("Failed to append trade to log: {}", e);
+        }
+        if tx.send(trade).await.is_err() {
+            break;
+        }
+    }
+    if let Err(e) = writer.flush() {
+        warn!("Failed to flush trade log on shutdown: {}", e);
+    }
+}
+
+/// Zero-copy, memory-mapped reader over a `TradeLogWriter` archive file, for
+/// historical replay.
+pub struct TradeLogReader {
+    mmap: Mmap,
+    record_count: usize,
+}
+
+impl TradeLogReader {
+    pub fn open(path: &str) -> Result<Self> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len() as usize;
+        if len % SharedTrade::SIZE != 0 {
+            return Err(AlphaPulseError::InvalidMemoryLayout {
+                expected: SharedTrade::SIZE,
+                actual: len % SharedTrade::SIZE,
+            });
+        }
+
+        let mmap = unsafe {
+            MmapOptions::new()
+                .map(&file)
+                .map_err(|e| AlphaPulseError::MemoryMappingError(e.to_string()))?
+        };
+
+        Ok(Self {
+            mmap,
+            record_count: len / SharedTrade::SIZE,
+        })
+    }
+
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    fn record_at(&self, index: usize) -> &SharedTrade {
+        let offset = index * SharedTrade::SIZE;
+        unsafe { &*(self.mmap.as_ptr().add(offset) as *const SharedTrade) }
+    }
+
+    /// Index of the first record with `timestamp_ns >= from_ns`, via binary search
+    /// over the timestamp column. Assumes records are stored in non-decreasing
+    /// timestamp order; `TradeLogWriter::append` writes records as given and does
+    /// not check this.
+    fn lower_bound(&self, from_ns: u64) -> usize {
+        let mut lo = 0usize;
+        let mut hi = self.record_count;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.record_at(mid).timestamp_ns < from_ns {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Replay every record with `from_ns <= timestamp_ns < to_ns` into `tx`, in
+    /// order, without reading any record outside that range. Returns the number of
+    /// records sent.
+    pub async fn replay_range(&self, from_ns: u64, to_ns: u64, tx: &mpsc::Sender<Trade>) -> Result<u64> {
+        let mut sent = 0u64;
+        for i in self.lower_bound(from_ns)..self.record_count {
+            let record = self.record_at(i);
+            if record.timestamp_ns >= to_ns {
+                break;
+            }
+            if tx.send(record.to_trade()).await.is_err() {
+                break;
+            }
+            sent += 1;
+        }
+        Ok(sent)
+    }
+
+    /// Replay the entire archive into `tx`.
+    pub async fn replay_all(&self, tx: &mpsc::Sender<Trade>) -> Result<u64> {
+        self.replay_range(0, u64::MAX, tx).await
+    }
+
+    /// Drop the whole OS page cache (not just one file's pages) so a subsequent replay measures true
+    /// disk-bound throughput instead of a page-cache-warm artifact. Requires root
+    /// (writes to `/proc/sys/vm/drop_caches`) and is Linux-only; intended for manual
+    /// benchmarking, not production paths.
+    pub fn drop_page_cache() -> Result<()> {
+        std::fs::write("/proc/sys/vm/drop_caches", b"3\n")
+            .map_err(|e| AlphaPulseError::MemoryMappingError(format!("failed to drop page cache: {}", e)))
+    }
+
+    /// Replay the whole archive, timing it. When `cold` is set, the OS page cache is
+    /// dropped first via `drop_page_cache` so the measured duration reflects
+    /// disk-bound reads rather than a warm page cache.
+    pub async fn benchmark_replay(path: &str, cold: bool) -> Result<(u64, std::time::Duration)> {
+        if cold {
+            Self::drop_page_cache()?;
+        }
+        let reader = Self::open(path)?;
+        let (tx, mut rx) = mpsc::channel(1024);
+        let drain = tokio::spawn(async move { while rx.recv().await.is_some() {} });
+
+        let start = Instant::now();
+        let sent = reader.replay_all(&tx).await?;
+        drop(tx);
+        let _ = drain.await;
+        let elapsed = start.elapsed();
+
+        Ok((sent, elapsed))
+    }
+}
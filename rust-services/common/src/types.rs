@@ -17,11 +17,15 @@ pub struct Trade {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OHLCVBar {
     pub timestamp: f64,
+    pub symbol: String,
+    pub exchange: String,
     pub open: f64,
     pub high: f64,
     pub low: f64,
     pub close: f64,
     pub volume: f64,
+    /// Volume-weighted mean price over the bar: `Σ(price·volume) / Σvolume`.
+    pub vwap: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +99,19 @@ pub struct KrakenTradeData {
     pub timestamp: String,
 }
 
+/// Funding-rate update for a perpetual/swap market (e.g. OKX swaps, Deribit),
+/// published alongside trades/orderbook so strategies can track carry cost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingRate {
+    pub symbol: String,
+    pub exchange: String,
+    pub rate: f64,
+    pub next_rate: Option<f64>,
+    pub funding_timestamp: f64,
+    pub mark_price: Option<f64>,
+    pub index_price: Option<f64>,
+}
+
 // Configuration types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollectorConfig {
@@ -11,11 +11,25 @@ use alphapulse_common::{
     Trade, OrderBookDelta, OrderBookSnapshot, PriceLevel, DeltaAction,
     shared_memory::{SharedMemoryReader, OrderBookDeltaReader, SharedTrade, SharedOrderBookDelta}
 };
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex;
+use rand::Rng;
 use numpy::{PyArray1, PyArray2};
 use chrono::{DateTime, Utc};
 
+/// One Tokio runtime shared across the blocking reader calls in this module
+/// (`benchmark_shared_memory_latency` still builds its own, once per run).
+/// Spinning up a fresh `Runtime` per call (the previous approach) costs
+/// hundreds of microseconds on its own, which defeats the sub-10μs latency
+/// this module exists for.
+static SHARED_RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+fn shared_runtime() -> &'static tokio::runtime::Runtime {
+    SHARED_RUNTIME.get_or_init(|| {
+        tokio::runtime::Runtime::new().expect("failed to create shared Tokio runtime")
+    })
+}
+
 /// Python wrapper for Trade data
 #[pyclass]
 #[derive(Clone)]
@@ -277,16 +291,11 @@ impl PySharedMemoryReader {
     /// Read all new trades since last call (non-blocking)
     fn read_trades(&mut self, py: Python) -> PyResult<Vec<PyTrade>> {
         let reader = self.reader.clone();
-        
+
         py.allow_threads(|| {
-            let rt = tokio::runtime::Runtime::new()
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to create async runtime: {}", e)
-                ))?;
-            
-            rt.block_on(async {
+            shared_runtime().block_on(async {
                 let mut reader_guard = reader.lock().await;
-                
+
                 match reader_guard.read_trades() {
                     Ok(trades) => {
                         let py_trades: Vec<PyTrade> = trades
@@ -302,20 +311,41 @@ impl PySharedMemoryReader {
             })
         })
     }
-    
+
+    /// Async equivalent of `read_trades`, for callers already running an
+    /// event loop instead of hot-polling synchronously.
+    fn read_trades_async<'p>(&mut self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let reader = self.reader.clone();
+
+        future_into_py(py, async move {
+            let mut reader_guard = reader.lock().await;
+            match reader_guard.read_trades() {
+                Ok(trades) => Ok(trades.iter().map(PyTrade::from).collect::<Vec<_>>()),
+                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                    format!("Failed to read trades: {}", e)
+                )),
+            }
+        })
+    }
+
+    /// Return an async iterator that yields batches of `PyTrade` as they
+    /// arrive, polling shared memory every `poll_interval_us` microseconds
+    /// with the GIL released between polls.
+    fn stream_trades(&self, poll_interval_us: u64) -> PyTradeStream {
+        PyTradeStream {
+            reader: self.reader.clone(),
+            poll_interval_us,
+        }
+    }
+
     /// Get memory statistics
     fn get_stats(&self, py: Python) -> PyResult<PyObject> {
         let reader = self.reader.clone();
-        
+
         py.allow_threads(|| {
-            let rt = tokio::runtime::Runtime::new()
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to create async runtime: {}", e)
-                ))?;
-            
-            rt.block_on(async {
+            shared_runtime().block_on(async {
                 let reader_guard = reader.lock().await;
-                
+
                 Python::with_gil(|py| {
                     let dict = PyDict::new(py);
                     dict.set_item("capacity", reader_guard.capacity())?;
@@ -328,6 +358,44 @@ impl PySharedMemoryReader {
     }
 }
 
+/// Async iterator over live trades, returned by `PySharedMemoryReader::stream_trades`.
+/// Each `async for` step polls shared memory every `poll_interval_us`
+/// microseconds (releasing the GIL between polls) until a non-empty batch
+/// arrives.
+#[pyclass]
+pub struct PyTradeStream {
+    reader: Arc<Mutex<SharedMemoryReader>>,
+    poll_interval_us: u64,
+}
+
+#[pymethods]
+impl PyTradeStream {
+    fn __aiter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Option<&'p PyAny>> {
+        let reader = self.reader.clone();
+        let poll_interval = std::time::Duration::from_micros(self.poll_interval_us);
+
+        let future = future_into_py(py, async move {
+            loop {
+                let trades = {
+                    let mut reader_guard = reader.lock().await;
+                    reader_guard.read_trades().unwrap_or_default()
+                };
+                if !trades.is_empty() {
+                    let py_trades: Vec<PyTrade> = trades.iter().map(PyTrade::from).collect();
+                    return Ok(py_trades);
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+        })?;
+
+        Ok(Some(future))
+    }
+}
+
 /// Ultra-fast orderbook delta reader
 #[pyclass]
 pub struct PyOrderBookDeltaReader {
@@ -351,70 +419,13 @@ impl PyOrderBookDeltaReader {
     /// Read all new deltas since last call
     fn read_deltas(&mut self, py: Python) -> PyResult<Vec<PyOrderBookDelta>> {
         let reader = self.reader.clone();
-        
+
         py.allow_threads(|| {
-            let rt = tokio::runtime::Runtime::new()
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to create async runtime: {}", e)
-                ))?;
-            
-            rt.block_on(async {
+            shared_runtime().block_on(async {
                 let mut reader_guard = reader.lock().await;
-                
+
                 match reader_guard.read_deltas() {
-                    Ok(deltas) => {
-                        // Convert SharedOrderBookDelta to OrderBookDelta then to PyOrderBookDelta
-                        let py_deltas: Vec<PyOrderBookDelta> = deltas
-                            .iter()
-                            .filter_map(|shared_delta| {
-                                // Convert shared delta to regular delta format
-                                // This is a simplified conversion - in practice you'd fully reconstruct
-                                let symbol = std::str::from_utf8(&shared_delta.symbol)
-                                    .unwrap_or("UNKNOWN")
-                                    .trim_end_matches('\0');
-                                let exchange = std::str::from_utf8(&shared_delta.exchange)
-                                    .unwrap_or("UNKNOWN")
-                                    .trim_end_matches('\0');
-                                
-                                // Extract changes from shared delta
-                                let mut bid_changes = Vec::new();
-                                let mut ask_changes = Vec::new();
-                                
-                                for i in 0..shared_delta.change_count as usize {
-                                    if i < shared_delta.changes.len() {
-                                        let change = &shared_delta.changes[i];
-                                        let py_level = PyPriceLevel {
-                                            price: change.price,
-                                            volume: change.volume,
-                                            action: match change.action {
-                                                0 => "add".to_string(),
-                                                1 => "update".to_string(),
-                                                2 => "remove".to_string(),
-                                                _ => "unknown".to_string(),
-                                            }
-                                        };
-                                        
-                                        if change.is_ask != 0 {
-                                            ask_changes.push(py_level);
-                                        } else {
-                                            bid_changes.push(py_level);
-                                        }
-                                    }
-                                }
-                                
-                                Some(PyOrderBookDelta {
-                                    timestamp: shared_delta.timestamp_ns as f64 / 1_000_000_000.0,
-                                    symbol: symbol.to_string(),
-                                    exchange: exchange.to_string(),
-                                    version: shared_delta.version,
-                                    prev_version: shared_delta.prev_version,
-                                    bid_changes,
-                                    ask_changes,
-                                })
-                            })
-                            .collect();
-                        Ok(py_deltas)
-                    }
+                    Ok(deltas) => Ok(convert_shared_deltas(&deltas)),
                     Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
                         format!("Failed to read deltas: {}", e)
                     ))
@@ -422,12 +433,215 @@ impl PyOrderBookDeltaReader {
             })
         })
     }
+
+    /// Async equivalent of `read_deltas`.
+    fn read_deltas_async<'p>(&mut self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let reader = self.reader.clone();
+
+        future_into_py(py, async move {
+            let mut reader_guard = reader.lock().await;
+            match reader_guard.read_deltas() {
+                Ok(deltas) => Ok(convert_shared_deltas(&deltas)),
+                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                    format!("Failed to read deltas: {}", e)
+                )),
+            }
+        })
+    }
+}
+
+/// Convert raw `SharedOrderBookDelta` records to `PyOrderBookDelta`, shared
+/// between `read_deltas` and `read_deltas_async`.
+fn convert_shared_deltas(deltas: &[SharedOrderBookDelta]) -> Vec<PyOrderBookDelta> {
+    deltas
+        .iter()
+        .filter_map(|shared_delta| {
+            // Convert shared delta to regular delta format
+            // This is a simplified conversion - in practice you'd fully reconstruct
+            let symbol = std::str::from_utf8(&shared_delta.symbol)
+                .unwrap_or("UNKNOWN")
+                .trim_end_matches('\0');
+            let exchange = std::str::from_utf8(&shared_delta.exchange)
+                .unwrap_or("UNKNOWN")
+                .trim_end_matches('\0');
+
+            // Extract changes from shared delta
+            let mut bid_changes = Vec::new();
+            let mut ask_changes = Vec::new();
+
+            for i in 0..shared_delta.change_count as usize {
+                if i < shared_delta.changes.len() {
+                    let change = &shared_delta.changes[i];
+                    let py_level = PyPriceLevel {
+                        price: change.price,
+                        volume: change.volume,
+                        action: match change.action {
+                            0 => "add".to_string(),
+                            1 => "update".to_string(),
+                            2 => "remove".to_string(),
+                            _ => "unknown".to_string(),
+                        }
+                    };
+
+                    if change.is_ask != 0 {
+                        ask_changes.push(py_level);
+                    } else {
+                        bid_changes.push(py_level);
+                    }
+                }
+            }
+
+            Some(PyOrderBookDelta {
+                timestamp: shared_delta.timestamp_ns as f64 / 1_000_000_000.0,
+                symbol: symbol.to_string(),
+                exchange: exchange.to_string(),
+                version: shared_delta.version,
+                prev_version: shared_delta.prev_version,
+                bid_changes,
+                ask_changes,
+            })
+        })
+        .collect()
+}
+
+pyo3::create_exception!(
+    alphapulse_rust,
+    SequenceGap,
+    pyo3::exceptions::PyException
+);
+
+/// Full orderbook snapshot used to seed (or re-seed, after a sequence gap) a
+/// `PyOrderBookReconstructor`'s state for one `exchange:symbol` feed.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyOrderBookSnapshot {
+    #[pyo3(get)]
+    pub symbol: String,
+    #[pyo3(get)]
+    pub exchange: String,
+    #[pyo3(get)]
+    pub timestamp: f64,
+    #[pyo3(get)]
+    pub version: u64,
+    pub bids: Vec<(f64, f64)>, // (price, volume)
+    pub asks: Vec<(f64, f64)>,
+}
+
+#[pymethods]
+impl PyOrderBookSnapshot {
+    #[new]
+    fn new(
+        symbol: String,
+        exchange: String,
+        timestamp: f64,
+        version: u64,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    ) -> Self {
+        Self {
+            symbol,
+            exchange,
+            timestamp,
+            version,
+            bids,
+            asks,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PyOrderBookSnapshot(symbol='{}', exchange='{}', version={}, bids={}, asks={})",
+            self.symbol,
+            self.exchange,
+            self.version,
+            self.bids.len(),
+            self.asks.len()
+        )
+    }
 }
 
 /// OrderBook reconstructor for full orderbook from deltas
 #[pyclass]
 pub struct PyOrderBookReconstructor {
     orderbooks: std::collections::HashMap<String, PyOrderBook>,
+    /// Feeds that have seen a sequence gap (or never been seeded) and so must
+    /// not have further deltas applied until a fresh snapshot arrives.
+    needs_snapshot: std::collections::HashSet<String>,
+    /// Per-`exchange:symbol` tick/lot sizing; a feed with no entry here uses
+    /// `PyMarketSpec::default()` (the old hardcoded `1e5` scale).
+    market_specs: std::collections::HashMap<String, PyMarketSpec>,
+}
+
+/// The price scaling the old hardcoded `* 100000.0` factor used:
+/// `price_key = round(price / tick_size)`. Kept as the default so a feed with
+/// no registered `PyMarketSpec` keeps the same 1e5 price scale; keys are now
+/// rounded to the nearest tick where the old factor truncated.
+const DEFAULT_TICK_SIZE: f64 = 0.00001;
+
+/// Per-market tick/lot sizing, mirroring DeepBook's `Book` concept: prices are
+/// keyed on an exact integer multiple of `tick_size` instead of a fixed
+/// decimal scale, and incoming volumes are rounded to `lot_size` and rejected
+/// below `min_size`.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyMarketSpec {
+    #[pyo3(get)]
+    pub tick_size: f64,
+    #[pyo3(get)]
+    pub lot_size: f64,
+    #[pyo3(get)]
+    pub min_size: f64,
+    #[pyo3(get)]
+    pub price_decimals: u32,
+}
+
+#[pymethods]
+impl PyMarketSpec {
+    #[new]
+    fn new(tick_size: f64, lot_size: f64, min_size: f64, price_decimals: u32) -> Self {
+        Self {
+            tick_size,
+            lot_size,
+            min_size,
+            price_decimals,
+        }
+    }
+}
+
+impl Default for PyMarketSpec {
+    fn default() -> Self {
+        Self {
+            tick_size: DEFAULT_TICK_SIZE,
+            lot_size: 0.0,
+            min_size: 0.0,
+            price_decimals: 5,
+        }
+    }
+}
+
+impl PyMarketSpec {
+    /// Round `volume` to the nearest multiple of `lot_size` (a `lot_size` of
+    /// 0 disables rounding), or `None` if the result falls below `min_size`.
+    fn round_volume(&self, volume: f64) -> Option<f64> {
+        let rounded = if self.lot_size > 0.0 {
+            (volume / self.lot_size).round() * self.lot_size
+        } else {
+            volume
+        };
+        if rounded < self.min_size {
+            None
+        } else {
+            Some(rounded)
+        }
+    }
+
+    fn price_key(&self, price: f64) -> u64 {
+        (price / self.tick_size).round() as u64
+    }
+
+    fn price_from_key(&self, price_key: u64) -> f64 {
+        price_key as f64 * self.tick_size
+    }
 }
 
 #[pyclass]
@@ -443,6 +657,9 @@ pub struct PyOrderBook {
     pub version: u64,
     pub bids: std::collections::BTreeMap<u64, f64>, // price_key -> volume
     pub asks: std::collections::BTreeMap<u64, f64>, // price_key -> volume
+    /// The spec used to pack/unpack this book's price keys, so readers invert
+    /// them the same way they were built.
+    pub spec: PyMarketSpec,
 }
 
 #[pymethods]
@@ -451,29 +668,29 @@ impl PyOrderBook {
         let bids: Vec<[f64; 2]> = self.bids
             .iter()
             .rev() // Highest prices first
-            .map(|(price_key, volume)| [*price_key as f64 / 100000.0, *volume])
+            .map(|(price_key, volume)| [self.spec.price_from_key(*price_key), *volume])
             .collect();
-        
+
         let array = PyArray2::from_vec2(py, &[bids])?;
         Ok(array.into_py(py))
     }
-    
+
     fn get_asks(&self, py: Python) -> PyResult<PyObject> {
         let asks: Vec<[f64; 2]> = self.asks
             .iter()
-            .map(|(price_key, volume)| [*price_key as f64 / 100000.0, *volume])
+            .map(|(price_key, volume)| [self.spec.price_from_key(*price_key), *volume])
             .collect();
-        
+
         let array = PyArray2::from_vec2(py, &[asks])?;
         Ok(array.into_py(py))
     }
-    
+
     fn get_best_bid(&self) -> Option<f64> {
-        self.bids.iter().next_back().map(|(price_key, _)| *price_key as f64 / 100000.0)
+        self.bids.iter().next_back().map(|(price_key, _)| self.spec.price_from_key(*price_key))
     }
-    
+
     fn get_best_ask(&self) -> Option<f64> {
-        self.asks.iter().next().map(|(price_key, _)| *price_key as f64 / 100000.0)
+        self.asks.iter().next().map(|(price_key, _)| self.spec.price_from_key(*price_key))
     }
     
     fn get_spread(&self) -> Option<f64> {
@@ -482,6 +699,61 @@ impl PyOrderBook {
             _ => None,
         }
     }
+
+    /// Walk the ask side in price order, filling up to `size` units and
+    /// returning `(volume_weighted_avg_price, filled_size)`. `filled_size` is
+    /// less than `size` when the book can't fill the whole order.
+    fn quote_buy(&self, size: f64) -> Option<(f64, f64)> {
+        self.walk_levels(self.asks.iter(), size)
+    }
+
+    /// Walk the bid side in descending price order, filling up to `size`
+    /// units and returning `(volume_weighted_avg_price, filled_size)`.
+    fn quote_sell(&self, size: f64) -> Option<(f64, f64)> {
+        self.walk_levels(self.bids.iter().rev(), size)
+    }
+}
+
+impl PyOrderBook {
+    /// Accumulate `(price_key, volume)` levels (already in the order the
+    /// order would actually fill) until `size` is reached, returning the
+    /// volume-weighted average price and the total filled quantity.
+    fn walk_levels<'a>(
+        &self,
+        levels: impl Iterator<Item = (&'a u64, &'a f64)>,
+        size: f64,
+    ) -> Option<(f64, f64)> {
+        let mut remaining = size;
+        let mut filled = 0.0;
+        let mut notional = 0.0;
+
+        for (price_key, volume) in levels {
+            if remaining <= 0.0 {
+                break;
+            }
+            let price = self.spec.price_from_key(*price_key);
+            let take = remaining.min(*volume);
+            notional += price * take;
+            filled += take;
+            remaining -= take;
+        }
+
+        if filled <= 0.0 {
+            return None;
+        }
+        Some((notional / filled, filled))
+    }
+
+    /// Total resting volume on the ask side, i.e. the most this book can fill
+    /// a buy order for.
+    fn ask_depth(&self) -> f64 {
+        self.asks.values().sum()
+    }
+
+    /// Total resting volume on the bid side.
+    fn bid_depth(&self) -> f64 {
+        self.bids.values().sum()
+    }
 }
 
 #[pymethods]
@@ -490,65 +762,137 @@ impl PyOrderBookReconstructor {
     fn new() -> Self {
         Self {
             orderbooks: std::collections::HashMap::new(),
+            needs_snapshot: std::collections::HashSet::new(),
+            market_specs: std::collections::HashMap::new(),
         }
     }
-    
-    /// Apply delta to reconstruct orderbook state
-    fn apply_delta(&mut self, delta: &PyOrderBookDelta) {
-        let key = format!("{}:{}", delta.exchange, delta.symbol);
-        
-        let orderbook = self.orderbooks.entry(key.clone()).or_insert_with(|| {
+
+    /// Register the tick/lot sizing for `exchange:symbol`. Must be set
+    /// before `seed_snapshot`/`apply_delta` for the spec to take effect,
+    /// since existing price keys in an already-seeded book aren't
+    /// retroactively rekeyed.
+    fn set_market_spec(&mut self, exchange: &str, symbol: &str, spec: PyMarketSpec) {
+        self.market_specs.insert(format!("{}:{}", exchange, symbol), spec);
+    }
+
+    /// Seed (or re-seed after a `SequenceGap`) a feed's orderbook from a full
+    /// snapshot, clearing its `needs_snapshot` flag.
+    fn seed_snapshot(&mut self, snapshot: &PyOrderBookSnapshot) {
+        let key = format!("{}:{}", snapshot.exchange, snapshot.symbol);
+        let spec = self.market_specs.get(&key).cloned().unwrap_or_default();
+
+        let bids = snapshot
+            .bids
+            .iter()
+            .filter_map(|(price, volume)| Some((spec.price_key(*price), spec.round_volume(*volume)?)))
+            .collect();
+        let asks = snapshot
+            .asks
+            .iter()
+            .filter_map(|(price, volume)| Some((spec.price_key(*price), spec.round_volume(*volume)?)))
+            .collect();
+
+        self.orderbooks.insert(
+            key.clone(),
             PyOrderBook {
-                symbol: delta.symbol.clone(),
-                exchange: delta.exchange.clone(),
-                timestamp: delta.timestamp,
-                version: delta.version,
-                bids: std::collections::BTreeMap::new(),
-                asks: std::collections::BTreeMap::new(),
+                symbol: snapshot.symbol.clone(),
+                exchange: snapshot.exchange.clone(),
+                timestamp: snapshot.timestamp,
+                version: snapshot.version,
+                bids,
+                asks,
+                spec,
+            },
+        );
+        self.needs_snapshot.remove(&key);
+    }
+
+    /// Whether `exchange:symbol` has never been seeded, or has seen a
+    /// sequence gap since its last snapshot, and so needs `seed_snapshot`
+    /// before more deltas can be applied.
+    fn needs_snapshot(&self, exchange: &str, symbol: &str) -> bool {
+        let key = format!("{}:{}", exchange, symbol);
+        !self.orderbooks.contains_key(&key) || self.needs_snapshot.contains(&key)
+    }
+
+    /// Apply delta to reconstruct orderbook state. Raises `SequenceGap` (and
+    /// leaves the orderbook untouched) if `delta.prev_version` doesn't match
+    /// the feed's last applied version, and marks the feed as needing a fresh
+    /// snapshot rather than guessing at recovery. A feed already marked keeps
+    /// raising `SequenceGap` until `seed_snapshot` is called for it.
+    fn apply_delta(&mut self, delta: &PyOrderBookDelta) -> PyResult<()> {
+        let key = format!("{}:{}", delta.exchange, delta.symbol);
+
+        if self.needs_snapshot.contains(&key) {
+            return Err(SequenceGap::new_err(format!(
+                "awaiting a fresh snapshot for {}",
+                key
+            )));
+        }
+
+        if let Some(orderbook) = self.orderbooks.get(&key) {
+            if orderbook.version != delta.prev_version {
+                self.needs_snapshot.insert(key.clone());
+                return Err(SequenceGap::new_err(format!(
+                    "expected prev_version {}, got {} for {}",
+                    orderbook.version, delta.prev_version, key
+                )));
             }
-        });
-        
+        } else {
+            self.needs_snapshot.insert(key.clone());
+            return Err(SequenceGap::new_err(format!(
+                "no snapshot seeded yet for {}",
+                key
+            )));
+        }
+
+        let orderbook = self.orderbooks.get_mut(&key).unwrap();
+
         // Update orderbook with delta changes
         orderbook.timestamp = delta.timestamp;
         orderbook.version = delta.version;
-        
+
         // Apply bid changes
         for change in &delta.bid_changes {
-            let price_key = (change.price * 100000.0) as u64;
+            let price_key = orderbook.spec.price_key(change.price);
             match change.action.as_str() {
-                "add" | "update" => {
-                    if change.volume > 0.0 {
-                        orderbook.bids.insert(price_key, change.volume);
-                    } else {
+                "add" | "update" => match orderbook.spec.round_volume(change.volume) {
+                    Some(volume) if volume > 0.0 => {
+                        orderbook.bids.insert(price_key, volume);
+                    }
+                    _ => {
                         orderbook.bids.remove(&price_key);
                     }
-                }
+                },
                 "remove" => {
                     orderbook.bids.remove(&price_key);
                 }
                 _ => {}
             }
         }
-        
+
         // Apply ask changes
         for change in &delta.ask_changes {
-            let price_key = (change.price * 100000.0) as u64;
+            let price_key = orderbook.spec.price_key(change.price);
             match change.action.as_str() {
-                "add" | "update" => {
-                    if change.volume > 0.0 {
-                        orderbook.asks.insert(price_key, change.volume);
-                    } else {
+                "add" | "update" => match orderbook.spec.round_volume(change.volume) {
+                    Some(volume) if volume > 0.0 => {
+                        orderbook.asks.insert(price_key, volume);
+                    }
+                    _ => {
                         orderbook.asks.remove(&price_key);
                     }
-                }
+                },
                 "remove" => {
                     orderbook.asks.remove(&price_key);
                 }
                 _ => {}
             }
         }
+
+        Ok(())
     }
-    
+
     /// Get current orderbook state
     fn get_orderbook(&self, exchange: &str, symbol: &str) -> Option<PyOrderBook> {
         let key = format!("{}:{}", exchange, symbol);
@@ -567,6 +911,20 @@ pub struct PyArbitrageDetector {
     orderbooks: std::collections::HashMap<String, PyOrderBook>,
     min_profit_bps: f64,
     min_volume: f64,
+    /// Per-exchange taker fee in basis points, applied to both legs of a
+    /// quoted trade before computing net profit. Exchanges with no entry are
+    /// treated as zero-fee.
+    taker_fees_bps: std::collections::HashMap<String, f64>,
+}
+
+/// The result of walking both books for an exchange pair: the executable
+/// size and the net/gross profit after fees, or `None` if nothing on either
+/// side could fill.
+struct BookWalkResult {
+    size: f64,
+    gross_bps: f64,
+    net_bps: f64,
+    limiting_side: &'static str,
 }
 
 #[pymethods]
@@ -577,61 +935,49 @@ impl PyArbitrageDetector {
             orderbooks: std::collections::HashMap::new(),
             min_profit_bps,
             min_volume,
+            taker_fees_bps: std::collections::HashMap::new(),
         }
     }
-    
+
+    /// Set the taker fee (in basis points) charged by `exchange`, used to
+    /// compute `net_bps` in `detect_opportunities`.
+    fn set_taker_fee_bps(&mut self, exchange: String, fee_bps: f64) {
+        self.taker_fees_bps.insert(exchange, fee_bps);
+    }
+
     /// Update orderbook state
     fn update_orderbook(&mut self, orderbook: PyOrderBook) {
         let key = format!("{}:{}", orderbook.exchange, orderbook.symbol);
         self.orderbooks.insert(key, orderbook);
     }
-    
-    /// Detect arbitrage opportunities
+
+    /// Detect arbitrage opportunities, walking both books to the size that
+    /// can actually execute rather than comparing top-of-book alone.
     fn detect_opportunities(&self, symbol: &str) -> Vec<PyObject> {
         let mut opportunities = Vec::new();
-        
+
         // Find all exchanges with this symbol
         let exchanges: Vec<(&String, &PyOrderBook)> = self.orderbooks
             .iter()
             .filter(|(key, _)| key.ends_with(&format!(":{}", symbol)))
             .collect();
-        
-        // Compare all exchange pairs
-        for (i, (key1, book1)) in exchanges.iter().enumerate() {
-            for (key2, book2) in exchanges.iter().skip(i + 1) {
-                if let (Some(ask1), Some(bid2)) = (book1.get_best_ask(), book2.get_best_bid()) {
-                    if bid2 > ask1 {
-                        let profit_bps = ((bid2 - ask1) / ask1) * 10000.0;
-                        if profit_bps >= self.min_profit_bps {
-                            Python::with_gil(|py| {
-                                let dict = PyDict::new(py);
-                                dict.set_item("symbol", symbol).unwrap();
-                                dict.set_item("buy_exchange", book1.exchange.clone()).unwrap();
-                                dict.set_item("sell_exchange", book2.exchange.clone()).unwrap();
-                                dict.set_item("buy_price", ask1).unwrap();
-                                dict.set_item("sell_price", bid2).unwrap();
-                                dict.set_item("profit_bps", profit_bps).unwrap();
-                                dict.set_item("timestamp", book1.timestamp.max(book2.timestamp)).unwrap();
-                                opportunities.push(dict.into());
-                            });
-                        }
-                    }
-                }
-                
-                // Check the reverse direction
-                if let (Some(ask2), Some(bid1)) = (book2.get_best_ask(), book1.get_best_bid()) {
-                    if bid1 > ask2 {
-                        let profit_bps = ((bid1 - ask2) / ask2) * 10000.0;
-                        if profit_bps >= self.min_profit_bps {
+
+        // Compare all exchange pairs, in both directions
+        for (i, (_, book1)) in exchanges.iter().enumerate() {
+            for (_, book2) in exchanges.iter().skip(i + 1) {
+                for (ask_book, bid_book) in [(*book1, *book2), (*book2, *book1)] {
+                    if let Some(result) = self.walk_pair(ask_book, bid_book) {
+                        if result.net_bps >= self.min_profit_bps {
                             Python::with_gil(|py| {
                                 let dict = PyDict::new(py);
                                 dict.set_item("symbol", symbol).unwrap();
-                                dict.set_item("buy_exchange", book2.exchange.clone()).unwrap();
-                                dict.set_item("sell_exchange", book1.exchange.clone()).unwrap();
-                                dict.set_item("buy_price", ask2).unwrap();
-                                dict.set_item("sell_price", bid1).unwrap();
-                                dict.set_item("profit_bps", profit_bps).unwrap();
-                                dict.set_item("timestamp", book1.timestamp.max(book2.timestamp)).unwrap();
+                                dict.set_item("buy_exchange", ask_book.exchange.clone()).unwrap();
+                                dict.set_item("sell_exchange", bid_book.exchange.clone()).unwrap();
+                                dict.set_item("size", result.size).unwrap();
+                                dict.set_item("gross_bps", result.gross_bps).unwrap();
+                                dict.set_item("net_bps", result.net_bps).unwrap();
+                                dict.set_item("limiting_side", result.limiting_side).unwrap();
+                                dict.set_item("timestamp", ask_book.timestamp.max(bid_book.timestamp)).unwrap();
                                 opportunities.push(dict.into());
                             });
                         }
@@ -639,36 +985,364 @@ impl PyArbitrageDetector {
                 }
             }
         }
-        
+
         opportunities
     }
 }
 
-/// Performance testing utilities
+impl PyArbitrageDetector {
+    fn taker_fee_bps(&self, exchange: &str) -> f64 {
+        self.taker_fees_bps.get(exchange).copied().unwrap_or(0.0)
+    }
+
+    /// Quote buying on `ask_book` and selling on `bid_book` for the maximum
+    /// size both sides can support, net of each exchange's taker fee. Returns
+    /// `None` when that size (or the filled size) is below `min_volume`.
+    fn walk_pair(&self, ask_book: &PyOrderBook, bid_book: &PyOrderBook) -> Option<BookWalkResult> {
+        let ask_depth = ask_book.ask_depth();
+        let bid_depth = bid_book.bid_depth();
+        let size = ask_depth.min(bid_depth);
+        if size < self.min_volume {
+            return None;
+        }
+
+        let (buy_vwap, buy_filled) = ask_book.quote_buy(size)?;
+        let (sell_vwap, sell_filled) = bid_book.quote_sell(size)?;
+        let filled = buy_filled.min(sell_filled);
+        if filled < self.min_volume || buy_vwap <= 0.0 {
+            return None;
+        }
+
+        let limiting_side = if ask_depth <= bid_depth { "ask" } else { "bid" };
+        let gross_bps = ((sell_vwap - buy_vwap) / buy_vwap) * 10000.0;
+        let fee_bps = self.taker_fee_bps(&ask_book.exchange) + self.taker_fee_bps(&bid_book.exchange);
+        let net_bps = gross_bps - fee_bps;
+
+        Some(BookWalkResult {
+            size: filled,
+            gross_bps,
+            net_bps,
+            limiting_side,
+        })
+    }
+}
+
+/// A resting order in the simulated matching venue. Not exposed to Python
+/// directly - callers interact through `submit_order`/`cancel_order`/`fills`.
+struct BacktestOrder {
+    id: u64,
+    exchange: String,
+    symbol: String,
+    side: String,       // "buy" | "sell"
+    order_type: String, // "limit" | "market"
+    limit_price: Option<f64>,
+    remaining: f64,
+    /// Simulated-time nanosecond at which the order becomes visible to the
+    /// book, per the configured latency model.
+    active_at_ns: u64,
+}
+
+/// One simulated execution.
+struct BacktestFill {
+    order_id: u64,
+    exchange: String,
+    symbol: String,
+    side: String,
+    price: f64,
+    size: f64,
+    fee: f64,
+    timestamp_ns: u64,
+}
+
+/// Event-driven backtest venue: replays `PyOrderBookDelta` streams through an
+/// internal `PyOrderBookReconstructor` and simulates order execution against
+/// the reconstructed book, the same way a simulated matching venue would.
+/// Resting limit orders fill when the crossing top-of-book level reaches
+/// their limit price; market orders fill by walking book depth for a
+/// realized VWAP, the same `quote_buy`/`quote_sell` mechanics `PyOrderBook`
+/// exposes for live arbitrage detection.
+#[pyclass]
+pub struct PyBacktestExchange {
+    reconstructor: PyOrderBookReconstructor,
+    orders: std::collections::HashMap<u64, BacktestOrder>,
+    next_order_id: u64,
+    fills: Vec<BacktestFill>,
+    /// Fixed nanosecond delay applied between order submission and when the
+    /// order becomes active in the book.
+    latency_ns: u64,
+    /// Additional uniform-random delay added on top of `latency_ns`, so
+    /// latency can be sampled rather than fixed. Zero disables sampling.
+    latency_jitter_ns: u64,
+    maker_fee_bps: f64,
+    taker_fee_bps: f64,
+    /// Simulated time, advanced by each delta's timestamp.
+    current_time_ns: u64,
+}
+
+#[pymethods]
+impl PyBacktestExchange {
+    #[new]
+    fn new(latency_ns: u64, latency_jitter_ns: u64, maker_fee_bps: f64, taker_fee_bps: f64) -> Self {
+        Self {
+            reconstructor: PyOrderBookReconstructor::new(),
+            orders: std::collections::HashMap::new(),
+            next_order_id: 1,
+            fills: Vec::new(),
+            latency_ns,
+            latency_jitter_ns,
+            maker_fee_bps,
+            taker_fee_bps,
+            current_time_ns: 0,
+        }
+    }
+
+    /// Register the tick/lot sizing for `exchange:symbol`; forwarded to the
+    /// internal reconstructor.
+    fn set_market_spec(&mut self, exchange: &str, symbol: &str, spec: PyMarketSpec) {
+        self.reconstructor.set_market_spec(exchange, symbol, spec);
+    }
+
+    /// Seed the internal reconstructor's book for `exchange:symbol`; required
+    /// before the first `apply_delta` for that feed, same as
+    /// `PyOrderBookReconstructor::seed_snapshot`.
+    fn seed_snapshot(&mut self, snapshot: &PyOrderBookSnapshot) {
+        self.reconstructor.seed_snapshot(snapshot);
+    }
+
+    /// Submit a limit or market order (`side`: "buy"/"sell", `order_type`:
+    /// "limit"/"market"). Returns the order id. The order only becomes
+    /// visible to matching once `current_time_ns` (driven by `apply_delta`)
+    /// reaches its simulated activation time.
+    fn submit_order(
+        &mut self,
+        exchange: &str,
+        symbol: &str,
+        side: &str,
+        order_type: &str,
+        quantity: f64,
+        limit_price: Option<f64>,
+    ) -> u64 {
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+
+        let jitter = if self.latency_jitter_ns > 0 {
+            rand::thread_rng().gen_range(0..=self.latency_jitter_ns)
+        } else {
+            0
+        };
+
+        self.orders.insert(
+            order_id,
+            BacktestOrder {
+                id: order_id,
+                exchange: exchange.to_string(),
+                symbol: symbol.to_string(),
+                side: side.to_string(),
+                order_type: order_type.to_string(),
+                limit_price,
+                remaining: quantity,
+                active_at_ns: self.current_time_ns + self.latency_ns + jitter,
+            },
+        );
+
+        order_id
+    }
+
+    /// Cancel a resting order. Returns `false` if it was already filled or
+    /// never existed.
+    fn cancel_order(&mut self, order_id: u64) -> bool {
+        self.orders.remove(&order_id).is_some()
+    }
+
+    /// Apply the next delta: advance the book (and simulated clock), then run
+    /// matching for every active order on that feed. Raises `SequenceGap` the
+    /// same way `PyOrderBookReconstructor::apply_delta` does.
+    fn apply_delta(&mut self, delta: &PyOrderBookDelta) -> PyResult<()> {
+        let timestamp_ns = (delta.timestamp * 1_000_000_000.0) as u64;
+        self.current_time_ns = self.current_time_ns.max(timestamp_ns);
+
+        self.reconstructor.apply_delta(delta)?;
+        self.match_orders(&delta.exchange, &delta.symbol, timestamp_ns);
+        Ok(())
+    }
+
+    /// Every fill so far, as `{order_id, exchange, symbol, side, price, size,
+    /// fee, timestamp}` dicts in execution order.
+    fn fills(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        self.fills
+            .iter()
+            .map(|fill| {
+                let dict = PyDict::new(py);
+                dict.set_item("order_id", fill.order_id)?;
+                dict.set_item("exchange", &fill.exchange)?;
+                dict.set_item("symbol", &fill.symbol)?;
+                dict.set_item("side", &fill.side)?;
+                dict.set_item("price", fill.price)?;
+                dict.set_item("size", fill.size)?;
+                dict.set_item("fee", fill.fee)?;
+                dict.set_item("timestamp", fill.timestamp_ns as f64 / 1_000_000_000.0)?;
+                Ok(dict.into())
+            })
+            .collect()
+    }
+}
+
+impl PyBacktestExchange {
+    /// Try to fill every active order resting on `exchange:symbol` against
+    /// the reconstructor's current book for that feed.
+    fn match_orders(&mut self, exchange: &str, symbol: &str, timestamp_ns: u64) {
+        let Some(orderbook) = self.reconstructor.get_orderbook(exchange, symbol) else {
+            return;
+        };
+
+        let order_ids: Vec<u64> = self
+            .orders
+            .values()
+            .filter(|order| {
+                order.exchange == exchange && order.symbol == symbol && order.active_at_ns <= timestamp_ns
+            })
+            .map(|order| order.id)
+            .collect();
+
+        for order_id in order_ids {
+            self.try_fill(order_id, &orderbook, timestamp_ns);
+        }
+    }
+
+    fn try_fill(&mut self, order_id: u64, orderbook: &PyOrderBook, timestamp_ns: u64) {
+        let Some(order) = self.orders.get(&order_id) else {
+            return;
+        };
+
+        let fill = if order.order_type == "market" {
+            let quote = if order.side == "buy" {
+                orderbook.quote_buy(order.remaining)
+            } else {
+                orderbook.quote_sell(order.remaining)
+            };
+            quote.map(|(vwap, filled)| (vwap, filled, self.taker_fee_bps))
+        } else {
+            // Limit order: only crosses against the top-of-book level, the
+            // same condition a real venue's match engine checks per tick
+            // rather than walking the whole book.
+            let Some(limit_price) = order.limit_price else {
+                return;
+            };
+            if order.side == "buy" {
+                orderbook
+                    .asks
+                    .iter()
+                    .next()
+                    .map(|(price_key, volume)| (orderbook.spec.price_from_key(*price_key), *volume))
+                    .filter(|(best_ask, _)| *best_ask <= limit_price)
+                    .map(|(best_ask, volume)| (best_ask, order.remaining.min(volume), self.maker_fee_bps))
+            } else {
+                orderbook
+                    .bids
+                    .iter()
+                    .next_back()
+                    .map(|(price_key, volume)| (orderbook.spec.price_from_key(*price_key), *volume))
+                    .filter(|(best_bid, _)| *best_bid >= limit_price)
+                    .map(|(best_bid, volume)| (best_bid, order.remaining.min(volume), self.maker_fee_bps))
+            }
+        };
+
+        let Some((price, size, fee_bps)) = fill else {
+            return;
+        };
+        if size <= 0.0 {
+            return;
+        }
+
+        let fee = price * size * fee_bps / 10000.0;
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.remaining -= size;
+        let (order_exchange, order_symbol, order_side) =
+            (order.exchange.clone(), order.symbol.clone(), order.side.clone());
+        let filled_out = order.remaining <= 0.0;
+
+        self.fills.push(BacktestFill {
+            order_id,
+            exchange: order_exchange,
+            symbol: order_symbol,
+            side: order_side,
+            price,
+            size,
+            fee,
+            timestamp_ns,
+        });
+
+        if filled_out {
+            self.orders.remove(&order_id);
+        }
+    }
+}
+
+/// Percentile index into a sorted `Vec`, following the fixed-percentile
+/// prioritization-fee summary approach: sort all samples once, then index at
+/// each fixed point rather than maintaining a running estimate.
+fn percentile_ns(sorted: &[u64], pct: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let index = ((sorted.len() - 1) as f64 * pct).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+/// Performance testing utilities.
+///
+/// Returns a dict of read-latency percentiles (in microseconds) plus `count`
+/// and `empty_reads`, so tail latency and no-data polls don't hide behind a
+/// single mean.
 #[pyfunction]
-fn benchmark_shared_memory_latency(path: &str, iterations: usize) -> PyResult<f64> {
+fn benchmark_shared_memory_latency(py: Python, path: &str, iterations: usize) -> PyResult<PyObject> {
     let rt = tokio::runtime::Runtime::new()
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Failed to create async runtime: {}", e)
         ))?;
-    
-    rt.block_on(async {
+
+    let (mut samples_ns, empty_reads) = rt.block_on(async {
         let mut reader = SharedMemoryReader::open(path, 999)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
                 format!("Failed to open shared memory: {}", e)
             ))?;
-        
-        let start = std::time::Instant::now();
-        
+
+        let mut samples_ns = Vec::with_capacity(iterations);
+        let mut empty_reads = 0u64;
+
         for _ in 0..iterations {
-            let _ = reader.read_trades().unwrap_or_default();
+            let start = std::time::Instant::now();
+            let trades = reader.read_trades().unwrap_or_default();
+            samples_ns.push(start.elapsed().as_nanos() as u64);
+            if trades.is_empty() {
+                empty_reads += 1;
+            }
         }
-        
-        let elapsed = start.elapsed();
-        let avg_latency_us = elapsed.as_nanos() as f64 / iterations as f64 / 1000.0;
-        
-        Ok(avg_latency_us)
-    })
+
+        Ok::<_, PyErr>((samples_ns, empty_reads))
+    })?;
+
+    samples_ns.sort_unstable();
+    let ns_to_us = |ns: u64| ns as f64 / 1000.0;
+    let mean_us = if samples_ns.is_empty() {
+        0.0
+    } else {
+        samples_ns.iter().sum::<u64>() as f64 / samples_ns.len() as f64 / 1000.0
+    };
+
+    let dict = PyDict::new(py);
+    dict.set_item("count", samples_ns.len())?;
+    dict.set_item("empty_reads", empty_reads)?;
+    dict.set_item("min", samples_ns.first().copied().map(ns_to_us).unwrap_or(0.0))?;
+    dict.set_item("p50", ns_to_us(percentile_ns(&samples_ns, 0.50)))?;
+    dict.set_item("p75", ns_to_us(percentile_ns(&samples_ns, 0.75)))?;
+    dict.set_item("p90", ns_to_us(percentile_ns(&samples_ns, 0.90)))?;
+    dict.set_item("p95", ns_to_us(percentile_ns(&samples_ns, 0.95)))?;
+    dict.set_item("p99", ns_to_us(percentile_ns(&samples_ns, 0.99)))?;
+    dict.set_item("max", samples_ns.last().copied().map(ns_to_us).unwrap_or(0.0))?;
+    dict.set_item("mean", mean_us)?;
+
+    Ok(dict.into())
 }
 
 /// Module initialization
@@ -678,11 +1352,16 @@ fn alphapulse_rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyPriceLevel>()?;
     m.add_class::<PyOrderBookDelta>()?;
     m.add_class::<PySharedMemoryReader>()?;
+    m.add_class::<PyTradeStream>()?;
     m.add_class::<PyOrderBookDeltaReader>()?;
     m.add_class::<PyOrderBook>()?;
+    m.add_class::<PyMarketSpec>()?;
+    m.add_class::<PyOrderBookSnapshot>()?;
     m.add_class::<PyOrderBookReconstructor>()?;
     m.add_class::<PyArbitrageDetector>()?;
+    m.add_class::<PyBacktestExchange>()?;
     m.add_function(wrap_pyfunction!(benchmark_shared_memory_latency, m)?)?;
+    m.add("SequenceGap", _py.get_type::<SequenceGap>())?;
     
     // Add version info
     m.add("__version__", "0.1.0")?;
@@ -73,6 +73,30 @@ impl MetricsCollector {
         counter!("buffer_overflows_total", "type" => buffer_type.to_string()).increment(1);
     }
 
+    // Stream retention metrics
+    pub fn record_stream_trim(&self, stream_key: &str, trimmed: usize) {
+        counter!("redis_stream_trimmed_entries_total", "stream" => stream_key.to_string()).increment(trimmed as u64);
+    }
+
+    // Backpressure metrics
+    pub fn record_buffer_blocked(&self, duration_ms: f64, buffer_type: &str) {
+        histogram!("buffer_blocked_ms", "type" => buffer_type.to_string()).record(duration_ms);
+    }
+
+    // Stream consumer-group metrics
+    pub fn record_stream_lag(&self, stream_key: &str, lag: usize) {
+        gauge!("redis_stream_consumer_lag", "stream" => stream_key.to_string()).set(lag as f64);
+    }
+
+    pub fn record_stream_reclaimed(&self, stream_key: &str, count: usize) {
+        counter!("redis_stream_reclaimed_entries_total", "stream" => stream_key.to_string()).increment(count as u64);
+    }
+
+    // Distributed lock metrics (acquired/renewed/lost/denied)
+    pub fn record_lock_event(&self, stream_key: &str, event: &str) {
+        counter!("redis_stream_lock_events_total", "stream" => stream_key.to_string(), "event" => event.to_string()).increment(1);
+    }
+
     // HTTP API metrics
     pub fn record_http_request(&self, method: &str, path: &str, status_code: u16) {
         counter!("http_requests_total", 